@@ -0,0 +1,62 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+"""Shared scaffold of the tests of spacecraft with internal degrees of freedom."""
+
+from adcs import AngularMomentum
+from adcs import integrators as it
+
+DT = 0.01
+
+
+def dot(a, b):
+    return a.x * b.x + a.y * b.y + a.z * b.z
+
+
+def momentum(state, internal):
+    """Total angular momentum (inertial frame) of the bus, given the angular momentum
+    (body frame) of its internal degrees of freedom."""
+    h = AngularMomentum.product(state.inertia, state.angular_velocity) + internal
+    return h.rotate(state.quaternion)
+
+
+def simulate(state, iterations, dt=DT):
+    integrator = it.RungeKutta4(dt)
+    for _ in range(iterations):
+        state = integrator.step(state)
+    return state
+
+
+class MomentumMixin:
+    """Assertion of conservation of the total angular momentum `self.momentum(state)`."""
+
+    def assertMomentum(self, initial, final, delta):
+        h0, h = self.momentum(initial), self.momentum(final)
+        for a, b in zip((h.x, h.y, h.z), (h0.x, h0.y, h0.z)):
+            self.assertAlmostEqual(a, b, delta=delta)
+
+
+class ConservationMixin(MomentumMixin):
+    """Tests of conservation of momentum and energy of a tumbling spacecraft.
+
+    The test case provides `initial(damping)`, `momentum(state)` and `energy(state)`,
+    the damping `DAMPING`, the tolerances `MOMENTUM` and `ENERGY`, and the least
+    energy `DISSIPATED` by the damping.
+    """
+
+    ITERATIONS = 3000
+
+    def test_undamped(self):
+        state = self.initial(0.0)
+        final = simulate(state, self.ITERATIONS)
+
+        self.assertMomentum(state, final, self.MOMENTUM)
+        self.assertAlmostEqual(self.energy(final), self.energy(state), delta=self.ENERGY)
+
+    def test_damped(self):
+        # Damping dissipates energy, but preserves momentum
+        state = self.initial(self.DAMPING)
+        final = simulate(state, self.ITERATIONS)
+
+        self.assertMomentum(state, final, self.MOMENTUM)
+        self.assertLess(self.energy(final), self.energy(state) - self.DISSIPATED)
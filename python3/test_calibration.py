@@ -0,0 +1,133 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import random
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    State,
+    Vector,
+)
+from adcs import calibration
+from adcs import integrators as it
+from adcs import sensors
+
+
+def inverse(m):
+    """Invert a 3x3 matrix."""
+    (a, b, c), (d, e, f), (g, h, i) = m
+    det = a*(e*i - f*h) - b*(d*i - f*g) + c*(d*h - e*g)
+    return [
+        [(e*i - f*h) / det, (c*h - b*i) / det, (b*f - c*e) / det],
+        [(f*g - d*i) / det, (a*i - c*g) / det, (c*d - a*f) / det],
+        [(d*h - e*g) / det, (b*g - a*h) / det, (a*e - b*d) / det],
+    ]
+
+
+class TestMagnetometerCalibration(unittest.TestCase):
+    def test_recovers_truth(self):
+        rng = random.Random(1)
+        magnetometer = sensors.Magnetometer(1.0, seed=2)
+        magnetometer.bias = Vector(3000.0, -1500.0, 800.0)
+        magnetometer.misalignment = [
+            [1.05, 0.02, -0.01],
+            [0.02, 0.97, 0.03],
+            [-0.01, 0.03, 1.01],
+        ]
+        magnetometer.noise = 50.0
+
+        state = State(Inertia(1, 1, 1, 0, 0, 0))
+        measurements = []
+        magnitudes = []
+        for k in range(500):
+            state.time = float(k)
+            state.quaternion = Quaternion(*(rng.gauss(0, 1) for _ in range(4))).normalize()
+
+            # Field magnitude varies along the orbit
+            strength = 30000.0 * (1.0 + 0.5 * math.sin(k / 40.0))
+            field = Vector(strength, 0.0, 0.0)
+            measurements.append(magnetometer.measure(state, field))
+            magnitudes.append(strength)
+
+        result = calibration.magnetometer(measurements, magnitudes)
+
+        for estimated, truth in zip(
+            (result.bias.x, result.bias.y, result.bias.z),
+            (3000.0, -1500.0, 800.0),
+        ):
+            self.assertAlmostEqual(estimated, truth, delta=20.0)
+
+        truth = inverse(magnetometer.misalignment)
+        for i in range(3):
+            for j in range(3):
+                self.assertAlmostEqual(result.correction[i][j], truth[i][j], delta=2e-3)
+
+        # Calibrated magnitudes match the reference
+        for m, h in zip(measurements, magnitudes):
+            self.assertAlmostEqual(result.apply(m).norm() / h, 1.0, delta=1e-2)
+
+    def test_rejects_too_few_measurements(self):
+        with self.assertRaises(ValueError):
+            calibration.magnetometer([Vector(1, 0, 0)] * 8, [1.0] * 8)
+
+
+class TestGyroCalibration(unittest.TestCase):
+    def test_recovers_truth(self):
+        gyro = sensors.Gyro(50.0, seed=3)
+        gyro.bias = AngularVelocity(0.01, -0.02, 0.005)
+        gyro.scale_factor = (0.02, -0.01, 0.015)
+        gyro.misalignment = [
+            [1.0, 0.003, -0.002],
+            [-0.001, 1.0, 0.004],
+            [0.002, -0.003, 1.0],
+        ]
+        gyro.arw = 1e-5
+
+        # Tumble about all three axes
+        state = State(Inertia(1.0, 1.5, 2.0, 0, 0, 0))
+        state.angular_velocity = AngularVelocity(0.3, 0.8, -0.2)
+        integrator = it.RungeKutta4(0.02)
+
+        times = []
+        attitudes = []
+        measurements = []
+        for _ in range(3000):
+            times.append(state.time)
+            attitudes.append(state.quaternion)
+            measurements.append(gyro.measure(state))
+            state = integrator.step(state)
+
+        result = calibration.gyro(times, attitudes, measurements)
+
+        for estimated, truth in zip(
+            (result.bias.x, result.bias.y, result.bias.z),
+            (0.01, -0.02, 0.005),
+        ):
+            self.assertAlmostEqual(estimated, truth, delta=1e-4)
+
+        for estimated, truth in zip(result.scale_factor, gyro.scale_factor):
+            self.assertAlmostEqual(estimated, truth, delta=1e-3)
+
+        for i in range(3):
+            for j in range(3):
+                self.assertAlmostEqual(
+                    result.misalignment[i][j],
+                    gyro.misalignment[i][j],
+                    delta=1e-3,
+                )
+
+    def test_rejects_mismatched_lengths(self):
+        with self.assertRaises(ValueError):
+            calibration.gyro(
+                [0.0, 1.0, 2.0, 3.0, 4.0],
+                [Quaternion(1, 0, 0, 0)] * 5,
+                [AngularVelocity(0, 0, 0)] * 4,
+            )
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,93 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import random
+import unittest
+
+from adcs import (
+    Quaternion,
+    Vector,
+    VectorMeasurement,
+)
+from adcs import determination
+
+# Rotation angles spanning the full range, including the QUEST singularity at pi
+ANGLES = [0.0, 0.1, math.pi / 4, math.pi / 2, 2.0, 3.0, math.pi - 1e-6, math.pi]
+
+# Rotation axis
+AXIS = (1.0, -2.0, 0.5)
+
+# Reference directions (reference frame)
+REFERENCES = [
+    Vector(1, 0, 0),
+    Vector(0.2, 1, 0.1),
+    Vector(-0.3, 0.4, 1),
+]
+
+SIGMA = 1e-3
+
+
+def rotation(angle):
+    """Rotation from body frame to reference frame."""
+    return Quaternion.from_rotation(angle, *AXIS)
+
+
+def measurements(q, references=REFERENCES, noise=0.0, rng=None):
+    """Vector measurements of a set of references, seen from a body at attitude `q`."""
+    result = []
+    for r in references:
+        b = r.normalize().rotate(q.inv())
+        if noise > 0:
+            b = Vector(*(c + rng.gauss(0, noise) for c in (b.x, b.y, b.z)))
+        result.append(VectorMeasurement(b, r, SIGMA))
+    return result
+
+
+def error(estimate, truth):
+    """Rotation angle (rad) between two attitudes."""
+    dq = truth.inv() * estimate
+    return 2 * math.asin(min(1.0, math.sqrt(dq.x**2 + dq.y**2 + dq.z**2)))
+
+
+class TestRoundTrip(unittest.TestCase):
+    def test_triad(self):
+        for angle in ANGLES:
+            q = rotation(angle)
+            first, second = measurements(q, REFERENCES[:2])
+            estimate = determination.triad(first, second)
+            self.assertLess(error(estimate.quaternion, q), 1e-9, angle)
+
+    def test_optimal(self):
+        for method in (determination.q_method, determination.quest, determination.esoq2):
+            for angle in ANGLES:
+                q = rotation(angle)
+                estimate = method(measurements(q))
+                self.assertLess(error(estimate.quaternion, q), 1e-9, (method.__name__, angle))
+
+    def test_noisy_agreement(self):
+        rng = random.Random(0)
+        for angle in ANGLES:
+            q = rotation(angle)
+            m = measurements(q, noise=SIGMA, rng=rng)
+            davenport = determination.q_method(m).quaternion
+            for method in (determination.quest, determination.esoq2):
+                self.assertLess(error(method(m).quaternion, davenport), 1e-8, (method.__name__, angle))
+            self.assertLess(error(davenport, q), 10 * SIGMA)
+
+    def test_covariance(self):
+        estimate = determination.quest(measurements(rotation(1.0)))
+        p = estimate.covariance
+        for i in range(3):
+            self.assertGreater(p[i][i], 0)
+            for j in range(3):
+                self.assertAlmostEqual(p[i][j], p[j][i])
+
+    def test_parallel_vectors(self):
+        first, second = measurements(Quaternion(1, 0, 0, 0), [Vector(1, 0, 0), Vector(2, 0, 0)])
+        with self.assertRaises(ValueError):
+            determination.triad(first, second)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,106 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    State,
+    Torque,
+    Vector,
+)
+from adcs import control
+from adcs import integrators as it
+from adcs import sensors
+
+DT = 0.1
+TIME = 4000
+ITERATIONS = int(TIME // DT)
+
+# Orbital rate of a low Earth orbit (rad/s)
+ORBIT_RATE = 2 * math.pi / 5400
+
+# Magnetic field strength (T)
+FIELD = 3e-5
+
+# Magnetorquer saturation limit (A m^2)
+MAX_DIPOLE = 1.0
+
+
+def field(time):
+    """Magnetic field (inertial frame) seen along a tilted circular orbit."""
+    u = ORBIT_RATE * time
+    return Vector(
+        FIELD * 2 * math.sin(u),
+        FIELD * math.cos(u) * math.cos(1.0),
+        FIELD * math.cos(u) * math.sin(1.0),
+    )
+
+
+def rate(state):
+    w = state.angular_velocity
+    return math.sqrt(w.x**2 + w.y**2 + w.z**2)
+
+
+def tumbling():
+    state = State(Inertia(0.1, 0.12, 0.08, 0, 0, 0))
+    state.angular_velocity = AngularVelocity(0.1, -0.08, 0.12)
+    return state
+
+
+class TestDetumble(unittest.TestCase):
+    def test_bdot_rate_decay(self):
+        state = tumbling()
+        initial = rate(state)
+
+        magnetometer = sensors.Magnetometer(1 / DT)
+        controller = control.BDot(5e-4, MAX_DIPOLE)
+        integrator = it.RungeKutta4(DT)
+
+        for _ in range(ITERATIONS):
+            b = field(state.time)
+            reading = magnetometer.measure(state, b)
+            dipole = controller.dipole(state.time, reading)
+
+            # Torque from the true field
+            t = dipole.cross(b.rotate(state.quaternion.inv()))
+            state.torque = Torque(t.x, t.y, t.z)
+            state = integrator.step(state)
+
+            self.assertLessEqual(max(abs(dipole.x), abs(dipole.y), abs(dipole.z)), MAX_DIPOLE + 1e-12)
+
+        self.assertLess(rate(state), 0.2 * initial)
+
+    def test_bcross_rate_decay(self):
+        state = tumbling()
+        initial = rate(state)
+
+        controller = control.BCross(5e-4, MAX_DIPOLE)
+        integrator = it.RungeKutta4(DT)
+
+        for _ in range(ITERATIONS):
+            b = field(state.time).rotate(state.quaternion.inv())
+            state.torque = controller.torque(b, state.angular_velocity)
+            state = integrator.step(state)
+
+        self.assertLess(rate(state), 0.2 * initial)
+
+    def test_saturation(self):
+        controller = control.BCross(1.0, 0.2)
+        dipole = controller.dipole(Vector(FIELD, 0, 0), AngularVelocity(0, 1, 2))
+        self.assertAlmostEqual(max(abs(dipole.x), abs(dipole.y), abs(dipole.z)), 0.2)
+
+        # Direction is preserved
+        self.assertAlmostEqual(dipole.z / dipole.y, -0.5)
+
+    def test_first_reading_commands_nothing(self):
+        controller = control.BDot(1.0, 0.2)
+        dipole = controller.dipole(0.0, Vector(FIELD, 0, 0))
+        self.assertEqual((dipole.x, dipole.y, dipole.z), (0.0, 0.0, 0.0))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,124 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import unittest
+
+from adcs import (
+    Inertia,
+    State,
+    Torque,
+    Vector,
+)
+from adcs import actuators
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+
+def at(time):
+    state = State(INERTIA)
+    state.time = time
+    return state
+
+
+def run(effects, signal, dt=0.01, time=1.0):
+    """Apply the effects to a signal of time, returning the delivered x components."""
+    return [effects.apply(k * dt, Torque(signal(k * dt), 0, 0)).x for k in range(round(time / dt))]
+
+
+class TestEffects(unittest.TestCase):
+    def test_ideal(self):
+        delivered = run(actuators.ActuatorEffects(), lambda t: t * t)
+        for k, d in enumerate(delivered):
+            self.assertEqual(d, (0.01 * k) ** 2)
+
+    def test_hold(self):
+        # Sampled at 10 Hz, the torque is held between samples
+        delivered = run(actuators.ActuatorEffects(None, 10.0), lambda t: t)
+        for k, d in enumerate(delivered):
+            self.assertAlmostEqual(d, 0.1 * (k // 10))
+
+    def test_delay(self):
+        # Each sample arrives five steps late (times are exact in binary)
+        dt = 1 / 64
+        effects = actuators.ActuatorEffects()
+        effects.delay = 5 * dt
+        delivered = run(effects, lambda t: 1.0 + t, dt)
+        for k, d in enumerate(delivered):
+            self.assertEqual(d, 0.0 if k < 5 else 1.0 + dt * (k - 5))
+
+    def test_quantization(self):
+        effects = actuators.ActuatorEffects()
+        effects.quantum = 0.25
+        for torque, expected in ((0.1, 0.0), (0.13, 0.25), (-0.4, -0.5), (0.9, 1.0)):
+            self.assertEqual(effects.apply(0.0, Torque(torque, 0, 0)).x, expected)
+
+    def test_saturation(self):
+        effects = actuators.ActuatorEffects()
+        effects.max_torque = 0.2
+        delivered = effects.apply(0.0, Torque(0.5, -0.1, -0.3))
+        self.assertEqual((delivered.x, delivered.y, delivered.z), (0.2, -0.1, -0.2))
+
+    def test_slew(self):
+        # A step is delivered as a ramp at the slew-rate limit
+        effects = actuators.ActuatorEffects()
+        effects.max_rate = 2.0
+        delivered = run(effects, lambda t: 1.0)
+        for k, d in enumerate(delivered):
+            self.assertAlmostEqual(d, min(1.0, 0.02 * k))
+
+    def test_order(self):
+        # Quantization and saturation act before the slew-rate limit
+        effects = actuators.ActuatorEffects()
+        effects.quantum = 0.3
+        effects.max_torque = 0.5
+        effects.max_rate = 10.0
+        delivered = run(effects, lambda t: 0.8, time=0.1)
+        self.assertAlmostEqual(delivered[-1], 0.5)
+        self.assertAlmostEqual(delivered[2], 0.2)
+
+    def test_reset(self):
+        effects = actuators.ActuatorEffects()
+        effects.delay = 0.5
+        run(effects, lambda t: 1.0)
+        effects.reset()
+        self.assertEqual(effects.apply(2.0, Torque(1, 0, 0)).x, 0.0)
+
+
+class TestWrapping(unittest.TestCase):
+    def test_actuator(self):
+        effects = actuators.ActuatorEffects(lambda state, command: Torque(2 * command, 0, 0))
+        effects.max_torque = 0.3
+        self.assertEqual(effects(at(0.0), 0.1).x, 0.2)
+        self.assertEqual(effects(at(0.1), 0.5).x, 0.3)
+
+    def test_ideal_command(self):
+        self.assertEqual(actuators.ActuatorEffects()(at(0.0), Torque(0.1, 0, 0)).x, 0.1)
+
+    def test_thrusters(self):
+        cluster = actuators.ThrusterCluster([
+            actuators.Thruster(Vector(0, 1, 0), Vector(0, 0, 1), 1.0),
+            actuators.Thruster(Vector(0, 1, 0), Vector(0, 0, -1), 1.0),
+        ], 0.5)
+        effects = actuators.ActuatorEffects(lambda state, command: cluster.command(state.time, command))
+        with self.assertRaises(TypeError):
+            effects(at(0.0), Torque(0.1, 0, 0))
+
+
+class TestValidation(unittest.TestCase):
+    def test_setters(self):
+        effects = actuators.ActuatorEffects()
+        for name, value in (
+            ("max_torque", 0.0),
+            ("max_rate", float("nan")),
+            ("delay", -1.0),
+            ("quantum", -0.1),
+        ):
+            with self.assertRaises(ValueError):
+                setattr(effects, name, value)
+
+        with self.assertRaises(ValueError):
+            actuators.ActuatorEffects(None, 0.0)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,109 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import random
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Quaternion,
+    Vector,
+    VectorMeasurement,
+)
+from adcs import estimation
+
+# Gyro sample period (s)
+DT = 0.1
+
+# Gyro angle random walk and rate random walk
+ARW = 1e-4
+RRW = 1e-6
+
+# Vector sensor noise (rad) and update period (gyro samples)
+SIGMA = 1e-3
+UPDATE = 10
+
+# True angular velocity and initial gyro bias (body frame)
+OMEGA = AngularVelocity(0.01, -0.02, 0.015)
+BIAS = AngularVelocity(1e-3, -2e-3, 5e-4)
+
+# Reference directions (inertial frame)
+REFERENCES = [
+    Vector(1, 0, 0),
+    Vector(0, 1, 0.3),
+]
+
+
+def gauss(rng, sigma):
+    return AngularVelocity(rng.gauss(0, sigma), rng.gauss(0, sigma), rng.gauss(0, sigma))
+
+
+def run(filter, duration, rng):
+    """Run a filter against a simulated gyro and vector sensor, returning the NEES at
+    each gyro sample and the final attitude error (rad)."""
+    q = Quaternion(1, 0, 0, 0)
+    b = BIAS
+    w = math.sqrt(OMEGA.x**2 + OMEGA.y**2 + OMEGA.z**2)
+    nees = []
+    for k in range(round(duration / DT)):
+        q = q * Quaternion.from_rotation(w * DT, OMEGA.x, OMEGA.y, OMEGA.z)
+        b = b + gauss(rng, RRW * math.sqrt(DT))
+        filter.propagate(OMEGA + b + gauss(rng, ARW / math.sqrt(DT)), DT)
+        if k % UPDATE == UPDATE - 1:
+            for r in REFERENCES:
+                m = r.normalize().rotate(q.inv())
+                m = Vector(*(c + rng.gauss(0, SIGMA) for c in (m.x, m.y, m.z)))
+                filter.update_vector(VectorMeasurement(m, r, SIGMA))
+        nees.append(filter.nees(q, b))
+    dq = q.inv() * filter.quaternion
+    return nees, 2 * math.asin(min(1.0, math.sqrt(dq.x**2 + dq.y**2 + dq.z**2)))
+
+
+def initial(cls, angle, attitude_sigma):
+    """Construct a filter with an initial attitude error of `angle`, and no bias estimate."""
+    q = Quaternion.from_rotation(angle, 1, -1, 2)
+    return cls(q, AngularVelocity(0, 0, 0), attitude_sigma, 1e-3, ARW, RRW)
+
+
+def mean(values):
+    return sum(values) / len(values)
+
+
+class TestMekf(unittest.TestCase):
+    def test_consistency(self):
+        # Averaged over runs, the NEES is near the dimension of the error state
+        nees = []
+        for seed in range(10):
+            filter = initial(estimation.Mekf, 0.01, 0.01)
+            result, _ = run(filter, 300, random.Random(seed))
+            nees.append(mean(result[-1000:]))
+        self.assertLess(abs(mean(nees) - 6), 2)
+
+    def test_large_initial_error(self):
+        # The attitude converges, but the filter is left inconsistent
+        filter = initial(estimation.Mekf, 2.0, 1.0)
+        nees, error = run(filter, 400, random.Random(0))
+        self.assertLess(error, 5 * SIGMA)
+        self.assertGreater(mean(nees[-1000:]), 20)
+
+
+class TestUsque(unittest.TestCase):
+    def test_consistency(self):
+        nees = []
+        for seed in range(10):
+            filter = initial(estimation.Usque, 0.01, 0.01)
+            result, _ = run(filter, 300, random.Random(seed))
+            nees.append(mean(result[-1000:]))
+        self.assertLess(abs(mean(nees) - 6), 2)
+
+    def test_large_initial_error(self):
+        # Unlike the MEKF, the USQUE recovers a consistent estimate
+        filter = initial(estimation.Usque, 2.0, 1.0)
+        nees, error = run(filter, 400, random.Random(0))
+        self.assertLess(error, 5 * SIGMA)
+        self.assertLess(mean(nees[-1000:]), 15)
+
+
+if __name__ == "__main__":
+    unittest.main()
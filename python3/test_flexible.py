@@ -0,0 +1,61 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import unittest
+
+from adcs import (
+    AngularMomentum,
+    AngularVelocity,
+    FlexibleModes,
+    Inertia,
+    State,
+    Vector,
+)
+
+from conservation import (
+    ConservationMixin,
+    dot,
+    momentum,
+    simulate,
+)
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+
+def initial(damping_ratio):
+    """Tumbling spacecraft with two deflected appendage modes."""
+    state = State(INERTIA)
+    state.angular_velocity = AngularVelocity(0.1, -0.2, 0.15)
+    modes = FlexibleModes([2.0, 5.0], [damping_ratio] * 2, [Vector(0.8, 0.1, 0), Vector(0, 0.3, 0.5)])
+    modes.displacements = [0.05, -0.02]
+    state.flexible = modes
+    return state
+
+
+class TestConservation(ConservationMixin, unittest.TestCase):
+    DAMPING = 0.02
+    MOMENTUM = 1e-10
+    ENERGY = 1e-7
+    DISSIPATED = 1e-3
+
+    def initial(self, damping_ratio):
+        return initial(damping_ratio)
+
+    def momentum(self, state):
+        """Total angular momentum (inertial frame) of the bus and the modes."""
+        return momentum(state, state.flexible.momentum())
+
+    def energy(self, state):
+        """Total kinetic and elastic energy of the bus and the modes."""
+        w = state.angular_velocity
+        rigid = 0.5 * dot(AngularMomentum.product(INERTIA, w), w)
+        return rigid + dot(state.flexible.momentum(), w) + state.flexible.energy()
+
+    def test_exchange(self):
+        # The modes exchange momentum with the bus
+        final = simulate(initial(0.0), self.ITERATIONS)
+        self.assertGreater(abs(final.flexible.momentum().x), 1e-3)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,217 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularMomentum,
+    Inertia,
+    Quaternion,
+    Vector,
+)
+from adcs import guidance
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+START = Quaternion.from_rotation(0.4, 0, 1, 1)
+END = Quaternion.from_rotation(2.0, 1, -2, 0.5) * START
+
+MAX_TORQUE = 0.1
+MAX_RATE = 0.05
+
+# Step of the finite differences (s)
+DT = 1e-3
+
+# Gravitational parameter of the Earth (m^3/s^2)
+MU = 3.986004418e14
+
+# Eccentric, inclined orbit, at perigee
+POSITION = Vector(6.9e6, 0, 0)
+VELOCITY = Vector(0, 7.8e3, 1.2e3)
+
+
+def angle(q):
+    """Rotation angle (rad) of a unit quaternion."""
+    return 2 * math.acos(min(1.0, abs(q.w)))
+
+
+def components(v):
+    return (v.x, v.y, v.z)
+
+
+def norm(v):
+    return math.sqrt(v.x**2 + v.y**2 + v.z**2)
+
+
+def unit(v):
+    return v.scale(1 / norm(v))
+
+
+def gravity(r):
+    return r.scale(-MU / norm(r)**3)
+
+
+def orbit(time, dt=1.0):
+    """Position and velocity of the body along its Keplerian orbit, by Runge-Kutta."""
+    r, v = POSITION, VELOCITY
+    n = max(1, round(abs(time) / dt))
+    h = time / n
+    for _ in range(n):
+        k1r, k1v = v, gravity(r)
+        k2r, k2v = v + k1v.scale(h / 2), gravity(r + k1r.scale(h / 2))
+        k3r, k3v = v + k2v.scale(h / 2), gravity(r + k2r.scale(h / 2))
+        k4r, k4v = v + k3v.scale(h), gravity(r + k3r.scale(h))
+        r = r + (k1r + k2r.scale(2) + k3r.scale(2) + k4r).scale(h / 6)
+        v = v + (k1v + k2v.scale(2) + k3v.scale(2) + k4v).scale(h / 6)
+    return r, v
+
+
+def differentiate(reference, time):
+    """Angular velocity (target frame) of a reference, by central differences."""
+    e = reference(time - DT).quaternion.inv() * reference(time + DT).quaternion
+    s = 1 if e.w >= 0 else -1
+    return tuple(s * c / DT for c in (e.x, e.y, e.z))
+
+
+class TestSlew(unittest.TestCase):
+    def slews(self):
+        for smooth in (False, True):
+            yield guidance.Slew(START, END, INERTIA, MAX_TORQUE, MAX_RATE, smooth, 10.0)
+
+    def times(self, slew, n=200):
+        return [slew.start_time + slew.duration * (i + 0.5) / n for i in range(n)]
+
+    def test_boundary_conditions(self):
+        for slew in self.slews():
+            self.assertAlmostEqual(slew.angle, 2.0)
+            for time, attitude in ((0.0, START), (slew.start_time, START), (slew.end_time, END), (1e3, END)):
+                reference = slew.sample(time)
+                self.assertAlmostEqual(angle(attitude.inv() * reference.quaternion), 0.0, delta=1e-7)
+                self.assertAlmostEqual(norm(reference.angular_velocity), 0.0, delta=1e-12)
+                self.assertAlmostEqual(norm(reference.angular_acceleration), 0.0, delta=1e-12)
+
+    def test_limits(self):
+        for slew in self.slews():
+            rates, torques = [], []
+            for time in self.times(slew):
+                reference = slew.sample(time)
+                rates.append(norm(reference.angular_velocity))
+                j = AngularMomentum.product(INERTIA, reference.angular_acceleration)
+                torques.append(max(abs(c) for c in components(j)))
+
+            # Each limit is reached, but not exceeded
+            self.assertAlmostEqual(max(rates), MAX_RATE)
+            self.assertAlmostEqual(max(torques), MAX_TORQUE, delta=1e-3 * MAX_TORQUE)
+            self.assertLessEqual(max(torques), MAX_TORQUE * (1 + 1e-12))
+
+    def test_short_slew(self):
+        # A small angle is too short to reach the rate limit
+        end = Quaternion.from_rotation(0.01, 1, 0, 0) * START
+        slew = guidance.Slew(START, end, INERTIA, MAX_TORQUE, MAX_RATE)
+        peak = max(norm(slew.sample(t).angular_velocity) for t in self.times(slew))
+        self.assertLess(peak, MAX_RATE)
+        self.assertAlmostEqual(angle(end.inv() * slew.sample(slew.end_time).quaternion), 0.0, delta=1e-9)
+
+    def test_consistency(self):
+        # The angular velocity is the derivative of the attitude, and the angular
+        # acceleration is the derivative of the angular velocity
+        for slew in self.slews():
+            for time in self.times(slew, 37):
+                reference = slew.sample(time)
+                for a, b in zip(components(reference.angular_velocity), differentiate(slew.sample, time)):
+                    self.assertAlmostEqual(a, b, delta=1e-6)
+
+                before = slew.sample(time - DT).angular_velocity
+                after = slew.sample(time + DT).angular_velocity
+                for a, b, c in zip(components(reference.angular_acceleration), components(before), components(after)):
+                    self.assertAlmostEqual(a, (c - b) / (2 * DT), delta=1e-6)
+
+    def test_smooth(self):
+        # The smoothed torque starts and ends at zero
+        slew = list(self.slews())[1]
+        for time in (slew.start_time + 1e-6, slew.end_time - 1e-6):
+            self.assertAlmostEqual(norm(slew.feedforward(time)), 0.0, delta=1e-6)
+
+    def test_invalid_limits(self):
+        with self.assertRaises(ValueError):
+            guidance.Slew(START, END, INERTIA, 0.0, MAX_RATE)
+        with self.assertRaises(ValueError):
+            guidance.Slew(START, END, INERTIA, MAX_TORQUE, -1.0)
+
+
+
+class TestPointing(unittest.TestCase):
+    def assertVector(self, a, b, delta=1e-9):
+        for x, y in zip(components(a), components(b)):
+            self.assertAlmostEqual(x, y, delta=delta)
+
+    def assertRates(self, sample, time, delta):
+        reference = sample(time)
+        self.assertVector(reference.angular_velocity, Vector(*differentiate(sample, time)), delta)
+
+        before = sample(time - DT).angular_velocity
+        after = sample(time + DT).angular_velocity
+        self.assertVector(reference.angular_acceleration, (after - before).scale(1 / (2 * DT)), delta)
+
+    def test_inertial_hold(self):
+        hold = guidance.InertialHold(Quaternion(2, 0, 0, 0))
+        reference = hold.sample()
+        self.assertEqual(reference.quaternion.w, 1.0)
+        self.assertEqual(norm(reference.angular_velocity), 0.0)
+        self.assertEqual(norm(reference.angular_acceleration), 0.0)
+
+    def test_sun_pointing(self):
+        sun = Vector(0.3, -1, 0.5)
+        pole = Vector(0, 0, 1)
+        mode = guidance.SunPointing(Vector(1, 0, 0), Vector(0, 1, 1), pole)
+        q = mode.sample(sun).quaternion
+
+        # The primary axis points at the sun, and the secondary axis lies in the plane
+        # of the sun and the secondary direction, on the side of the secondary direction
+        self.assertVector(Vector(1, 0, 0).rotate(q), unit(sun))
+        secondary = Vector(0, 1, 1).rotate(q)
+        self.assertAlmostEqual(secondary.dot(sun.cross(pole)), 0.0)
+        self.assertGreater(secondary.dot(pole), 0.0)
+
+        with self.assertRaises(ValueError):
+            mode.sample(pole)
+
+    def test_nadir_pointing(self):
+        mode = guidance.NadirPointing()
+        q = mode.sample(POSITION, VELOCITY).quaternion
+        self.assertVector(Vector(0, 0, 1).rotate(q), -unit(POSITION))
+        self.assertVector(Vector(0, -1, 0).rotate(q), unit(POSITION.cross(VELOCITY)))
+
+        # The rates hold along an eccentric orbit, with an offset
+        mode.offset = Quaternion.from_rotation(0.3, 1, 1, 0)
+        sample = lambda t: mode.sample(*orbit(t))
+        for time in (0.0, 600.0, 1500.0):
+            self.assertRates(sample, time, 1e-8)
+
+        # The LVLH Z axis, expressed in the target frame, still points at nadir
+        q = mode.sample(POSITION, VELOCITY).quaternion
+        self.assertVector(Vector(0, 0, 1).rotate(mode.offset.inv()).rotate(q), -unit(POSITION))
+
+    def test_target_tracking(self):
+        # With the target directly below, tracking matches nadir pointing
+        mode = guidance.TargetTracking(0.0, 0.0)
+        tracking = mode.sample(0.0, POSITION, VELOCITY).quaternion
+        nadir = guidance.NadirPointing().sample(POSITION, VELOCITY).quaternion
+        self.assertAlmostEqual(angle(nadir.inv() * tracking), 0.0, delta=1e-7)
+
+        # The boresight follows the target as the Earth and the body move
+        mode = guidance.TargetTracking(0.3, 0.5, 100.0, Vector(1, 0, 0), Vector(0, 0, 1), 0.2)
+        sample = lambda t: mode.sample(t, *orbit(t))
+        for time in (0.0, 200.0, 400.0):
+            r, _ = orbit(time)
+            q = sample(time).quaternion
+            self.assertVector(Vector(1, 0, 0).rotate(q), unit(mode.target(time) - r))
+            self.assertRates(sample, time, 1e-7)
+
+        # The target lies on the rotating Earth
+        self.assertAlmostEqual(norm(mode.target(0.0)), norm(mode.target(3600.0)))
+
+
+if __name__ == "__main__":
+    unittest.main()
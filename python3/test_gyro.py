@@ -0,0 +1,76 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    Inertia,
+    State,
+)
+from adcs import sensors
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+# Number of independent gyros in each ensemble
+GYROS = 1000
+
+
+def at(time):
+    state = State(INERTIA)
+    state.time = time
+    return state
+
+
+def deviation(values):
+    mean = sum(values) / len(values)
+    return math.sqrt(sum((v - mean)**2 for v in values) / (len(values) - 1))
+
+
+class TestPolling(unittest.TestCase):
+    def test_rate_random_walk(self):
+        # Polled once after 100 s, a 10 Hz gyro accumulates 100 s of random walk
+        biases = []
+        for seed in range(GYROS):
+            gyro = sensors.Gyro(10.0, seed=seed)
+            gyro.rrw = 1e-3
+            gyro.measure(at(0.0))
+            gyro.measure(at(100.0))
+            biases.append(gyro.true_bias.x)
+
+        self.assertAlmostEqual(deviation(biases), 1e-3 * math.sqrt(100.0), delta=1e-3)
+
+    def test_gauss_markov(self):
+        # Polled after many correlation times, the bias is drawn from the steady state
+        biases = []
+        for seed in range(GYROS):
+            gyro = sensors.Gyro(10.0, seed=seed)
+            gyro.bias_instability = 1e-3
+            gyro.correlation_time = 1.0
+            gyro.measure(at(0.0))
+            gyro.measure(at(50.0))
+            biases.append(gyro.true_bias.y)
+
+        self.assertAlmostEqual(deviation(biases), 1e-3, delta=1e-4)
+
+    def test_angle_random_walk(self):
+        # The rate noise averages over the time since the previous sample
+        for period in (0.1, 4.0):
+            rates = []
+            for seed in range(GYROS):
+                gyro = sensors.Gyro(10.0, seed=seed)
+                gyro.arw = 1e-3
+                gyro.measure(at(0.0))
+                rates.append(gyro.measure(at(period)).z)
+
+            self.assertAlmostEqual(deviation(rates) * math.sqrt(period), 1e-3, delta=1e-4)
+
+    def test_not_due(self):
+        gyro = sensors.Gyro(10.0)
+        self.assertIsNotNone(gyro.measure(at(0.0)))
+        self.assertIsNone(gyro.measure(at(0.05)))
+        self.assertIsNotNone(gyro.measure(at(0.1)))
+
+
+if __name__ == "__main__":
+    unittest.main()
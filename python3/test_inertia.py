@@ -0,0 +1,172 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import unittest
+
+from adcs import (
+    Inertia,
+    Quaternion,
+    Vector,
+)
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+
+def matrix(j):
+    return [
+        [j.j1, j.j6, j.j5],
+        [j.j6, j.j2, j.j4],
+        [j.j5, j.j4, j.j3],
+    ]
+
+
+def matmul(a, b):
+    return [[sum(x * y for x, y in zip(row, col)) for col in zip(*b)] for row in a]
+
+
+class TestInertia(unittest.TestCase):
+    def assertInertia(self, actual, expected, places=9):
+        for a, e in zip(sum(matrix(actual), []), sum(matrix(expected), [])):
+            self.assertAlmostEqual(a, e, places=places)
+
+    def test_principal(self):
+        (a, b, c), q = INERTIA.principal()
+        self.assertLessEqual(a, b)
+        self.assertLessEqual(b, c)
+
+        # The diagonal tensor, rotated from the principal frame, is the original
+        self.assertInertia(Inertia(a, b, c, 0, 0, 0).rotate(q), INERTIA)
+
+        # The trace is invariant
+        self.assertAlmostEqual(a + b + c, INERTIA.j1 + INERTIA.j2 + INERTIA.j3)
+
+    def test_inverse(self):
+        product = matmul(matrix(INERTIA), matrix(INERTIA.inverse()))
+        for i in range(3):
+            for j in range(3):
+                self.assertAlmostEqual(product[i][j], float(i == j))
+
+    def test_rotate(self):
+        q = Quaternion.from_rotation(0.7, 1, -2, 0.5)
+
+        # Rotation preserves the principal moments, and is undone by the inverse
+        rotated = INERTIA.rotate(q)
+        for a, b in zip(rotated.principal()[0], INERTIA.principal()[0]):
+            self.assertAlmostEqual(a, b)
+        self.assertInertia(rotated.rotate(q.inv()), INERTIA)
+
+        # A quarter turn about z swaps the x and y axes
+        quarter = Inertia(1, 2, 3, 0, 0, 0).rotate(Quaternion.from_rotation(1.5707963267948966, 0, 0, 1))
+        self.assertInertia(quarter, Inertia(2, 1, 3, 0, 0, 0))
+
+    def test_shift(self):
+        # J + m * (|r|^2 I - r r.T)
+        m, r = 3.0, Vector(0.5, -1.0, 2.0)
+        shifted = INERTIA.shift(m, r)
+        offset = [r.x, r.y, r.z]
+        expected = [
+            [
+                matrix(INERTIA)[i][j] + m * ((r.x**2 + r.y**2 + r.z**2) * (i == j) - offset[i] * offset[j])
+                for j in range(3)
+            ]
+            for i in range(3)
+        ]
+        for row, e in zip(matrix(shifted), expected):
+            for a, b in zip(row, e):
+                self.assertAlmostEqual(a, b)
+
+    def test_composite(self):
+        # Two identical parts, placed symmetrically, have no products of inertia
+        part = Inertia(2, 2, 2, 0, 0, 0)
+        r = Vector(1, 1, 0)
+        total = part.shift(1.0, r) + part.shift(1.0, -r)
+        self.assertInertia(total, Inertia(6, 6, 8, 0, 0, -2))
+
+        # Addition is componentwise, and commutes with rotation
+        q = Quaternion.from_rotation(0.3, 0, 1, 1)
+        self.assertInertia((total + INERTIA).rotate(q), total.rotate(q) + INERTIA.rotate(q))
+
+
+class TestValidation(unittest.TestCase):
+    def test_singular(self):
+        with self.assertRaises(ValueError):
+            Inertia(1, 1, 0, 0, 0, 0)
+        with self.assertRaises(ValueError):
+            Inertia(1, 1, 1, 0, 0, 1)
+        with self.assertRaises(ValueError):
+            Inertia.point_mass(1.0, Vector(1, 0, 0)).inverse()
+
+    def test_triangle_inequality(self):
+        with self.assertRaises(ValueError):
+            Inertia(1, 1, 2.5, 0, 0, 0)
+
+        # A thin plate lies on the boundary
+        Inertia(1, 2, 3, 0, 0, 0)
+
+    def test_non_finite(self):
+        with self.assertRaises(ValueError):
+            Inertia(float("nan"), 1, 1, 0, 0, 0)
+        with self.assertRaises(ValueError):
+            Inertia(float("inf"), 1, 1, 0, 0, 0)
+
+    def test_setters(self):
+        j = Inertia(2, 2, 2, 0, 0, 0)
+        with self.assertRaises(ValueError):
+            j.j3 = 5
+        with self.assertRaises(ValueError):
+            j.j6 = 3
+        self.assertEqual(j.j3, 2)
+        self.assertEqual(j.j6, 0)
+
+        j.j3 = 3
+        j.j6 = 0.5
+        self.assertEqual((j.j3, j.j6), (3, 0.5))
+
+
+class TestShapes(unittest.TestCase):
+    def assertMoments(self, j, expected):
+        for a, e in zip((j.j1, j.j2, j.j3, j.j4, j.j5, j.j6), expected + (0, 0, 0)):
+            self.assertAlmostEqual(a, e)
+
+    def test_box(self):
+        m, a, b, c = 6.0, 1.0, 2.0, 3.0
+        self.assertMoments(Inertia.box(m, a, b, c), (
+            m * (b**2 + c**2) / 12,
+            m * (a**2 + c**2) / 12,
+            m * (a**2 + b**2) / 12,
+        ))
+
+        # Two cubes side by side make a box
+        cube = Inertia.box(m, a, a, a)
+        pair = cube.shift(m, Vector(a / 2, 0, 0)) + cube.shift(m, Vector(-a / 2, 0, 0))
+        expected = Inertia.box(2 * m, 2 * a, a, a)
+        self.assertMoments(pair, (expected.j1, expected.j2, expected.j3))
+
+    def test_cylinder(self):
+        m, r, h = 4.0, 0.5, 2.0
+        transverse = m * (3 * r**2 + h**2) / 12
+        self.assertMoments(Inertia.cylinder(m, r, h), (transverse, transverse, m * r**2 / 2))
+
+    def test_spheres(self):
+        m, r = 3.0, 2.0
+        self.assertMoments(Inertia.sphere(m, r), (0.4 * m * r**2,) * 3)
+        self.assertMoments(Inertia.hollow_sphere(m, r), (2 * m * r**2 / 3,) * 3)
+
+    def test_point_mass(self):
+        j = Inertia.point_mass(2.0, Vector(1, 2, 0))
+        self.assertAlmostEqual(j.j1, 8)
+        self.assertAlmostEqual(j.j2, 2)
+        self.assertAlmostEqual(j.j3, 10)
+        self.assertAlmostEqual(j.j6, -4)
+        self.assertAlmostEqual(j.determinant(), 0)
+
+    def test_invalid_dimensions(self):
+        for args in ((0, 1, 1, 1), (1, -1, 1, 1), (1, 1, float("nan"), 1)):
+            with self.assertRaises(ValueError):
+                Inertia.box(*args)
+        with self.assertRaises(ValueError):
+            Inertia.sphere(1, 0)
+
+
+if __name__ == "__main__":
+    unittest.main()
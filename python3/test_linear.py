@@ -0,0 +1,175 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    State,
+    Torque,
+    Vector,
+)
+from adcs import control
+from adcs import integrators as it
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+# Orbital rate of a low Earth orbit (rad/s)
+RATE = 0.0011
+
+# Equilibria: inertial hold, spin, and nadir pointing in a circular orbit
+EQUILIBRIA = [
+    (AngularVelocity(0, 0, 0), None),
+    (AngularVelocity(0.01, -0.02, 0.015), None),
+    (AngularVelocity(0, -RATE, 0), Vector(0, 0, 1)),
+]
+
+Q = [[float(i == j) * (10 if i < 3 else 1) for j in range(6)] for i in range(6)]
+R = [[1, 0, 0], [0, 2, 0], [0, 0, 3]]
+
+
+def state(angular_velocity):
+    s = State(INERTIA)
+    s.angular_velocity = angular_velocity
+    return s
+
+
+def matmul(a, b):
+    return [[sum(x * y for x, y in zip(row, col)) for col in zip(*b)] for row in a]
+
+
+def transpose(a):
+    return [list(col) for col in zip(*a)]
+
+
+def add(*ms):
+    return [[sum(xs) for xs in zip(*rows)] for rows in zip(*ms)]
+
+
+def scale(a, s):
+    return [[s * x for x in row] for row in a]
+
+
+def largest(a):
+    return max(abs(x) for row in a for x in row)
+
+
+def closed_loop(system, gain):
+    return add(system.a, scale(matmul(system.b, gain), -1))
+
+
+class TestLinearize(unittest.TestCase):
+    def test_analytic_matches_numeric(self):
+        for w, nadir in EQUILIBRIA:
+            analytic = control.linearize(state(w), nadir)
+            numeric = control.linearize(state(w), nadir, numeric=True)
+            self.assertLess(largest(add(analytic.a, scale(numeric.a, -1))), 1e-11)
+            self.assertLess(largest(add(analytic.b, scale(numeric.b, -1))), 1e-11)
+
+
+class TestLqr(unittest.TestCase):
+    def test_continuous(self):
+        for w, nadir in EQUILIBRIA:
+            system = control.linearize(state(w), nadir)
+            a, b = system.a, system.b
+            k, p = control.lqr(system, Q, R)
+
+            # A.T @ P + P @ A - P @ B @ inv(R) @ B.T @ P + Q == 0, with K = inv(R) @ B.T @ P
+            residual = add(matmul(transpose(a), p), matmul(p, a), scale(matmul(transpose(k), matmul(R, k)), -1), Q)
+            self.assertLess(largest(residual), 1e-8 * largest(p))
+            self.assertLess(largest(add(matmul(R, k), scale(matmul(transpose(b), p), -1))), 1e-8 * largest(k))
+
+            # The closed loop A - B @ K decays
+            transition = control.StateSpace(closed_loop(system, k), b).discretize(200.0)
+            self.assertLess(largest(transition.a), 1e-3)
+
+    def test_discrete(self):
+        for w, nadir in EQUILIBRIA:
+            system = control.linearize(state(w), nadir).discretize(0.5)
+            a = system.a
+            k, p = control.lqr(system, Q, R)
+
+            # P == A.T @ P @ (A - B @ K) + Q
+            residual = add(matmul(transpose(a), matmul(p, closed_loop(system, k))), Q, scale(p, -1))
+            self.assertLess(largest(residual), 1e-8 * largest(p))
+
+            # The closed loop A - B @ K decays
+            transition = closed_loop(system, k)
+            for _ in range(9):
+                transition = matmul(transition, transition)
+            self.assertLess(largest(transition), 1e-3)
+
+    def test_ill_conditioned_input_weight(self):
+        # A nearly free input makes the Hamiltonian badly scaled, but the solution exists
+        system = control.linearize(state(AngularVelocity(0, 0, 0)))
+        gains = []
+        for cheap in (1e-12, 1e-14):
+            weight = [[1, 0, 0], [0, 1, 0], [0, 0, cheap]]
+            k, p = control.lqr(system, Q, weight)
+            gains.append(largest(k))
+
+            # R @ K == B.T @ P
+            b = system.b
+            self.assertLess(largest(add(matmul(weight, k), scale(matmul(transpose(b), p), -1))), 1e-8 * largest(k))
+
+            # The closed loop A - B @ K decays
+            transition = control.StateSpace(closed_loop(system, k), b).discretize(200.0)
+            self.assertLess(largest(transition.a), 1e-3)
+
+        # The gain of the cheap input grows as the inverse square root of its weight
+        self.assertAlmostEqual(gains[1] / gains[0], 10.0, delta=0.01)
+
+    def test_indefinite_input_weight(self):
+        system = control.linearize(state(AngularVelocity(0, 0, 0)))
+        with self.assertRaises(ValueError):
+            control.lqr(system, Q, [[1, 0, 0], [0, 1, 0], [0, 0, -1]])
+
+
+
+class TestStateFeedback(unittest.TestCase):
+    def test_torque(self):
+        gain = [[float(i + 2 * j) for j in range(6)] for i in range(3)]
+        trim = Torque(0.1, -0.2, 0.3)
+        controller = control.StateFeedback(gain, None, AngularVelocity(0, 0, 0.01), trim)
+
+        # u = trim - K @ [q.vector, omega - omega_target], with the target rate
+        # expressed in the body frame
+        q = Quaternion.from_rotation(0.2, 1, 2, 3)
+        w = AngularVelocity(0.01, 0.02, 0.03)
+        r = AngularVelocity(0, 0, 0.01).rotate(q.inv())
+        x = [q.x, q.y, q.z, w.x - r.x, w.y - r.y, w.z - r.z]
+        u = matmul(gain, [[c] for c in x])
+        torque = controller.torque(q, w)
+        for a, t, k in zip((torque.x, torque.y, torque.z), (trim.x, trim.y, trim.z), u):
+            self.assertAlmostEqual(a, t - k[0])
+
+    def test_closed_loop(self):
+        # The regulator of the linearized dynamics stabilizes the nonlinear dynamics
+        k, _ = control.lqr(control.linearize(state(AngularVelocity(0, 0, 0))), Q, R)
+        target = Quaternion.from_rotation(0.5, 0, 1, 0)
+        controller = control.StateFeedback(k, target)
+
+        s = state(AngularVelocity(0.02, -0.01, 0.03))
+        integrator = it.RungeKutta4(0.1)
+        for _ in range(1000):
+            s.torque = controller.torque(s.quaternion, s.angular_velocity)
+            s = integrator.step(s)
+
+        error = target.inv() * s.quaternion
+        self.assertLess(math.sqrt(error.x**2 + error.y**2 + error.z**2), 1e-6)
+        self.assertLess(math.sqrt(s.angular_velocity.x**2 + s.angular_velocity.y**2 + s.angular_velocity.z**2), 1e-6)
+
+    def test_invalid_gain(self):
+        with self.assertRaises(ValueError):
+            control.StateFeedback([[1] * 6] * 2)
+        with self.assertRaises(ValueError):
+            control.StateFeedback([[1] * 3] * 3)
+        with self.assertRaises(ValueError):
+            control.StateFeedback([[1] * 6] * 3, Quaternion(0, 0, 0, 0))
+
+
+if __name__ == "__main__":
+    unittest.main()
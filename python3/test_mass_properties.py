@@ -0,0 +1,67 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    Inertia,
+    MassProperties,
+    Quaternion,
+    Vector,
+)
+
+
+class TestMassProperties(unittest.TestCase):
+    def assertInertia(self, actual, expected):
+        for a, e in zip(
+            (actual.j1, actual.j2, actual.j3, actual.j4, actual.j5, actual.j6),
+            (expected.j1, expected.j2, expected.j3, expected.j4, expected.j5, expected.j6),
+        ):
+            self.assertAlmostEqual(a, e)
+
+    def test_assembly(self):
+        # A bus with two spherical tanks, offset along x
+        bus = MassProperties(100.0, Inertia.box(100.0, 1, 1, 2))
+        tank = Inertia.sphere(10.0, 0.2)
+        left = MassProperties(10.0, tank, Vector(-0.6, 0, 0.5))
+        right = MassProperties(10.0, tank, Vector(0.6, 0, 0.5))
+        total = MassProperties.combine([bus, left, right])
+
+        self.assertAlmostEqual(total.mass, 120.0)
+        c = total.center_of_mass
+        self.assertAlmostEqual(c.x, 0)
+        self.assertAlmostEqual(c.z, 20 * 0.5 / 120)
+
+        # Parallel-axis theorem about the combined centre of mass
+        d = 0.5 - c.z
+        expected = (
+            Inertia.box(100.0, 1, 1, 2).shift(100.0, Vector(0, 0, -c.z))
+            + tank.shift(10.0, Vector(-0.6, 0, d))
+            + tank.shift(10.0, Vector(0.6, 0, d))
+        )
+        self.assertInertia(total.inertia, expected)
+
+        # About another point
+        self.assertInertia(total.inertia_about(Vector(0, 0, 0)), total.inertia.shift(120.0, c))
+
+    def test_orientation(self):
+        # A rod along its part z axis, turned to lie along the body x axis
+        rod = Inertia.cylinder(2.0, 0.01, 1.0)
+        turn = Quaternion.from_rotation(math.pi / 2, 0, 1, 0)
+        part = MassProperties(2.0, rod, None, turn)
+        self.assertAlmostEqual(part.inertia.j1, rod.j3)
+        self.assertAlmostEqual(part.inertia.j3, rod.j1)
+
+    def test_combine(self):
+        self.assertIsNone(MassProperties.combine([]))
+
+        part = MassProperties(1.0, Inertia.sphere(1.0, 1.0), Vector(1, 2, 3))
+        self.assertInertia((part + part).inertia, Inertia.sphere(2.0, 1.0))
+
+        with self.assertRaises(ValueError):
+            MassProperties(0.0, Inertia.sphere(1.0, 1.0))
+
+
+if __name__ == "__main__":
+    unittest.main()
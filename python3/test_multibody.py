@@ -0,0 +1,101 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Body,
+    Inertia,
+    Multibody,
+    Simulation,
+    State,
+    Vector,
+)
+from adcs import actuators
+from adcs import control
+from adcs import integrators as it
+
+from conservation import (
+    MomentumMixin,
+    momentum,
+    simulate,
+)
+
+# Inertia of the bus, excluding the bodies
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+MAX_TORQUE = 0.2
+
+
+def articulated():
+    """Bus carrying a two-link arm: a yaw joint on the bus, and a pitch joint on the first link."""
+    state = State(INERTIA)
+    state.multibody = Multibody(100.0, [
+        Body(5.0, Inertia(0.5, 0.4, 0.3, 0, 0, 0), Vector(0.8, 0, 0.3), Vector(0, 0, 1), Vector(0.3, 0, 0)),
+        Body(2.0, Inertia(0.1, 0.2, 0.2, 0, 0, 0), Vector(0.6, 0, 0), Vector(0, 1, 0), Vector(0.2, 0.1, 0), 0),
+    ])
+    return state
+
+
+class JointServo(control.Controller):
+    """Proportional-derivative servo of the joint angles."""
+
+    def __init__(self, target):
+        self.target = target
+
+    def update(self, time, measurements):
+        angles, rates = measurements["joints"]
+        return {"motors": [2.0 * (t - a) - 3.0 * r for t, a, r in zip(self.target, angles, rates)]}
+
+
+class TestMultibody(MomentumMixin, unittest.TestCase):
+    def momentum(self, state):
+        """Total angular momentum (inertial frame) of the bus and the bodies."""
+        return momentum(state, state.multibody.momentum(state.angular_velocity))
+
+    def test_momentum_under_joint_torques(self):
+        # Joint torques are internal, so they exchange momentum between the bodies
+        state = articulated()
+        state.angular_velocity = AngularVelocity(0.05, -0.1, 0.08)
+        state.multibody.rates = [0.2, -0.1]
+        state.multibody.torques = [0.05, -0.03]
+
+        final = simulate(state, 1000)
+
+        self.assertMomentum(state, final, 1e-9)
+        self.assertNotAlmostEqual(final.multibody.rates[1], -0.1)
+
+    def test_simulation_with_joint_motors(self):
+        state = articulated()
+        target = [0.5, -0.3]
+
+        simulation = Simulation(it.RungeKutta4(0.05), JointServo(target), 20.0)
+        simulation.add_sensor("joints", lambda s: (s.multibody.angles, s.multibody.rates))
+        simulation.add_actuator("motors", actuators.JointMotors(MAX_TORQUE))
+        log = simulation.run(state, 30.0)
+        final = log.states[-1]
+
+        for angle, t in zip(final.multibody.angles, target):
+            self.assertAlmostEqual(angle, t, delta=1e-6)
+
+        # The motors saturate the initial commands
+        self.assertEqual(log.commands[0][1]["motors"], [1.0, -0.6])
+        torques = [abs(t) for s in log.states for t in s.multibody.torques]
+        self.assertAlmostEqual(max(torques), MAX_TORQUE)
+
+        # The bus reacts to the arm, conserving momentum
+        self.assertLess(final.quaternion.w, 0.999)
+        self.assertMomentum(state, final, 1e-8)
+
+    def test_invalid_torque_limit(self):
+        for limit in (0.0, -1.0, float("nan")):
+            with self.assertRaises(ValueError):
+                actuators.JointMotors(limit)
+            motors = actuators.JointMotors()
+            with self.assertRaises(ValueError):
+                motors.max_torque = limit
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,107 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    State,
+)
+from adcs import control
+from adcs import guidance
+from adcs import integrators as it
+
+DT = 0.01
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+
+def angle(q):
+    """Rotation angle (rad) of a unit quaternion."""
+    return 2 * math.acos(min(1.0, abs(q.w)))
+
+
+def simulate(controller, state, time):
+    """Run a controller against the dynamics, returning the states at each step."""
+    integrator = it.RungeKutta4(DT)
+    states = [state]
+    for _ in range(round(time / DT)):
+        state.torque = controller.torque(state.quaternion, state.angular_velocity)
+        state = integrator.step(state)
+        states.append(state)
+    return states
+
+
+class TestGains(unittest.TestCase):
+    def test_natural_frequency(self):
+        inertia = Inertia(10, 20, 30, 0, 0, 0)
+        kp, kd = control.QuaternionPd.gains(inertia, (0.1, 0.2, 0.3), (0.5, 0.7, 1.0))
+        for k, expected in zip(kp, (2 * 10 * 0.01, 2 * 20 * 0.04, 2 * 30 * 0.09)):
+            self.assertAlmostEqual(k, expected)
+        for k, expected in zip(kd, (2 * 0.5 * 0.1 * 10, 2 * 0.7 * 0.2 * 20, 2 * 1.0 * 0.3 * 30)):
+            self.assertAlmostEqual(k, expected)
+
+        pd = control.QuaternionPd.from_natural_frequency(inertia, (0.1, 0.2, 0.3), (0.5, 0.7, 1.0))
+        self.assertEqual((pd.kp, pd.kd), (kp, kd))
+
+    def test_invalid(self):
+        with self.assertRaises(ValueError):
+            control.QuaternionPd.gains(INERTIA, (0.1, 0, 0.1), (1, 1, 1))
+        with self.assertRaises(ValueError):
+            control.QuaternionPd.gains(INERTIA, (0.1, 0.1, 0.1), (1, -1, 1))
+        with self.assertRaises(ValueError):
+            control.QuaternionPd(INERTIA, (1, 1, 1), (1, 1, 0))
+
+
+class TestClosedLoop(unittest.TestCase):
+    def test_second_order_response(self):
+        # For a small error about a principal axis, the response is that of a
+        # critically damped second-order system
+        wn, theta = 0.5, 0.01
+        inertia = Inertia(10, 20, 30, 0, 0, 0)
+        pd = control.QuaternionPd.from_natural_frequency(inertia, (wn,) * 3, (1.0,) * 3)
+        state = State(inertia)
+        state.quaternion = Quaternion.from_rotation(theta, 0, 1, 0)
+
+        for i, s in enumerate(simulate(pd, state, 20.0)):
+            t = i * DT
+            expected = theta * (1 + wn * t) * math.exp(-wn * t)
+            self.assertAlmostEqual(angle(s.quaternion), expected, delta=2e-3 * theta)
+
+    def test_large_slew(self):
+        target = Quaternion.from_rotation(2.5, 1, -2, 0.5)
+        pd = control.QuaternionPd.from_natural_frequency(INERTIA, (0.2,) * 3, (0.9,) * 3, target)
+        state = State(INERTIA)
+        state.angular_velocity = AngularVelocity(0.05, -0.02, 0.03)
+
+        final = simulate(pd, state, 120.0)[-1]
+        self.assertLess(angle(target.inv() * final.quaternion), 1e-6)
+        w = final.angular_velocity
+        self.assertLess(abs(w.x) + abs(w.y) + abs(w.z), 1e-6)
+
+    def test_tracking(self):
+        # With feedforward, the body follows a target spinning about a skewed axis
+        rate = 0.05
+        axis = (1, 2, 2)
+        pd = control.QuaternionPd.from_natural_frequency(INERTIA, (0.3,) * 3, (0.9,) * 3)
+        w = AngularVelocity(*(rate * a / 3 for a in axis))
+
+        state = State(INERTIA)
+        integrator = it.RungeKutta4(DT)
+        for _ in range(round(100.0 / DT)):
+            target = Quaternion.from_rotation(rate * state.time, *axis)
+            pd.track(guidance.Reference(target, w))
+            state.torque = pd.torque(state.quaternion, state.angular_velocity)
+            state = integrator.step(state)
+
+        target = Quaternion.from_rotation(rate * state.time, *axis)
+        self.assertLess(angle(target.inv() * state.quaternion), 1e-5)
+        for a, b in zip((state.angular_velocity.x, state.angular_velocity.y, state.angular_velocity.z), (w.x, w.y, w.z)):
+            self.assertAlmostEqual(a, b, delta=1e-6)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,153 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Simulation,
+    State,
+)
+from adcs import control
+from adcs import guidance
+from adcs import integrators as it
+
+DT = 0.05
+TIME = 120
+ITERATIONS = int(TIME // DT)
+
+# True inertia of the body
+TRUE_INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+# Nominal inertia assumed by the controllers, with large errors
+NOMINAL_INERTIA = Inertia(8, 19, 12, 0, 0, 0)
+
+# Target of the large slew, 150 degrees about a skewed axis
+TARGET = Quaternion(math.cos(math.radians(75)), *(
+    math.sin(math.radians(75)) * c / math.sqrt(3) for c in (1, -1, 1)
+))
+
+
+def angle(q):
+    """Rotation angle (rad) of a unit quaternion."""
+    return 2 * math.acos(min(1.0, abs(q.w)))
+
+
+def rate(w):
+    return math.sqrt(w.x**2 + w.y**2 + w.z**2)
+
+
+def simulate(controller, adaptive=False, reference=None):
+    """Run a controller against the true dynamics, returning the final state."""
+    state = State(TRUE_INERTIA)
+    integrator = it.RungeKutta4(DT)
+
+    for _ in range(ITERATIONS):
+        if reference is not None:
+            controller.track(reference(state.time))
+        if adaptive:
+            controller.adapt(state.quaternion, state.angular_velocity, DT)
+        state.torque = controller.torque(state.quaternion, state.angular_velocity)
+        state = integrator.step(state)
+
+    return state
+
+
+def closed_loop(controller):
+    """Run a controller in a simulation of the true dynamics, with ideal sensors and
+    actuator, returning the final state."""
+    simulation = Simulation(it.RungeKutta4(DT), controller, 1 / DT)
+    simulation.add_sensor("attitude", lambda state: state.quaternion)
+    simulation.add_sensor("rate", lambda state: state.angular_velocity)
+    simulation.add_actuator("torque", lambda state, command: command)
+
+    return simulation.run(State(TRUE_INERTIA), TIME).states[-1]
+
+
+class TestSlidingMode(unittest.TestCase):
+    def test_large_slew_with_mismatched_inertia(self):
+        controller = control.SlidingMode(NOMINAL_INERTIA, 0.2, (2.0, 2.0, 2.0), 0.01, TARGET)
+        state = simulate(controller)
+
+        self.assertLess(angle(TARGET.inv() * state.quaternion), math.radians(0.5))
+        self.assertLess(rate(state.angular_velocity), 1e-3)
+
+    def test_simulation_with_mismatched_inertia(self):
+        controller = control.SlidingMode(NOMINAL_INERTIA, 0.2, (2.0, 2.0, 2.0), 0.01, TARGET)
+        state = closed_loop(controller)
+
+        self.assertLess(angle(TARGET.inv() * state.quaternion), math.radians(0.5))
+        self.assertLess(rate(state.angular_velocity), 1e-3)
+
+    def test_spin_tracking_with_mismatched_inertia(self):
+        spin = AngularVelocity(0.05, -0.1, 0.08)
+        controller = control.SlidingMode(NOMINAL_INERTIA, 0.2, (2.0, 2.0, 2.0), 0.01)
+        reference = lambda t: guidance.Reference(
+            Quaternion.from_rotation(rate(spin) * t, spin.x, spin.y, spin.z),
+            spin,
+        )
+        state = simulate(controller, reference=reference)
+
+        error = reference(state.time).quaternion.inv() * state.quaternion
+        self.assertLess(angle(error), math.radians(0.5))
+
+    def test_boundary_layer(self):
+        controller = control.SlidingMode(NOMINAL_INERTIA, 0.2, (1.0, 1.0, 1.0), 0.1)
+        q = Quaternion(1, 0, 0, 0)
+
+        # At the target attitude, s = omega and J @ omega_r_dot = -0.1 * J @ omega
+        feedforward = lambda w: -0.1 * NOMINAL_INERTIA.j1 * w
+
+        # Outside the boundary layer, the switching torque saturates at the gain
+        t = controller.torque(q, AngularVelocity(1.0, 0, 0))
+        self.assertAlmostEqual(t.x, feedforward(1.0) - 1.0)
+
+        # Inside, it is linear in the sliding variable
+        t = controller.torque(q, AngularVelocity(0.05, 0, 0))
+        self.assertAlmostEqual(t.x, feedforward(0.05) - 0.5)
+
+
+class TestAdaptiveControl(unittest.TestCase):
+    def test_large_slew_with_mismatched_inertia(self):
+        controller = control.AdaptiveControl(NOMINAL_INERTIA, 0.2, (5.0, 5.0, 5.0), 2000.0, TARGET)
+        state = simulate(controller, adaptive=True)
+
+        self.assertLess(angle(TARGET.inv() * state.quaternion), math.radians(0.5))
+        self.assertLess(rate(state.angular_velocity), 1e-3)
+
+    def test_simulation_with_mismatched_inertia(self):
+        controller = control.AdaptiveControl(NOMINAL_INERTIA, 0.2, (5.0, 5.0, 5.0), 2000.0, TARGET)
+        state = closed_loop(controller)
+
+        self.assertLess(angle(TARGET.inv() * state.quaternion), math.radians(0.5))
+        self.assertLess(rate(state.angular_velocity), 1e-3)
+
+        # The estimate was adapted during the run
+        j = controller.inertia
+        self.assertNotEqual((j.j1, j.j2, j.j3, j.j4, j.j5, j.j6), (8, 19, 12, 0, 0, 0))
+
+    def test_spin_tracking_with_mismatched_inertia(self):
+        spin = AngularVelocity(0.05, -0.1, 0.08)
+        controller = control.AdaptiveControl(NOMINAL_INERTIA, 0.2, (5.0, 5.0, 5.0), 2000.0)
+        reference = lambda t: guidance.Reference(
+            Quaternion.from_rotation(rate(spin) * t, spin.x, spin.y, spin.z),
+            spin,
+        )
+        state = simulate(controller, adaptive=True, reference=reference)
+
+        error = reference(state.time).quaternion.inv() * state.quaternion
+        self.assertLess(angle(error), math.radians(0.1))
+
+    def test_adaptation_stops_on_target(self):
+        controller = control.AdaptiveControl(NOMINAL_INERTIA, 0.2, (5.0, 5.0, 5.0), 2000.0)
+        controller.adapt(Quaternion(1, 0, 0, 0), AngularVelocity(0, 0, 0), DT)
+
+        j = controller.inertia
+        self.assertEqual((j.j1, j.j2, j.j3, j.j4, j.j5, j.j6), (8, 19, 12, 0, 0, 0))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,118 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Inertia,
+    Rotor,
+    State,
+    Vector,
+)
+
+from conservation import (
+    MomentumMixin,
+    momentum,
+    simulate,
+)
+
+# Principal moments chosen so that each is either larger or smaller than the mean of the
+# other two, where the axisymmetric approximation agrees with the major-axis rule
+INERTIA = Inertia(10, 12, 20, 0, 0, 0)
+MAJOR = 2
+
+AXES = [(1, 0, 0), (0, 1, 0), (0, 0, 1)]
+
+SPIN = 0.5
+
+
+class TestStability(unittest.TestCase):
+    def test_major_axis_rule(self):
+        # With the rotor at rest relative to the platform, the spacecraft is a single
+        # spinner, stable with energy dissipation only about its major axis
+        for k, axis in enumerate(AXES):
+            rotor = Rotor(Vector(*axis), 0.5)
+            w = AngularVelocity(*(SPIN * c for c in axis))
+            for dissipation in ((1.0, 0.0), (0.0, 1.0), (1.0, 1.0)):
+                self.assertEqual(rotor.stable(INERTIA, w, *dissipation), k == MAJOR, (axis, dissipation))
+
+    def test_dual_spin(self):
+        # A fast rotor stabilizes spin about the minor axis if the energy is dissipated
+        # in the platform, but not if it is dissipated in the rotor
+        rotor = Rotor(Vector(1, 0, 0), 0.5, 200.0)
+        w = AngularVelocity(SPIN, 0, 0)
+
+        platform, fast = rotor.nutation_frequencies(INERTIA, w)
+        self.assertGreater(platform, 0)
+        self.assertLess(fast, 0)
+
+        self.assertTrue(rotor.stable(INERTIA, w, 1.0, 0.0))
+        self.assertFalse(rotor.stable(INERTIA, w, 0.0, 1.0))
+
+    def test_negative_dissipation(self):
+        rotor = Rotor(Vector(0, 0, 1), 0.5)
+        with self.assertRaises(ValueError):
+            rotor.stable(INERTIA, AngularVelocity(0, 0, SPIN), -1.0, 0.0)
+
+
+
+class TestDynamics(MomentumMixin, unittest.TestCase):
+    def momentum(self, state):
+        """Total angular momentum (inertial frame) of the platform and the rotor."""
+        return momentum(state, state.rotor.momentum())
+
+    def test_motor_torque(self):
+        # The motor spins the rotor up, and the platform the other way, about the axis
+        rotor = Rotor(Vector(0, 0, 1), 2.0)
+        rotor.torque = 0.1
+        state = State(INERTIA)
+        state.rotor = rotor
+
+        final = simulate(state, 1000)
+        platform = -0.1 / (INERTIA.j3 - 2.0)
+        self.assertAlmostEqual(final.angular_velocity.z, platform * 10.0)
+        self.assertAlmostEqual(final.rotor.spin, (0.1 / 2.0 - platform) * 10.0)
+        self.assertAlmostEqual(final.angular_velocity.x, 0.0)
+
+    def test_bearing_damping(self):
+        # Friction brings the rotor to rest relative to the platform, at the rate
+        # c * J / (I_r * (J - I_r)) about the axis
+        state = State(INERTIA)
+        state.rotor = Rotor(Vector(0, 0, 1), 2.0, 5.0, 0.1)
+
+        final = simulate(state, 1000)
+        rate = 0.1 * INERTIA.j3 / (2.0 * (INERTIA.j3 - 2.0))
+        self.assertAlmostEqual(final.rotor.spin, 5.0 * math.exp(-rate * 10.0))
+
+        # The platform absorbs the momentum
+        self.assertAlmostEqual(INERTIA.j3 * final.angular_velocity.z + 2.0 * final.rotor.spin, 10.0)
+
+    def test_momentum(self):
+        # Bearing torques are internal, so a tumbling dual spinner conserves momentum
+        for torque, damping in ((0.0, 0.0), (0.05, 0.0), (0.0, 0.2), (0.05, 0.2)):
+            rotor = Rotor(Vector(1, 1, 0), 0.5, 10.0, damping)
+            rotor.torque = torque
+            state = State(INERTIA)
+            state.angular_velocity = AngularVelocity(0.1, -0.2, 0.15)
+            state.rotor = rotor
+
+            final = simulate(state, 3000)
+            self.assertMomentum(state, final, 1e-9)
+
+
+class TestValidation(unittest.TestCase):
+    def test_setters(self):
+        rotor = Rotor(Vector(0, 0, 1), 0.5)
+        for name, value in (("inertia", 0.0), ("inertia", float("nan")), ("damping", -1.0)):
+            with self.assertRaises(ValueError):
+                setattr(rotor, name, value)
+
+        rotor.inertia = 1.0
+        rotor.damping = 0.1
+        self.assertEqual((rotor.inertia, rotor.damping), (1.0, 0.1))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,58 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import unittest
+
+from adcs import (
+    Inertia,
+    Simulation,
+    State,
+    Torque,
+)
+from adcs import control
+from adcs import integrators as it
+
+INERTIA = Inertia(10, 20, 30, 0, 0, 0)
+
+DISTURBANCE = Torque(0.02, 0, 0)
+
+
+class Constant(control.Controller):
+    """Controller commanding a constant torque."""
+
+    def __init__(self, torque):
+        self.torque = torque
+
+    def update(self, time, measurements):
+        return {"torque": self.torque}
+
+
+def simulate(command):
+    state = State(INERTIA)
+    state.torque = DISTURBANCE
+
+    simulation = Simulation(it.RungeKutta4(0.1), Constant(command), 10.0)
+    simulation.add_actuator("torque", lambda state, command: command)
+    return simulation.run(state, 10.0)
+
+
+class TestDisturbance(unittest.TestCase):
+    def test_constant_disturbance(self):
+        # The initial torque acts throughout, in addition to the actuators
+        log = simulate(Torque(0.1, 0, 0))
+        w = log.states[-1].angular_velocity
+        self.assertAlmostEqual(w.x, (0.1 + 0.02) * 10.0 / 10)
+        self.assertEqual((w.y, w.z), (0, 0))
+
+        # Only the actuator torque is logged
+        for t in log.torques:
+            self.assertEqual((t.x, t.y, t.z), (0.1, 0, 0))
+
+    def test_cancelled_disturbance(self):
+        log = simulate(-DISTURBANCE)
+        w = log.states[-1].angular_velocity
+        self.assertEqual((w.x, w.y, w.z), (0, 0, 0))
+
+
+if __name__ == "__main__":
+    unittest.main()
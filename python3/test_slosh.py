@@ -0,0 +1,122 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    SloshPendulum,
+    State,
+    Vector,
+)
+
+from conservation import (
+    ConservationMixin,
+    dot,
+    momentum,
+    simulate,
+)
+
+# Inertia of the bus, excluding the slosh mass
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+
+def initial(damping):
+    """Tumbling spacecraft with a swinging slosh pendulum, unaccelerated."""
+    state = State(INERTIA)
+    state.angular_velocity = AngularVelocity(0.1, -0.2, 0.15)
+    pendulum = SloshPendulum(20.0, 0.3, Vector(0.5, 0, 0.2), 200.0, damping, Vector(1, 0.3, -0.2))
+    pendulum.rate = Vector(0, 0.5, 0.2)
+    state.slosh = pendulum
+    return state
+
+
+class TestConservation(ConservationMixin, unittest.TestCase):
+    DAMPING = 0.5
+    MOMENTUM = 1e-9
+    ENERGY = 1e-9
+    DISSIPATED = 1e-2
+
+    def initial(self, damping):
+        return initial(damping)
+
+    def momentum(self, state):
+        """Total angular momentum (inertial frame) of the bus and the slosh mass."""
+        return momentum(state, state.slosh.momentum(state.angular_velocity))
+
+    def energy(self, state):
+        """Total kinetic energy of the bus and the slosh mass."""
+        w = state.angular_velocity
+        p = state.slosh
+        v = Vector(w.x, w.y, w.z).cross(p.position()) + p.rate.scale(p.length)
+        return 0.5 * dot(AngularMomentum.product(INERTIA, w), w) + 0.5 * p.reduced_mass() * dot(v, v)
+
+
+class TestRestoring(unittest.TestCase):
+    def pendulum(self, damping):
+        """Light pendulum on a massive bus accelerating along +Z, displaced from -Z."""
+        state = State(Inertia(1e4, 1e4, 1e4, 0, 0, 0))
+        pendulum = SloshPendulum(1.0, 0.1, Vector(0, 0, 0), 1e6, damping, Vector(math.sin(0.05), 0, -math.cos(0.05)))
+        pendulum.acceleration = Vector(0, 0, 0.4)
+        state.slosh = pendulum
+        return state
+
+    def test_oscillation(self):
+        # The apparent gravity swings the pendulum about -Z at sqrt(acceleration / length)
+        period = 2 * math.pi / math.sqrt(0.4 / 0.1)
+        state = self.pendulum(0.0)
+        half = simulate(state, 500, period / 1000)
+        full = simulate(half, 500, period / 1000)
+
+        self.assertAlmostEqual(half.slosh.direction.x, -math.sin(0.05), delta=1e-6)
+        self.assertAlmostEqual(full.slosh.direction.x, math.sin(0.05), delta=1e-6)
+        self.assertAlmostEqual(full.slosh.rate.x, 0.0, delta=1e-4)
+
+    def test_damped(self):
+        # The damped pendulum settles along the apparent gravity
+        final = simulate(self.pendulum(0.01), 2000)
+
+        self.assertAlmostEqual(final.slosh.direction.z, -1.0)
+        self.assertAlmostEqual(final.slosh.rate.x, 0.0, delta=1e-4)
+
+
+class TestSpinner(unittest.TestCase):
+    def nutation(self, inertia, damping):
+        """Nutation angle (deg) after five minutes of a spinner about Z, with the slosh
+        mass pointing radially outward."""
+        state = State(inertia)
+        state.angular_velocity = AngularVelocity(0.02, 0, 1.0)
+        state.slosh = SloshPendulum(10.0, 0.2, Vector(0.3, 0, 0), 100.0, damping, Vector(1, 0, 0))
+        final = simulate(state, 15000, 0.02)
+
+        w = final.angular_velocity
+        h = AngularMomentum.product(inertia, w) + final.slosh.momentum(w)
+        return math.degrees(math.acos(abs(h.z) / math.sqrt(dot(h, h))))
+
+    def test_minor_axis(self):
+        # Slosh dissipation drives a spinner about its minor axis into a flat spin
+        self.assertLess(self.nutation(Inertia(10, 10, 4, 0, 0, 0), 0.0), 5.0)
+        self.assertGreater(self.nutation(Inertia(10, 10, 4, 0, 0, 0), 0.5), 60.0)
+
+    def test_major_axis(self):
+        # Slosh dissipation damps the nutation of a spinner about its major axis
+        undamped = self.nutation(Inertia(10, 10, 16, 0, 0, 0), 0.0)
+        self.assertLess(self.nutation(Inertia(10, 10, 16, 0, 0, 0), 0.5), 0.5 * undamped)
+
+
+class TestValidation(unittest.TestCase):
+    def test_negative_damping(self):
+        pendulum = initial(0.0).slosh
+        with self.assertRaises(ValueError):
+            pendulum.damping = -1.0
+        with self.assertRaises(ValueError):
+            pendulum.damping = float("nan")
+        pendulum.damping = 0.5
+        self.assertEqual(pendulum.damping, 0.5)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,136 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    State,
+    Vector,
+)
+from adcs import sensors
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+CROSS_BORESIGHT = 1e-4
+ROLL = 1e-3
+
+# Mounting with the boresight along the body X axis
+MOUNTING = Quaternion.from_rotation(math.pi / 2, 0, 1, 0)
+
+
+def at(time, quaternion=None):
+    state = State(INERTIA)
+    state.time = time
+    if quaternion is not None:
+        state.quaternion = quaternion
+    return state
+
+
+def tracker(**kwargs):
+    tracker = sensors.StarTracker(10.0, CROSS_BORESIGHT, ROLL, **kwargs)
+    tracker.mounting = MOUNTING
+    return tracker
+
+
+def error(truth, measured):
+    """Small rotation (body frame) from the true to the measured attitude."""
+    e = truth.inv() * measured
+    s = 2.0 if e.w >= 0 else -2.0
+    return (s * e.x, s * e.y, s * e.z)
+
+
+class TestNoise(unittest.TestCase):
+    def test_error_statistics(self):
+        truth = Quaternion.from_rotation(0.8, 1, 2, -1)
+        sensor = tracker(seed=4)
+        errors = []
+        for i in range(2000):
+            reading = sensor.measure(at(0.1 * i, truth))
+            errors.append(error(truth, reading.measurement.quaternion))
+
+        # The error is largest about the boresight
+        sigma = [math.sqrt(sum(e[k]**2 for e in errors) / len(errors)) for k in range(3)]
+        self.assertAlmostEqual(sigma[0] / ROLL, 1.0, delta=0.1)
+        self.assertAlmostEqual(sigma[1] / CROSS_BORESIGHT, 1.0, delta=0.1)
+        self.assertAlmostEqual(sigma[2] / CROSS_BORESIGHT, 1.0, delta=0.1)
+
+        # The reported covariance matches, in the body frame
+        p = reading.measurement.covariance
+        expected = [[ROLL**2, 0, 0], [0, CROSS_BORESIGHT**2, 0], [0, 0, CROSS_BORESIGHT**2]]
+        for row, e in zip(p, expected):
+            for a, b in zip(row, e):
+                self.assertAlmostEqual(a, b, delta=1e-15)
+
+
+class TestValidity(unittest.TestCase):
+    def test_sun_exclusion(self):
+        sensor = tracker()
+        sensor.sun_exclusion = 0.5
+
+        # The boresight points along inertial X at the identity attitude
+        self.assertFalse(sensor.measure(at(0.0), Vector(1, 0.3, 0)).valid)
+        self.assertTrue(sensor.measure(at(0.1), Vector(1, 0.6, 0)).valid)
+        self.assertTrue(sensor.measure(at(0.2), Vector(-1, 0, 0)).valid)
+        self.assertTrue(sensor.measure(at(0.3)).valid)
+
+    def test_rate(self):
+        sensor = tracker()
+        sensor.max_rate = 0.01
+
+        state = at(0.0)
+        state.angular_velocity = AngularVelocity(0, 0.006, 0.008)
+        self.assertTrue(sensor.measure(state).valid)
+
+        state = at(0.1)
+        state.angular_velocity = AngularVelocity(0, 0.006, 0.0081)
+        self.assertFalse(sensor.measure(state).valid)
+
+
+class TestLatency(unittest.TestCase):
+    def test_delivery(self):
+        sensor = tracker()
+        sensor.latency = 0.25
+
+        delivered = []
+        for i in range(20):
+            time = 0.05 * i
+            reading = sensor.measure(at(time))
+            if reading is not None:
+                delivered.append((time, reading.time))
+
+        # Each reading, taken every 0.1 s, arrives one latency period later
+        self.assertEqual(len(delivered), 8)
+        for time, taken in delivered:
+            self.assertAlmostEqual(time - taken, 0.25)
+
+    def test_immediate(self):
+        reading = tracker().measure(at(1.0))
+        self.assertEqual(reading.time, 1.0)
+
+
+class TestValidation(unittest.TestCase):
+    def test_setters(self):
+        sensor = tracker()
+        for name, value in (
+            ("cross_boresight", 0.0),
+            ("roll", 0.0),
+            ("roll", float("nan")),
+            ("sun_exclusion", -0.1),
+            ("max_rate", 0.0),
+            ("latency", -0.1),
+            ("latency", float("inf")),
+        ):
+            with self.assertRaises(ValueError):
+                setattr(sensor, name, value)
+
+        sensor.roll = 2e-3
+        sensor.max_rate = float("inf")
+        self.assertEqual(sensor.roll, 2e-3)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,83 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    FlexibleModes,
+    Inertia,
+    Quaternion,
+    Rotor,
+    State,
+    Torque,
+    Vector,
+)
+from adcs import integrators as it
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+INTEGRATORS = [it.ForwardEuler, it.RungeKutta4]
+
+
+class TestValidation(unittest.TestCase):
+    def test_zero_quaternion(self):
+        with self.assertRaises(ValueError):
+            Quaternion(0, 0, 0, 0).normalize()
+
+        state = State(INERTIA)
+        state.quaternion = Quaternion(0, 0, 0, 0)
+        with self.assertRaises(ValueError):
+            state.validate()
+
+    def test_non_finite(self):
+        state = State(INERTIA)
+        state.validate()
+
+        state.torque = Torque(float("nan"), 0, 0)
+        with self.assertRaises(ValueError):
+            state.validate()
+
+    def test_inertia(self):
+        state = State(INERTIA)
+        with self.assertRaises(ValueError):
+            State(Inertia.point_mass(1.0, Vector(1, 0, 0)))
+        with self.assertRaises(ValueError):
+            state.inertia = Inertia.point_mass(1.0, Vector(1, 0, 0))
+        self.assertEqual(state.inertia.j1, INERTIA.j1)
+
+    def test_effective_inertia(self):
+        # Attachments may not couple more inertia than the bus has
+        state = State(Inertia(1, 1, 1, 0, 0, 0))
+        with self.assertRaises(ValueError):
+            state.flexible = FlexibleModes([1.0], [0.0], [Vector(1, 0, 0)])
+        with self.assertRaises(ValueError):
+            state.rotor = Rotor(Vector(0, 0, 1), 2.0)
+        self.assertIsNone(state.flexible)
+        self.assertIsNone(state.rotor)
+
+        state.rotor = Rotor(Vector(0, 0, 1), 0.5)
+        with self.assertRaises(ValueError):
+            state.inertia = Inertia(1, 1, 0.4, 0, 0, 0)
+        self.assertEqual(state.inertia.j3, 1.0)
+
+        state.rotor = None
+        state.inertia = Inertia(1, 1, 0.4, 0, 0, 0)
+
+
+class TestStrict(unittest.TestCase):
+    def test_floating_point_error(self):
+        for integrator in INTEGRATORS:
+            state = State(INERTIA)
+            state.torque = Torque(math.inf, 0, 0)
+
+            # By default, non-finite values propagate silently
+            result = integrator(0.1).step(state)
+            self.assertFalse(math.isfinite(result.angular_velocity.x))
+
+            with self.assertRaises(FloatingPointError):
+                integrator(0.1, strict=True).step(state)
+
+
+if __name__ == "__main__":
+    unittest.main()
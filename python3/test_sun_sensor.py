@@ -0,0 +1,147 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import random
+import unittest
+
+from adcs import (
+    Inertia,
+    Quaternion,
+    State,
+    Vector,
+)
+from adcs import sensors
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+# Photodiodes on each face of a cube
+NORMALS = [
+    Vector(1, 0, 0), Vector(-1, 0, 0),
+    Vector(0, 1, 0), Vector(0, -1, 0),
+    Vector(0, 0, 1), Vector(0, 0, -1),
+]
+
+
+def at(time, quaternion=None):
+    state = State(INERTIA)
+    state.time = time
+    if quaternion is not None:
+        state.quaternion = quaternion
+    return state
+
+
+def angle(a, b):
+    cos = a.dot(b) / math.sqrt(a.dot(a) * b.dot(b))
+    return math.acos(max(-1.0, min(1.0, cos)))
+
+
+def directions(n, seed=0):
+    rng = random.Random(seed)
+    for _ in range(n):
+        yield Vector(rng.gauss(0, 1), rng.gauss(0, 1), rng.gauss(0, 1))
+
+
+class TestCoarseSunSensor(unittest.TestCase):
+    def errors(self, noise, suns):
+        sensor = sensors.CoarseSunSensor(10.0, NORMALS, seed=1)
+        sensor.scale = 2.0
+        sensor.noise = noise
+        errors = []
+        for i, sun in enumerate(suns):
+            currents = sensor.measure(at(0.1 * i), sun)
+            errors.append(angle(sensor.estimate(currents), sun))
+        return errors
+
+    def test_noiseless(self):
+        for e in self.errors(0.0, directions(100)):
+            self.assertAlmostEqual(e, 0.0, delta=1e-7)
+
+    def test_dark_photodiodes(self):
+        # Dark photodiodes read only noise, which must not bias the estimate
+        for noise in (1e-4, 1e-6):
+            errors = self.errors(noise, [Vector(1, 1, 1)] * 200)
+            self.assertLess(sum(errors) / len(errors), 10 * noise)
+            self.assertLess(max(errors), 50 * noise)
+
+    def test_random_directions(self):
+        # Three photodiodes must be clearly illuminated
+        suns = [
+            s for s in directions(500, seed=2)
+            if min(abs(s.x), abs(s.y), abs(s.z)) > 0.1 * math.sqrt(s.dot(s))
+        ]
+        errors = self.errors(1e-3, suns)
+        self.assertLess(sum(errors) / len(errors), 1e-3)
+        self.assertLess(max(errors), 1e-2)
+
+    def test_underdetermined(self):
+        # A single illuminated photodiode does not determine a direction
+        sensor = sensors.CoarseSunSensor(10.0, NORMALS)
+        sensor.noise = 1e-3
+        self.assertIsNone(sensor.estimate(sensor.measure(at(0.0), Vector(0, 1, 0))))
+
+    def test_eclipse(self):
+        sensor = sensors.CoarseSunSensor(10.0, NORMALS)
+        sensor.noise = 1e-3
+        self.assertIsNone(sensor.estimate(sensor.measure(at(0.0), None)))
+
+    def test_field_of_view(self):
+        sensor = sensors.CoarseSunSensor(10.0, NORMALS)
+        sensor.field_of_view = 0.8
+        currents = sensor.measure(at(0.0), Vector(1, 0.8, 0))
+
+        # The +X photodiode sees the sun at 0.675 rad, the +Y photodiode at 0.896 rad
+        self.assertEqual(currents[2], 0.0)
+        self.assertGreater(currents[0], 0.0)
+
+    def test_setters(self):
+        sensor = sensors.CoarseSunSensor(10.0, NORMALS)
+        for name, value in (
+            ("scale", 0.0),
+            ("scale", float("nan")),
+            ("noise", -1e-3),
+            ("field_of_view", 0.0),
+        ):
+            with self.assertRaises(ValueError):
+                setattr(sensor, name, value)
+
+
+class TestFineSunSensor(unittest.TestCase):
+    def test_accuracy(self):
+        sigma = 1e-3
+        sensor = sensors.FineSunSensor(10.0, sigma, 1.0, seed=3)
+        sensor.mounting = Quaternion.from_rotation(-math.pi / 2, 0, 1, 0)
+        truth = Quaternion.from_rotation(0.3, 1, 1, 0)
+        sun = Vector(-1, 0.2, 0.1).rotate(truth)
+
+        errors = []
+        for i in range(1000):
+            m = sensor.measure(at(0.1 * i, truth), sun)
+            self.assertEqual(m.sigma, sigma)
+            errors.append(angle(m.body, Vector(-1, 0.2, 0.1)))
+
+        # The angle between two perpendicular errors has a Rayleigh distribution
+        rms = math.sqrt(sum(e**2 for e in errors) / len(errors))
+        self.assertAlmostEqual(rms / (sigma * math.sqrt(2)), 1.0, delta=0.1)
+
+    def test_field_of_view(self):
+        sensor = sensors.FineSunSensor(10.0, 1e-3, 0.5)
+        self.assertIsNotNone(sensor.measure(at(0.0), Vector(0.4, 0, 1)))
+        self.assertIsNone(sensor.measure(at(0.1), Vector(0.6, 0, 1)))
+        self.assertIsNone(sensor.measure(at(0.2), None))
+
+    def test_setters(self):
+        sensor = sensors.FineSunSensor(10.0, 1e-3, 0.5)
+        for name, value in (
+            ("sigma", -1.0),
+            ("sigma", 0.0),
+            ("field_of_view", 0.0),
+            ("field_of_view", float("nan")),
+        ):
+            with self.assertRaises(ValueError):
+                setattr(sensor, name, value)
+        self.assertEqual(sensor.sigma, 1e-3)
+
+
+if __name__ == "__main__":
+    unittest.main()
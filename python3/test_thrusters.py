@@ -0,0 +1,151 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import unittest
+
+from adcs import (
+    Inertia,
+    State,
+    Torque,
+    Vector,
+)
+from adcs import actuators
+from adcs import integrators as it
+
+# Six thrusters, each producing a unit couple about one body axis, in either sense
+MOUNTS = [
+    ((0, 1, 0), (0, 0, 1)),
+    ((0, 1, 0), (0, 0, -1)),
+    ((0, 0, 1), (1, 0, 0)),
+    ((0, 0, 1), (-1, 0, 0)),
+    ((1, 0, 0), (0, 1, 0)),
+    ((1, 0, 0), (0, -1, 0)),
+]
+
+PERIOD = 0.5
+
+
+def thrusters(min_impulse=0.0, open_delay=0.0, close_delay=0.0):
+    return [
+        actuators.Thruster(Vector(*position), Vector(*direction), 1.0, min_impulse, open_delay, close_delay)
+        for position, direction in MOUNTS
+    ]
+
+
+def fire(cluster, command, periods):
+    """Command a constant torque over a number of periods, and return the combined firing."""
+    firing = actuators.Firing()
+    for k in range(periods):
+        firing = firing + cluster.command(k * PERIOD, command)
+    return firing
+
+
+class TestThrusters(unittest.TestCase):
+    def assertTorque(self, actual, expected, places=12):
+        for a, e in zip((actual.x, actual.y, actual.z), (expected.x, expected.y, expected.z)):
+            self.assertAlmostEqual(a, e, places=places)
+
+    def test_duty_cycles(self):
+        cluster = actuators.ThrusterCluster(thrusters(), PERIOD)
+        command = Torque(0.3, -0.2, 0.5)
+
+        duty = cluster.duty_cycles(command)
+        for d in duty:
+            self.assertGreaterEqual(d, 0)
+            self.assertLessEqual(d, 1)
+
+        # Opposing thrusters are never fired together, so the duty is least
+        self.assertAlmostEqual(sum(duty), 1.0)
+
+        firing = cluster.command(0.0, command)
+        self.assertTorque(firing.mean(0.0, PERIOD), command)
+
+    def test_saturation(self):
+        # Beyond one period of full thrust, the direction is preserved
+        cluster = actuators.ThrusterCluster(thrusters(), PERIOD)
+        duty = cluster.duty_cycles(Torque(2.0, 1.0, -4.0))
+        self.assertAlmostEqual(duty[5], 1.0)
+        self.assertAlmostEqual(duty[0], 0.5)
+        self.assertAlmostEqual(duty[2], 0.25)
+
+    def test_pwpf(self):
+        # The mean torque tracks the demand, with a static error of about
+        # (on_threshold + off_threshold) / (2 * gain) of full thrust
+        command = Torque(0.3, -0.2, 0.05)
+        errors = []
+        for gain in (5.0, 20.0):
+            cluster = actuators.ThrusterCluster(thrusters(), PERIOD, actuators.Pwpf(gain, 0.5, 0.5, 0.1))
+            firing = fire(cluster, command, 200)
+            mean = firing.mean(20.0, 200 * PERIOD)
+            errors.append(max(abs(m - c) for m, c in zip(
+                (mean.x, mean.y, mean.z),
+                (command.x, command.y, command.z),
+            )))
+        self.assertLess(errors[1], 0.02)
+        self.assertLess(errors[1], errors[0])
+
+    def test_minimum_impulse(self):
+        cluster = actuators.ThrusterCluster(thrusters(0.05), PERIOD)
+
+        # A 0.01 s pulse is below the minimum impulse bit, and is dropped
+        self.assertEqual(len(cluster.command(0.0, Torque(0.02, 0, 0))), 0)
+
+        # A 0.1 s pulse is fired in full
+        firing = cluster.command(PERIOD, Torque(0.2, 0, 0))
+        self.assertEqual(len(firing), 1)
+        self.assertTorque(firing.mean(PERIOD, 2 * PERIOD), Torque(0.2, 0, 0))
+
+
+    def test_valve_delays(self):
+        cluster = actuators.ThrusterCluster(thrusters(0.0, 0.02, 0.03), PERIOD)
+
+        # A pulse opens and closes late, so it is lengthened by the difference
+        firing = cluster.command(0.0, Torque(0.2, 0, 0))
+        ((thruster, start, end),) = firing.pulses
+        self.assertEqual(thruster, 0)
+        self.assertAlmostEqual(start, 0.02)
+        self.assertAlmostEqual(end, 0.13)
+
+        # A pulse continuing across periods keeps the valve open
+        cluster.reset()
+        firing = cluster.command(0.0, Torque(2.0, 0, 0)) + cluster.command(PERIOD, Torque(2.0, 0, 0))
+        self.assertEqual(sorted(firing.pulses), [(0, 0.02, PERIOD), (0, PERIOD, 2 * PERIOD)])
+
+        # The next pulse opens the valve again
+        cluster.reset()
+        ((_, start, _),) = cluster.command(2 * PERIOD, Torque(0.2, 0, 0)).pulses
+        self.assertAlmostEqual(start, 2 * PERIOD + 0.02)
+
+
+class TestIntegration(unittest.TestCase):
+    def test_impulse(self):
+        # With unit inertia, the change in rate is the impulse, however the pulses fall
+        # relative to the integration stages
+        h = 0.1
+        cluster = actuators.ThrusterCluster(thrusters(), PERIOD)
+        for integrator in (it.ForwardEuler(h), it.RungeKutta4(h)):
+            for width, time in ((0.01, 0.0), (0.003, 0.0), (0.003, 0.048), (0.13, 0.02)):
+                firing = cluster.command(time, Torque(width / PERIOD, 0, 0))
+                state = State(Inertia(1, 1, 1, 0, 0, 0))
+                for _ in range(3):
+                    state = integrator.step(state, firing)
+
+                self.assertAlmostEqual(state.angular_velocity.x, width, places=12)
+                self.assertAlmostEqual(state.time, 3 * h, places=12)
+                self.assertEqual(state.torque.x, 0.0)
+
+    def test_impulse_with_delays(self):
+        cluster = actuators.ThrusterCluster(thrusters(0.0, 0.02, 0.03), PERIOD)
+        firing = cluster.command(0.0, Torque(0.2, -0.1, 0))
+        state = State(Inertia(1, 1, 1, 0, 0, 0))
+        state = it.RungeKutta4(0.05).step(state, firing)
+        state = it.RungeKutta4(0.05).step(state, firing)
+        state = it.RungeKutta4(0.05).step(state, firing)
+
+        # Each pulse is lengthened by 0.01 s
+        self.assertAlmostEqual(state.angular_velocity.x, 0.11, places=12)
+        self.assertAlmostEqual(state.angular_velocity.y, -0.06, places=12)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,110 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    Vector,
+)
+from adcs import control
+
+DT = 1.0
+
+INERTIA = Inertia(12, 15, 9, 0.4, -0.6, 0.8)
+
+# Initial excess momentum stored in the wheels
+EXCESS = AngularMomentum(0.3, -0.2, 0.4)
+
+# Orbital rate (rad/s)
+ORBIT_RATE = 2 * math.pi / 5400
+
+
+def norm(v):
+    return math.sqrt(v.x**2 + v.y**2 + v.z**2)
+
+
+def field(time):
+    """Magnetic field (body frame, T) of an inertially pointed body, turning twice
+    per orbit as in a polar orbit."""
+    u = ORBIT_RATE * time
+    return Vector(1.5 * math.sin(2 * u), 0.3, 3 * math.cos(u) ** 2 - 1).scale(2e-5)
+
+
+def unload(torque, time):
+    """Unload the excess momentum of an inertially pointed body, whose wheels absorb
+    the external torque, returning the excess after each step."""
+    excess = EXCESS
+    history = [excess]
+    for k in range(round(time / DT)):
+        t = torque(excess, k * DT)
+        excess = excess + AngularMomentum(t.x, t.y, t.z).scale(DT)
+        history.append(excess)
+    return history
+
+
+class TestMomentumUnloading(unittest.TestCase):
+    def test_excess(self):
+        controller = control.MomentumUnloading(0.01, AngularMomentum(0, 0, 0.1))
+        w = AngularVelocity(0.01, 0.02, -0.01)
+        excess = controller.excess(INERTIA, w, AngularMomentum(0.1, 0.2, 0.3))
+        bus = AngularMomentum.product(INERTIA, w)
+        self.assertAlmostEqual(excess.x, bus.x + 0.1)
+        self.assertAlmostEqual(excess.z, bus.z + 0.2)
+
+    def test_thruster_torque(self):
+        # The excess decays exponentially at the rate of the gain
+        gain = 0.01
+        controller = control.MomentumUnloading(gain)
+        history = unload(lambda h, t: controller.thruster_torque(h), 500.0)
+        final = history[-1]
+        for a, b in zip((final.x, final.y, final.z), (EXCESS.x, EXCESS.y, EXCESS.z)):
+            self.assertAlmostEqual(a, b * (1 - gain * DT) ** 500)
+
+    def test_saturated_thruster_torque(self):
+        # At the torque limit, the largest component decreases linearly
+        controller = control.MomentumUnloading(1.0)
+        controller.max_torque = 1e-3
+        torque = controller.thruster_torque(EXCESS)
+        self.assertAlmostEqual(torque.z, -1e-3)
+        self.assertAlmostEqual(torque.x / torque.z, EXCESS.x / EXCESS.z)
+
+        history = unload(lambda h, t: controller.thruster_torque(h), 600.0)
+        self.assertLess(norm(history[-1]), 1e-9)
+        self.assertAlmostEqual(history[100].z, EXCESS.z - 0.1)
+
+    def test_magnetic_torque(self):
+        controller = control.MomentumUnloading(2e-3)
+        controller.max_dipole = 50.0
+
+        # The torque is perpendicular to the field, and opposes the excess
+        b = field(1000.0)
+        torque = controller.magnetic_torque(EXCESS, b)
+        self.assertAlmostEqual(torque.x * b.x + torque.y * b.y + torque.z * b.z, 0.0, delta=1e-15)
+        self.assertLess(torque.x * EXCESS.x + torque.y * EXCESS.y + torque.z * EXCESS.z, 0.0)
+
+        # As the field turns around the orbit, every component of the excess decays
+        history = unload(lambda h, t: controller.magnetic_torque(h, field(t)), 5 * 5400.0)
+        self.assertLess(norm(history[-1]), 1e-2 * norm(EXCESS))
+        orbits = [norm(h) for h in history[::5400]]
+        for a, b in zip(orbits[1:], orbits):
+            self.assertLess(a, b)
+
+    def test_fixed_field(self):
+        # In a fixed field, the component of the excess along the field remains
+        controller = control.MomentumUnloading(2e-3)
+        b = Vector(0, 0, 3e-5)
+        final = unload(lambda h, t: controller.magnetic_torque(h, b), 5000.0)[-1]
+        self.assertAlmostEqual(final.z, EXCESS.z)
+        self.assertLess(math.hypot(final.x, final.y), 1e-3)
+
+    def test_zero_field(self):
+        controller = control.MomentumUnloading(2e-3)
+        self.assertEqual(norm(controller.dipole(EXCESS, Vector(0, 0, 0))), 0.0)
+
+
+if __name__ == "__main__":
+    unittest.main()
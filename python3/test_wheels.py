@@ -0,0 +1,93 @@
+# ADCS
+# Copyright (c) 2026 Joseph Hobbs
+
+import math
+import unittest
+
+from adcs import (
+    Torque,
+    Vector,
+)
+from adcs import actuators
+
+# Pyramid of four wheels, inclined at the "magic" angle to the z-axis
+BETA = math.acos(1 / math.sqrt(3))
+AXES = [
+    Vector(math.sin(BETA), 0, math.cos(BETA)),
+    Vector(0, math.sin(BETA), math.cos(BETA)),
+    Vector(-math.sin(BETA), 0, math.cos(BETA)),
+    Vector(0, -math.sin(BETA), math.cos(BETA)),
+]
+
+MAX_TORQUE = 0.1
+
+
+def pyramid():
+    return actuators.ReactionWheels(AXES, MAX_TORQUE)
+
+
+def body_torque(torques):
+    """Body torque produced by a set of wheel torques."""
+    return [sum(u * getattr(a, c) for u, a in zip(torques, AXES)) for c in "xyz"]
+
+
+class TestAllocation(unittest.TestCase):
+    def assertTorque(self, actual, expected):
+        for a, e in zip(actual, (expected.x, expected.y, expected.z)):
+            self.assertAlmostEqual(a, e, places=12)
+
+    def test_achievable(self):
+        wheels = pyramid()
+        command = Torque(0.01, 0.02, 0.03)
+        for allocation in (wheels.pseudo_inverse(command), wheels.min_max(command)):
+            self.assertFalse(allocation.saturated)
+            self.assertTorque(body_torque(allocation.torques), command)
+            self.assertTorque((allocation.torque.x, allocation.torque.y, allocation.torque.z), command)
+
+        # Min-max never needs a larger wheel torque than the pseudo-inverse
+        largest = lambda a: max(abs(u) for u in a.torques)
+        self.assertLessEqual(largest(wheels.min_max(command)), largest(wheels.pseudo_inverse(command)) + 1e-12)
+
+    def test_saturation(self):
+        # The pseudo-inverse exceeds the limit on the first wheel, but the remaining
+        # wheels can make up the difference
+        wheels = pyramid()
+        command = Torque(0.15, 0, 0.1)
+        allocation = wheels.pseudo_inverse(command)
+        self.assertTrue(allocation.saturated)
+        self.assertAlmostEqual(allocation.torques[0], MAX_TORQUE)
+        for u in allocation.torques:
+            self.assertLessEqual(abs(u), MAX_TORQUE + 1e-12)
+        self.assertTorque(body_torque(allocation.torques), command)
+
+    def test_unachievable(self):
+        # Beyond the capability of the array, min-max preserves the direction
+        wheels = pyramid()
+        command = Torque(0.3, 0.1, 0.2)
+        allocation = wheels.min_max(command)
+        self.assertTrue(allocation.saturated)
+        self.assertAlmostEqual(max(abs(u) for u in allocation.torques), MAX_TORQUE)
+        achieved = body_torque(allocation.torques)
+        ratio = achieved[0] / command.x
+        self.assertLess(ratio, 1)
+        self.assertTorque(achieved, Torque(ratio * command.x, ratio * command.y, ratio * command.z))
+
+    def test_null_motion(self):
+        wheels = pyramid()
+        wheels.bias_momentum = 0.5
+        wheels.null_gain = 0.1
+        momenta = [0.1, -0.2, 1.0, 1.0]
+
+        null = wheels.null_motion(momenta)
+        self.assertGreater(max(abs(u) for u in null), 1e-3)
+        self.assertTorque(body_torque(null), Torque(0, 0, 0))
+
+        # Null motion leaves the allocated body torque unchanged
+        command = Torque(0.01, 0.02, 0.03)
+        allocation = wheels.pseudo_inverse(command, momenta)
+        self.assertTorque(body_torque(allocation.torques), command)
+        self.assertNotAlmostEqual(allocation.torques[0], wheels.pseudo_inverse(command).torques[0])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,216 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Non-ideal actuator effects.
+
+use std::collections::VecDeque;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+
+use crate::{
+    State,
+    Torque,
+};
+use crate::actuator::Firing;
+use crate::inertia::{
+    non_negative,
+    positive,
+    positive_or_infinite,
+};
+use crate::sensor::due;
+
+#[pyclass]
+/// Non-ideal actuator effects.
+///
+/// The effects turn an ideal torque (body frame) into the torque actually delivered,
+/// applying, in order:
+///
+/// 1. a zero-order hold, sampling the ideal torque at the command rate and holding it
+///    between samples;
+/// 2. a transport delay, delivering each sample after the delay has elapsed;
+/// 3. quantization of each component to a multiple of the quantum;
+/// 4. saturation of each component at the torque limit;
+/// 5. a slew-rate limit on each component of the delivered torque.
+///
+/// Each effect is disabled by default.  The delivered torque starts at zero.
+///
+/// The effects can wrap any torque-producing actuator, called as
+/// `actuator(state, command) -> Torque`, so that the effects themselves are an actuator
+/// of a [`crate::Simulation`], called at every integration step.  Without an actuator,
+/// the command is the ideal torque.  A thruster cluster, which returns a `Firing`,
+/// cannot be wrapped: its own firing logic models its valve delays, minimum impulse
+/// bit and saturation.
+pub struct ActuatorEffects {
+    #[pyo3(get)]
+    /// Wrapped actuator, or `None` if the command is the ideal torque.
+    pub actuator: Option<Py<PyAny>>,
+
+    #[pyo3(get)]
+    /// Command rate (Hz) of the zero-order hold, or `None` to sample at every call.
+    pub command_rate: Option<f64>,
+
+    #[pyo3(get)]
+    /// Torque limit of each body axis.
+    pub max_torque: f64,
+
+    #[pyo3(get)]
+    /// Slew-rate limit of each body axis (torque/s).
+    pub max_rate: f64,
+
+    #[pyo3(get)]
+    /// Transport delay (s).
+    pub delay: f64,
+
+    #[pyo3(get)]
+    /// Quantum of each component, or zero for no quantization.
+    pub quantum: f64,
+
+    // Time of the last sample.
+    last_sample: Option<f64>,
+
+    // Samples awaiting delivery, oldest first, with their times.
+    pending: VecDeque<(f64, Torque)>,
+
+    // Delivered torque, and the time at which it was delivered.
+    output: Option<(f64, Torque)>,
+}
+
+#[pymethods]
+impl ActuatorEffects {
+    #[new]
+    #[pyo3(signature = (actuator = None, command_rate = None))]
+    /// Construct new actuator effects, wrapping an optional torque-producing actuator,
+    /// with an optional zero-order hold at the given command rate.
+    ///
+    /// Raises `ValueError` if the command rate is not positive.
+    pub fn new(actuator: Option<Py<PyAny>>, command_rate: Option<f64>) -> PyResult<Self> {
+        if let Some (rate) = command_rate {
+            positive("command rate", rate)?;
+        }
+
+        Ok (Self {
+            actuator,
+            command_rate,
+            max_torque: f64::INFINITY,
+            max_rate: f64::INFINITY,
+            delay: 0.0,
+            quantum: 0.0,
+            last_sample: None,
+            pending: VecDeque::new(),
+            output: None,
+        })
+    }
+
+    #[setter]
+    /// Set the torque limit, which may be infinite.
+    fn set_max_torque(&mut self, max_torque: f64) -> PyResult<()> {
+        positive_or_infinite("maximum torque", max_torque)?;
+        self.max_torque = max_torque;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the slew-rate limit, which may be infinite.
+    fn set_max_rate(&mut self, max_rate: f64) -> PyResult<()> {
+        positive_or_infinite("maximum rate", max_rate)?;
+        self.max_rate = max_rate;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the transport delay.
+    fn set_delay(&mut self, delay: f64) -> PyResult<()> {
+        non_negative("delay", delay)?;
+        self.delay = delay;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the quantum.
+    fn set_quantum(&mut self, quantum: f64) -> PyResult<()> {
+        non_negative("quantum", quantum)?;
+        self.quantum = quantum;
+
+        Ok (())
+    }
+
+    /// Compute the delivered torque (body frame) at a given time, given the ideal
+    /// torque at that time.
+    ///
+    /// Calls must be made in order of time.
+    pub fn apply(&mut self, time: f64, torque: Torque) -> Torque {
+        // Zero-order hold
+        let period = self.command_rate.map_or(0.0, f64::recip);
+        if due(self.last_sample, period, time) {
+            self.last_sample = Some (time);
+            self.pending.push_back((time, torque));
+        }
+
+        // Transport delay, keeping only the latest sample that has arrived
+        while self.pending.len() > 1 && self.pending[1].0 <= time - self.delay {
+            self.pending.pop_front();
+        }
+        let target = match self.pending.front() {
+            Some (&(t, sample)) if t <= time - self.delay => sample,
+            _ => Torque::new(0.0, 0.0, 0.0),
+        };
+
+        // Quantization and saturation
+        let limit = |v: f64| {
+            let v = if self.quantum > 0.0 { (v / self.quantum).round() * self.quantum } else { v };
+            v.clamp(-self.max_torque, self.max_torque)
+        };
+        let target = Torque::new(limit(target.x), limit(target.y), limit(target.z));
+
+        // Slew-rate limit
+        let (last, previous) = self.output.unwrap_or((time, Torque::new(0.0, 0.0, 0.0)));
+        let step = self.max_rate * (time - last).max(0.0);
+        let slew = |to: f64, from: f64| if step.is_finite() {
+            from + (to - from).clamp(-step, step)
+        } else {
+            to
+        };
+        let delivered = Torque::new(
+            slew(target.x, previous.x),
+            slew(target.y, previous.y),
+            slew(target.z, previous.z),
+        );
+
+        self.output = Some ((time, delivered));
+
+        delivered
+    }
+
+    /// Compute the delivered torque (body frame), given the state and the command of
+    /// the wrapped actuator, or the ideal torque if there is no actuator.
+    ///
+    /// Raises `TypeError` if the wrapped actuator returns a thruster `Firing`.
+    fn __call__(&mut self, py: Python<'_>, state: State, command: &Bound<'_, PyAny>) -> PyResult<Torque> {
+        let torque = match &self.actuator {
+            Some (actuator) => {
+                let output = actuator.bind(py).call1((state.clone(), command))?;
+                if output.downcast::<Firing>().is_ok() {
+                    return Err (PyTypeError::new_err(
+                        "actuator effects apply to torques, not to thruster firings"
+                    ));
+                }
+                output.extract()?
+            },
+            None => command.extract()?,
+        };
+
+        Ok (self.apply(state.time, torque))
+    }
+
+    /// Reset the effects, discarding held and pending samples and returning the
+    /// delivered torque to zero.
+    pub fn reset(&mut self) {
+        self.last_sample = None;
+        self.pending.clear();
+        self.output = None;
+    }
+}
@@ -0,0 +1,74 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Joint motors of articulated bodies.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::State;
+use crate::inertia::positive_or_infinite;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Motors driving the revolute joints of the articulated bodies of a spacecraft.
+///
+/// The motors are an actuator of a [`crate::Simulation`]: called with the state and a
+/// list of commanded joint torques, one per joint, they return the joint torques
+/// actually delivered, which the simulation applies to the joints of the state.
+pub struct JointMotors {
+    #[pyo3(get)]
+    /// Torque limit of each joint.
+    pub max_torque: f64,
+}
+
+#[pymethods]
+impl JointMotors {
+    #[new]
+    #[pyo3(signature = (max_torque = f64::INFINITY))]
+    /// Construct new joint motors, given the torque limit of each joint, which may be
+    /// infinite.
+    ///
+    /// Raises `ValueError` if the torque limit is not positive.
+    pub fn new(max_torque: f64) -> PyResult<Self> {
+        positive_or_infinite("maximum torque", max_torque)?;
+
+        Ok (Self {
+            max_torque,
+        })
+    }
+
+    #[setter]
+    /// Set the torque limit, which may be infinite.
+    fn set_max_torque(&mut self, max_torque: f64) -> PyResult<()> {
+        positive_or_infinite("maximum torque", max_torque)?;
+        self.max_torque = max_torque;
+
+        Ok (())
+    }
+
+    /// Compute the delivered joint torques, given the state and the commanded joint
+    /// torques, each saturated at the torque limit.
+    ///
+    /// Raises `ValueError` if the state has no articulated bodies, or if the number of
+    /// commanded torques differs from the number of joints.
+    fn __call__(&self, state: State, command: Vec<f64>) -> PyResult<Vec<f64>> {
+        let Some (multibody) = state.multibody else {
+            return Err (PyValueError::new_err("state has no articulated bodies"));
+        };
+        if command.len() != multibody.len() {
+            return Err (PyValueError::new_err(format!(
+                "expected {} joint torques, got {}",
+                multibody.len(),
+                command.len(),
+            )));
+        }
+
+        Ok (command.iter().map(|t| t.clamp(-self.max_torque, self.max_torque)).collect())
+    }
+
+    /// Return a Pythonic representation of these motors.
+    fn __repr__(&self) -> String {
+        format!("JointMotors({})", self.max_torque)
+    }
+}
@@ -0,0 +1,26 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Actuator models.
+//!
+//! Each actuator model converts commands from a controller into the torque applied to
+//! the body (body frame), or to the joints of its articulated bodies, subject to the
+//! limits of the hardware.
+
+mod effects;
+mod joints;
+mod thrusters;
+mod wheels;
+
+pub use effects::ActuatorEffects;
+pub use joints::JointMotors;
+pub use thrusters::{
+    Firing,
+    Pwpf,
+    Thruster,
+    ThrusterCluster,
+};
+pub use wheels::{
+    Allocation,
+    ReactionWheels,
+};
@@ -0,0 +1,528 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Thrusters and thruster firing logic.
+
+use std::ops::Add;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    Torque,
+    Vector,
+};
+use crate::inertia::{
+    non_negative,
+    positive,
+};
+use crate::linalg::Matrix;
+use crate::optimize::linear_program;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// On-off thruster.
+///
+/// The thruster produces a constant thrust along its direction while its valve is open.
+/// The valve opens and closes some time after it is commanded to, and commanded pulses
+/// shorter than the minimum impulse bit are not fired.
+pub struct Thruster {
+    #[pyo3(get, set)]
+    /// Position of the thruster relative to the centre of mass (body frame).
+    pub position: Vector,
+
+    #[pyo3(get)]
+    /// Unit direction of the thrust (body frame).
+    pub direction: Vector,
+
+    #[pyo3(get)]
+    /// Thrust level.
+    pub thrust: f64,
+
+    #[pyo3(get)]
+    /// Minimum impulse bit.
+    pub min_impulse: f64,
+
+    #[pyo3(get)]
+    /// Delay between the command to open the valve and the start of the thrust.
+    pub open_delay: f64,
+
+    #[pyo3(get)]
+    /// Delay between the command to close the valve and the end of the thrust.
+    pub close_delay: f64,
+}
+
+#[pymethods]
+impl Thruster {
+    #[new]
+    #[pyo3(signature = (position, direction, thrust, min_impulse = 0.0, open_delay = 0.0, close_delay = 0.0))]
+    /// Construct a new thruster, given its position relative to the centre of mass and
+    /// its thrust direction (both body frame) and its thrust level.
+    ///
+    /// The direction is normalized automatically.  Raises `ValueError` if the direction
+    /// is zero, if the thrust is not positive, or if the minimum impulse bit or either
+    /// valve delay is negative.
+    pub fn new(
+        position: Vector,
+        direction: Vector,
+        thrust: f64,
+        min_impulse: f64,
+        open_delay: f64,
+        close_delay: f64,
+    ) -> PyResult<Self> {
+        positive("thrust", thrust)?;
+        non_negative("minimum impulse bit", min_impulse)?;
+        non_negative("valve opening delay", open_delay)?;
+        non_negative("valve closing delay", close_delay)?;
+
+        Ok (Self {
+            position,
+            direction: direction.normalize()?,
+            thrust,
+            min_impulse,
+            open_delay,
+            close_delay,
+        })
+    }
+
+    /// Compute the force (body frame) of the thruster while it fires.
+    pub fn force(&self) -> Vector {
+        self.direction.scale(self.thrust)
+    }
+
+    /// Compute the torque (body frame) of the thruster about the centre of mass while
+    /// it fires.
+    pub fn torque(&self) -> Torque {
+        let t = self.position.cross(self.force());
+
+        Torque::new(t.x, t.y, t.z)
+    }
+
+    #[getter]
+    /// Shortest on-time that the thruster fires.
+    pub fn min_on_time(&self) -> f64 {
+        self.min_impulse / self.thrust
+    }
+
+    /// Return a Pythonic representation of this thruster.
+    fn __repr__(&self) -> String {
+        format!(
+            "Thruster({}, {}, {}, {}, {}, {})",
+            self.position.__repr__(),
+            self.direction.__repr__(),
+            self.thrust,
+            self.min_impulse,
+            self.open_delay,
+            self.close_delay,
+        )
+    }
+}
+
+impl Thruster {
+    /// Convert a commanded on-interval into the interval over which the thruster fires,
+    /// or `None` if the pulse is shorter than the minimum impulse bit.
+    ///
+    /// An interval that continues a pulse from the previous period does not open the
+    /// valve, and one that continues into the next period does not close it.
+    fn pulse(&self, start: f64, end: f64, opens: bool, closes: bool) -> Option<(f64, f64)> {
+        if end <= start || (opens && closes && end - start < self.min_on_time()) {
+            return None;
+        }
+
+        Some ((
+            start + if opens { self.open_delay } else { 0.0 },
+            end + if closes { self.close_delay } else { 0.0 },
+        ))
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Pulse-width pulse-frequency (PWPF) modulator.
+///
+/// The modulator drives a first-order filter with the error between the demanded duty
+/// cycle and its own on-off output, and switches with a Schmitt trigger: it turns on
+/// when the filter output rises above the on threshold, and off when it falls below the
+/// off threshold.  The mean output follows the demand, with pulse widths and frequencies
+/// set by the parameters.
+pub struct Pwpf {
+    #[pyo3(get)]
+    /// Filter gain.
+    pub gain: f64,
+
+    #[pyo3(get)]
+    /// Filter time constant (s).
+    pub time_constant: f64,
+
+    #[pyo3(get)]
+    /// Filter output at which the output turns on.
+    pub on_threshold: f64,
+
+    #[pyo3(get)]
+    /// Filter output at which the output turns off.
+    pub off_threshold: f64,
+}
+
+#[pymethods]
+impl Pwpf {
+    #[new]
+    /// Construct a new PWPF modulator, given its filter gain and time constant and its
+    /// trigger thresholds.
+    ///
+    /// Raises `ValueError` if the gain or time constant is not positive, if the off
+    /// threshold is negative, or if the on threshold does not exceed the off threshold.
+    pub fn new(gain: f64, time_constant: f64, on_threshold: f64, off_threshold: f64) -> PyResult<Self> {
+        positive("filter gain", gain)?;
+        positive("filter time constant", time_constant)?;
+        non_negative("off threshold", off_threshold)?;
+        if !(on_threshold > off_threshold && on_threshold.is_finite()) {
+            return Err (PyValueError::new_err(format!(
+                "on threshold must be finite and exceed off threshold {}, got {}",
+                off_threshold,
+                on_threshold,
+            )));
+        }
+
+        Ok (Self {
+            gain,
+            time_constant,
+            on_threshold,
+            off_threshold,
+        })
+    }
+
+    /// Return a Pythonic representation of this modulator.
+    fn __repr__(&self) -> String {
+        format!(
+            "Pwpf({}, {}, {}, {})",
+            self.gain,
+            self.time_constant,
+            self.on_threshold,
+            self.off_threshold,
+        )
+    }
+}
+
+impl Pwpf {
+    /// Run one channel of the modulator for a given duration at a constant demand,
+    /// updating its filter output and trigger state, and return the intervals over
+    /// which the output is on, relative to the start.
+    ///
+    /// Since the filter input is constant between switches, the filter output and the
+    /// switching times are computed exactly.
+    fn modulate(&self, filter: &mut f64, on: &mut bool, demand: f64, duration: f64) -> Vec<(f64, f64)> {
+        let tau = self.time_constant;
+        let mut intervals = Vec::new();
+
+        let mut t = 0.0;
+        while t < duration {
+            let output = if *on { 1.0 } else { 0.0 };
+            let target = self.gain * (demand - output);
+
+            // Time until the filter output crosses the active threshold, if ever
+            let (threshold, crosses) = if *on {
+                (self.off_threshold, target < self.off_threshold)
+            } else {
+                (self.on_threshold, target > self.on_threshold)
+            };
+            let switch = if !crosses {
+                f64::INFINITY
+            } else if (*on && *filter <= threshold) || (!*on && *filter >= threshold) {
+                0.0
+            } else {
+                tau * ((*filter - target) / (threshold - target)).ln()
+            };
+
+            let step = switch.min(duration - t);
+            *filter = target + (*filter - target) * (-step / tau).exp();
+            if *on && step > 0.0 {
+                intervals.push((t, t + step));
+            }
+            t += step;
+
+            if switch <= step {
+                *on = !*on;
+            }
+        }
+
+        intervals
+    }
+}
+
+/// Single firing of a thruster.
+#[derive(Clone, Copy, Debug)]
+struct Pulse {
+    /// Index of the thruster in its cluster.
+    thruster: usize,
+
+    /// Torque (body frame) of the thruster while it fires.
+    torque: Torque,
+
+    /// Time at which the thrust starts.
+    start: f64,
+
+    /// Time at which the thrust ends.
+    end: f64,
+}
+
+#[pyclass]
+#[derive(Clone, Debug, Default)]
+/// Schedule of thruster firings.
+///
+/// A firing gives the torque of a set of thruster pulses as a function of time, so it
+/// can be passed to an integrator, which splits its step at the start and end of every
+/// pulse so that the impulse is delivered exactly.
+/// Firings can be combined with `+`.
+pub struct Firing {
+    // Pulses, in no particular order.
+    pulses: Vec<Pulse>,
+}
+
+#[pymethods]
+impl Firing {
+    #[new]
+    /// Construct a new, empty firing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[getter]
+    /// Pulses, as a list of `(thruster, start, end)`.
+    pub fn pulses(&self) -> Vec<(usize, f64, f64)> {
+        self.pulses.iter().map(|p| (p.thruster, p.start, p.end)).collect()
+    }
+
+    /// Compute the torque (body frame) at a given time.
+    pub fn torque(&self, time: f64) -> Torque {
+        self.pulses.iter()
+            .filter(|p| p.start <= time && time < p.end)
+            .fold(Torque::new(0.0, 0.0, 0.0), |t, p| t + p.torque)
+    }
+
+    /// Compute the mean torque (body frame) over a given interval.
+    ///
+    /// Raises `ValueError` if the interval is empty.
+    pub fn mean(&self, start: f64, end: f64) -> PyResult<Torque> {
+        positive("interval duration", end - start)?;
+
+        let impulse = self.pulses.iter().fold(Torque::new(0.0, 0.0, 0.0), |t, p| {
+            let overlap = (p.end.min(end) - p.start.max(start)).max(0.0);
+            t + p.torque.scale(overlap)
+        });
+
+        Ok (impulse.scale((end - start).recip()))
+    }
+
+    /// Compute the torque (body frame) at a given time.
+    fn __call__(&self, time: f64) -> Torque {
+        self.torque(time)
+    }
+
+    /// Combine two firings.
+    fn __add__(&self, other: Self) -> Self {
+        self.clone() + other
+    }
+
+    /// Return the number of pulses.
+    fn __len__(&self) -> usize {
+        self.pulses.len()
+    }
+}
+
+impl Add<Firing> for Firing {
+    type Output = Firing;
+
+    fn add(mut self, other: Self) -> Self::Output {
+        self.pulses.extend(other.pulses);
+        self
+    }
+}
+
+impl Firing {
+    /// Return the times at which any pulse starts or ends.
+    pub(crate) fn edges(&self) -> impl Iterator<Item = f64> + '_ {
+        self.pulses.iter().flat_map(|p| [p.start, p.end])
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Cluster of thrusters with firing logic.
+///
+/// The cluster is commanded once per control period, at consecutive periods.  At each
+/// period, the cluster converts a commanded body torque into the duty
+/// cycle of each thruster by linear programming, minimizing the total impulse.  If the
+/// torque cannot be achieved within one period, it is scaled down, preserving its
+/// direction.
+///
+/// Without a modulator, each thruster fires once at the start of the period, for its
+/// duty cycle.  With a PWPF modulator, each thruster has its own modulator channel,
+/// driven by its duty cycle, which may fire several pulses per period or one pulse over
+/// several periods.  In both cases, pulses shorter than the minimum impulse bit of the
+/// thruster are dropped, and the valve delays are applied.
+pub struct ThrusterCluster {
+    #[pyo3(get)]
+    /// Thrusters.
+    pub thrusters: Vec<Thruster>,
+
+    #[pyo3(get)]
+    /// Control period (s).
+    pub period: f64,
+
+    #[pyo3(get, set)]
+    /// PWPF modulator, or `None` for a single pulse per period.
+    pub modulator: Option<Pwpf>,
+
+    // Torque matrix, whose columns are the thruster torques.
+    distribution: Matrix,
+
+    // Filter output of each modulator channel, and whether each thruster is commanded
+    // on at the end of the last period.
+    channels: Vec<(f64, bool)>,
+}
+
+#[pymethods]
+impl ThrusterCluster {
+    #[new]
+    #[pyo3(signature = (thrusters, period, modulator = None))]
+    /// Construct a new thruster cluster, given its thrusters, its control period and an
+    /// optional PWPF modulator.
+    ///
+    /// Raises `ValueError` if there are no thrusters or if the period is not positive.
+    pub fn new(thrusters: Vec<Thruster>, period: f64, modulator: Option<Pwpf>) -> PyResult<Self> {
+        positive("control period", period)?;
+        if thrusters.is_empty() {
+            return Err (PyValueError::new_err("thruster cluster must have thrusters"));
+        }
+
+        let mut distribution = Matrix::zeros(3, thrusters.len());
+        for (k, thruster) in thrusters.iter().enumerate() {
+            let t = thruster.torque();
+            distribution.set_block(0, k, &Vector::new(t.x, t.y, t.z).column());
+        }
+
+        Ok (Self {
+            channels: vec![(0.0, false); thrusters.len()],
+            thrusters,
+            period,
+            modulator,
+            distribution,
+        })
+    }
+
+    /// Compute the duty cycle of each thruster over one period that achieves a body
+    /// torque, on average, with the least total impulse.
+    ///
+    /// If the torque cannot be achieved within one period, it is scaled down,
+    /// preserving its direction, to the largest torque that can.
+    ///
+    /// Raises `ValueError` if the thrusters cannot produce a torque in the direction
+    /// commanded.
+    pub fn duty_cycles(&self, torque: Torque) -> PyResult<Vec<f64>> {
+        let n = self.thrusters.len();
+        let target = [torque.x, torque.y, torque.z];
+
+        if let Some (duty) = self.least_impulse(&target) {
+            return Ok (duty);
+        }
+
+        // Variables [d, t, s], all non-negative, minimizing t subject to
+        // D @ d == torque and d + s == t
+        let mut a = Matrix::zeros(3 + n, 2 * n + 1);
+        for k in 0..n {
+            for i in 0..3 {
+                a[(i, k)] = self.distribution[(i, k)];
+            }
+            a[(3 + k, k)] = 1.0;
+            a[(3 + k, n)] = -1.0;
+            a[(3 + k, n + 1 + k)] = 1.0;
+        }
+        let mut b = vec![0.0; 3 + n];
+        b[..3].copy_from_slice(&target);
+        let mut c = vec![0.0; 2 * n + 1];
+        c[n] = 1.0;
+
+        let x = linear_program(&c, &a, &b).ok_or_else(|| PyValueError::new_err(format!(
+            "thrusters cannot produce torque {}",
+            torque.__repr__(),
+        )))?;
+
+        // Scale down to the largest torque achievable within one period
+        let scaled = target.map(|v| v / x[n]);
+        Ok (self.least_impulse(&scaled).unwrap_or_else(|| x[..n].iter().map(|d| d / x[n]).collect()))
+    }
+
+    /// Command a body torque for the period starting at a given time, and return the
+    /// resulting firing.
+    ///
+    /// Raises `ValueError` if the thrusters cannot produce a torque in the direction
+    /// commanded.
+    pub fn command(&mut self, time: f64, torque: Torque) -> PyResult<Firing> {
+        let duty = self.duty_cycles(torque)?;
+
+        let mut pulses = Vec::new();
+        for (k, thruster) in self.thrusters.iter().enumerate() {
+            let (filter, on) = &mut self.channels[k];
+            let continues = *on;
+            let intervals = if let Some (modulator) = self.modulator {
+                modulator.modulate(filter, on, duty[k], self.period)
+            } else {
+                *on = duty[k] >= 1.0;
+                vec![(0.0, duty[k] * self.period)]
+            };
+
+            pulses.extend(intervals.into_iter()
+                .filter_map(|(s, e)| thruster.pulse(
+                    time + s,
+                    time + e,
+                    !(continues && s == 0.0),
+                    !(*on && e == self.period),
+                ))
+                .map(|(start, end)| Pulse {
+                    thruster: k,
+                    torque: thruster.torque(),
+                    start,
+                    end,
+                }));
+        }
+
+        Ok (Firing {
+            pulses,
+        })
+    }
+
+    /// Reset every modulator channel, and forget any pulse continuing from the last
+    /// period.
+    pub fn reset(&mut self) {
+        self.channels.fill((0.0, false));
+    }
+}
+
+impl ThrusterCluster {
+    /// Compute the duty cycles, each at most one, that achieve a body torque with the
+    /// least total impulse, or `None` if there are none.
+    fn least_impulse(&self, torque: &[f64; 3]) -> Option<Vec<f64>> {
+        let n = self.thrusters.len();
+
+        // Variables [d, s], all non-negative, minimizing the impulse subject to
+        // D @ d == torque and d + s == 1
+        let mut a = Matrix::zeros(3 + n, 2 * n);
+        for k in 0..n {
+            for i in 0..3 {
+                a[(i, k)] = self.distribution[(i, k)];
+            }
+            a[(3 + k, k)] = 1.0;
+            a[(3 + k, n + k)] = 1.0;
+        }
+        let mut b = vec![1.0; 3 + n];
+        b[..3].copy_from_slice(torque);
+        let mut c = vec![0.0; 2 * n];
+        for (ck, thruster) in c.iter_mut().zip(&self.thrusters) {
+            *ck = thruster.thrust;
+        }
+
+        let x = linear_program(&c, &a, &b)?;
+
+        Some (x[..n].iter().map(|d| d.min(1.0)).collect())
+    }
+}
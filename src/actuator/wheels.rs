@@ -0,0 +1,300 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Reaction wheel torque allocation.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularMomentum,
+    Torque,
+    Vector,
+};
+use crate::inertia::positive;
+use crate::linalg::Matrix;
+use crate::optimize::linear_program;
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Allocation of a body torque to a set of reaction wheels.
+pub struct Allocation {
+    #[pyo3(get)]
+    /// Torque command of each wheel, applied to the body about the wheel axis.
+    pub torques: Vec<f64>,
+
+    #[pyo3(get)]
+    /// Torque (body frame) actually achieved by the wheel torques.
+    pub torque: Torque,
+
+    #[pyo3(get)]
+    /// Whether any wheel torque was limited by the torque limit.
+    pub saturated: bool,
+}
+
+#[pymethods]
+impl Allocation {
+    /// Return a Pythonic representation of this allocation.
+    fn __repr__(&self) -> String {
+        format!(
+            "Allocation({:?}, {}, {})",
+            self.torques,
+            self.torque.__repr__(),
+            if self.saturated { "True" } else { "False" },
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Array of reaction wheels.
+///
+/// The wheels distribute a commanded body torque among themselves.  The torque of each
+/// wheel is the torque it applies to the body about its axis, so that the momentum of
+/// the wheel changes at the opposite rate.  With more than three wheels, the torques
+/// can be shifted along the null space of the array without affecting the body torque.
+///
+/// If the bias momentum is positive, null motion is injected into every allocation
+/// for which the wheel momenta are given, driving any wheel whose momentum is smaller
+/// in magnitude than the bias away from zero, so that wheel speeds avoid zero
+/// crossings.
+pub struct ReactionWheels {
+    #[pyo3(get)]
+    /// Unit spin axis of each wheel (body frame).
+    pub axes: Vec<Vector>,
+
+    #[pyo3(get, set)]
+    /// Torque limit of each wheel.
+    pub max_torque: f64,
+
+    #[pyo3(get, set)]
+    /// Smallest wheel momentum magnitude maintained by null motion.
+    pub bias_momentum: f64,
+
+    #[pyo3(get, set)]
+    /// Gain of the null motion (1/s).
+    pub null_gain: f64,
+
+    // Distribution matrix, whose columns are the wheel axes.
+    distribution: Matrix,
+
+    // Pseudo-inverse of the distribution matrix.
+    inverse: Matrix,
+}
+
+#[pymethods]
+impl ReactionWheels {
+    #[new]
+    /// Construct a new array of reaction wheels, given the spin axis of each wheel
+    /// (body frame) and the torque limit of each wheel.
+    ///
+    /// The axes are normalized automatically.  Raises `ValueError` if any axis is zero,
+    /// if the axes do not span three dimensions, or if the torque limit is not positive.
+    pub fn new(axes: Vec<Vector>, max_torque: f64) -> PyResult<Self> {
+        positive("maximum torque", max_torque)?;
+
+        let axes = axes.iter().map(|a| a.normalize()).collect::<PyResult<Vec<_>>>()?;
+        let mut distribution = Matrix::zeros(3, axes.len());
+        for (k, a) in axes.iter().enumerate() {
+            distribution.set_block(0, k, &a.column());
+        }
+
+        if (&distribution * &distribution.transpose()).inverse().is_none() {
+            return Err (PyValueError::new_err(
+                "reaction wheel axes must span three dimensions"
+            ));
+        }
+
+        Ok (Self {
+            axes,
+            max_torque,
+            bias_momentum: 0.0,
+            null_gain: 0.0,
+            inverse: distribution.pseudo_inverse(),
+            distribution,
+        })
+    }
+
+    #[getter]
+    /// Distribution matrix, whose columns are the wheel axes.
+    pub fn distribution(&self) -> Vec<Vec<f64>> {
+        self.distribution.to_rows()
+    }
+
+    #[pyo3(signature = (torque, momenta = None))]
+    /// Allocate a body torque by the pseudo-inverse, which minimizes the sum of squares
+    /// of the wheel torques, given the momentum of each wheel for null motion.
+    ///
+    /// Wheels that would exceed the torque limit are clamped, and the remaining torque
+    /// is redistributed among the other wheels.
+    ///
+    /// Raises `ValueError` if the number of momenta differs from the number of wheels.
+    pub fn pseudo_inverse(&self, torque: Torque, momenta: Option<Vec<f64>>) -> PyResult<Allocation> {
+        let n = self.axes.len();
+        let target = Matrix::column(&[torque.x, torque.y, torque.z]);
+
+        let mut u = vec![0.0; n];
+        let mut free: Vec<usize> = (0..n).collect();
+        let mut saturated = false;
+        while !free.is_empty() {
+            // Torque remaining after the clamped wheels
+            let mut remaining = target.clone();
+            for k in (0..n).filter(|k| !free.contains(k)) {
+                remaining = &remaining - &self.axes[k].column().scale(u[k]);
+            }
+
+            let mut subset = Matrix::zeros(3, free.len());
+            for (i, &k) in free.iter().enumerate() {
+                subset.set_block(0, i, &self.axes[k].column());
+            }
+            let solution = &subset.pseudo_inverse() * &remaining;
+            for (i, &k) in free.iter().enumerate() {
+                u[k] = solution[(i, 0)];
+            }
+
+            // Clamp any wheels over the limit and redistribute
+            let over: Vec<usize> = free.iter().copied().filter(|&k| u[k].abs() > self.max_torque).collect();
+            if over.is_empty() {
+                break;
+            }
+            for &k in &over {
+                u[k] = self.max_torque.copysign(u[k]);
+            }
+            free.retain(|k| !over.contains(k));
+            saturated = true;
+        }
+
+        self.finish(u, saturated, momenta)
+    }
+
+    #[pyo3(signature = (torque, momenta = None))]
+    /// Allocate a body torque so as to minimize the largest wheel torque, given the
+    /// momentum of each wheel for null motion.
+    ///
+    /// If the torque cannot be achieved within the torque limit, it is scaled down,
+    /// preserving its direction.
+    ///
+    /// Raises `ValueError` if the number of momenta differs from the number of wheels.
+    pub fn min_max(&self, torque: Torque, momenta: Option<Vec<f64>>) -> PyResult<Allocation> {
+        let n = self.axes.len();
+
+        // Variables [u+, u-, t, s+, s-], all non-negative, minimizing t subject to
+        // A @ (u+ - u-) == torque and -t <= u+ - u- <= t
+        let mut a = Matrix::zeros(3 + 2 * n, 4 * n + 1);
+        for k in 0..n {
+            for i in 0..3 {
+                a[(i, k)] = self.distribution[(i, k)];
+                a[(i, n + k)] = -self.distribution[(i, k)];
+            }
+            for (row, sign) in [(3 + k, 1.0), (3 + n + k, -1.0)] {
+                a[(row, k)] = sign;
+                a[(row, n + k)] = -sign;
+                a[(row, 2 * n)] = -1.0;
+            }
+            a[(3 + k, 2 * n + 1 + k)] = 1.0;
+            a[(3 + n + k, 3 * n + 1 + k)] = 1.0;
+        }
+        let mut b = vec![0.0; 3 + 2 * n];
+        b[..3].copy_from_slice(&[torque.x, torque.y, torque.z]);
+        let mut c = vec![0.0; 4 * n + 1];
+        c[2 * n] = 1.0;
+
+        let x = linear_program(&c, &a, &b).ok_or_else(|| PyValueError::new_err(
+            "reaction wheel allocation failed"
+        ))?;
+        let mut u: Vec<f64> = (0..n).map(|k| x[k] - x[n + k]).collect();
+
+        // Scale down to the torque limit
+        let largest = u.iter().fold(0.0, |m: f64, v| m.max(v.abs()));
+        let saturated = largest > self.max_torque;
+        if saturated {
+            u.iter_mut().for_each(|v| *v *= self.max_torque / largest);
+        }
+
+        self.finish(u, saturated, momenta)
+    }
+
+    /// Compute the total angular momentum of the wheels (body frame), given the
+    /// momentum of each wheel about its axis.
+    ///
+    /// Raises `ValueError` if the number of momenta differs from the number of wheels.
+    pub fn momentum(&self, momenta: Vec<f64>) -> PyResult<AngularMomentum> {
+        self.check(&momenta)?;
+        let h = &self.distribution * &Matrix::column(&momenta);
+
+        Ok (AngularMomentum::new(h[(0, 0)], h[(1, 0)], h[(2, 0)]))
+    }
+
+    /// Compute the null-motion wheel torques, given the momentum of each wheel.
+    ///
+    /// The null motion produces no body torque.  It drives the momentum of every wheel
+    /// whose momentum is smaller in magnitude than the bias momentum towards the bias,
+    /// at the rate set by the null gain, as far as the null space allows.
+    ///
+    /// Raises `ValueError` if the number of momenta differs from the number of wheels.
+    pub fn null_motion(&self, momenta: Vec<f64>) -> PyResult<Vec<f64>> {
+        let n = self.axes.len();
+        self.check(&momenta)?;
+
+        // Momentum error of each wheel
+        let error: Vec<f64> = momenta.iter().map(|&h| {
+            if h.abs() < self.bias_momentum {
+                let sign = if h < 0.0 { -1.0 } else { 1.0 };
+                sign * self.bias_momentum - h
+            } else {
+                0.0
+            }
+        }).collect();
+
+        // Project onto the null space, and reverse for the torque on the body
+        let projector = &Matrix::identity(n) - &(&self.inverse * &self.distribution);
+        let u = (&projector * &Matrix::column(&error)).scale(-self.null_gain);
+
+        Ok ((0..n).map(|k| u[(k, 0)]).collect())
+    }
+}
+
+impl ReactionWheels {
+    /// Raise `ValueError` unless there is one momentum per wheel.
+    fn check(&self, momenta: &[f64]) -> PyResult<()> {
+        if momenta.len() != self.axes.len() {
+            return Err (PyValueError::new_err(format!(
+                "expected {} wheel momenta, got {}",
+                self.axes.len(),
+                momenta.len(),
+            )));
+        }
+
+        Ok (())
+    }
+
+    /// Complete an allocation, injecting as much null motion as the torque limit allows
+    /// and computing the achieved torque.
+    fn finish(&self, mut u: Vec<f64>, saturated: bool, momenta: Option<Vec<f64>>) -> PyResult<Allocation> {
+        if let Some (momenta) = momenta {
+            let null = self.null_motion(momenta)?;
+
+            // Largest fraction of the null motion within the torque limit
+            let fraction = u.iter().zip(&null).fold(1.0, |s: f64, (&v, &d)| {
+                if d > 0.0 {
+                    s.min(((self.max_torque - v) / d).max(0.0))
+                } else if d < 0.0 {
+                    s.min(((-self.max_torque - v) / d).max(0.0))
+                } else {
+                    s
+                }
+            });
+            for (v, d) in u.iter_mut().zip(&null) {
+                *v += fraction * d;
+            }
+        }
+
+        let achieved = &self.distribution * &Matrix::column(&u);
+
+        Ok (Allocation {
+            torques: u,
+            torque: Torque::new(achieved[(0, 0)], achieved[(1, 0)], achieved[(2, 0)]),
+            saturated,
+        })
+    }
+}
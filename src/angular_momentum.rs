@@ -113,7 +113,7 @@ impl AngularMomentum {
     }
 
     /// Return a Pythonic representation of this vector.
-    fn __repr__(&self) -> String {
+    pub(crate) fn __repr__(&self) -> String {
         format!(
             "AngularMomentum({}, {}, {})",
             self.x,
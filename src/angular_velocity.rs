@@ -87,7 +87,7 @@ impl AngularVelocity {
     }
 
     /// Return a Pythonic representation of this vector.
-    fn __repr__(&self) -> String {
+    pub(crate) fn __repr__(&self) -> String {
         format!(
             "AngularVelocity({}, {}, {})",
             self.x,
@@ -130,30 +130,16 @@ impl AngularVelocity {
             self.x*hy - self.y*hx,
         );
 
-        // Determinant of inertia matrix
-        let det = inertia.j1*(
-            inertia.j2 * inertia.j3 - inertia.j4.powi(2)
-        ) + inertia.j6*(
-            inertia.j4 * inertia.j5 - inertia.j3 * inertia.j6
-        ) + inertia.j5*(
-            inertia.j4 * inertia.j6 - inertia.j2 * inertia.j5
-        );
-
-        // Components of inverse inertia (Voigt notation)
-        let i1 = (inertia.j2*inertia.j3 - inertia.j4.powi(2)) / det;
-        let i2 = (inertia.j1*inertia.j3 - inertia.j5.powi(2)) / det;
-        let i3 = (inertia.j1*inertia.j2 - inertia.j6.powi(2)) / det;
-        let i4 = (inertia.j5*inertia.j6 - inertia.j1*inertia.j4) / det;
-        let i5 = (inertia.j4*inertia.j6 - inertia.j2*inertia.j5) / det;
-        let i6 = (inertia.j5*inertia.j4 - inertia.j3*inertia.j6) / det;
+        // Inverse inertia (Voigt notation)
+        let inv = inertia.invert();
 
         // Torque and torque-free components
         let t = torque + torque_free;
     
         Self {
-            x: i1*t.x + i6*t.y + i5*t.z,
-            y: i6*t.x + i2*t.y + i4*t.z,
-            z: i5*t.x + i4*t.y + i3*t.z,
+            x: inv.j1*t.x + inv.j6*t.y + inv.j5*t.z,
+            y: inv.j6*t.x + inv.j2*t.y + inv.j4*t.z,
+            z: inv.j5*t.x + inv.j4*t.y + inv.j3*t.z,
         }
     }
 }
@@ -0,0 +1,286 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Batch sensor calibration.
+//!
+//! The routines in this module estimate the deterministic error parameters of a sensor
+//! from a recorded sequence of its measurements, using the same error models as
+//! [`crate::sensor`], so that the estimates can be compared directly with the truth
+//! parameters of a simulated sensor.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Quaternion,
+    Vector,
+};
+use crate::linalg::Matrix;
+
+/// Number of Gauss-Newton iterations of the ellipsoid fit.
+const GAUSS_NEWTON_ITERATIONS: usize = 20;
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Magnetometer calibration.
+///
+/// The calibrated magnetic field is recovered from a raw measurement by removing the
+/// hard-iron bias and applying the soft-iron correction.
+/// ```
+/// field = correction @ (measured - bias)
+/// ```
+/// The correction is symmetric, so it determines the misalignment of the magnetometer
+/// only up to a rotation.
+pub struct MagnetometerCalibration {
+    #[pyo3(get)]
+    /// Hard-iron bias (body frame).
+    pub bias: Vector,
+
+    #[pyo3(get)]
+    /// Symmetric soft-iron correction matrix.
+    pub correction: [[f64; 3]; 3],
+}
+
+#[pymethods]
+impl MagnetometerCalibration {
+    /// Apply this calibration to a raw magnetometer measurement.
+    pub fn apply(&self, measured: Vector) -> Vector {
+        let c = Matrix::from_rows(&self.correction.map(|r| r.to_vec())).unwrap();
+
+        Vector::from_column(&(&c * &(measured - self.bias).column()))
+    }
+
+    /// Return a Pythonic representation of this calibration.
+    fn __repr__(&self) -> String {
+        format!(
+            "MagnetometerCalibration({}, {:?})",
+            self.bias.__repr__(),
+            self.correction,
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Gyro calibration.
+///
+/// The calibration follows the error model of [`crate::sensor::Gyro`], with a
+/// misalignment matrix of unit diagonal.
+/// ```
+/// measured = (I + diag(scale_factor)) @ misalignment @ omega + bias
+/// ```
+pub struct GyroCalibration {
+    #[pyo3(get)]
+    /// Constant bias (body frame).
+    pub bias: AngularVelocity,
+
+    #[pyo3(get)]
+    /// Scale factor error of each axis (fractional).
+    pub scale_factor: (f64, f64, f64),
+
+    #[pyo3(get)]
+    /// Axis misalignment and non-orthogonality matrix, with unit diagonal.
+    pub misalignment: [[f64; 3]; 3],
+}
+
+#[pymethods]
+impl GyroCalibration {
+    /// Apply this calibration to a raw gyro measurement.
+    ///
+    /// Raises `ValueError` if the calibrated sensitivity matrix is singular.
+    pub fn apply(&self, measured: AngularVelocity) -> PyResult<AngularVelocity> {
+        let m = Matrix::from_rows(&self.misalignment.map(|r| r.to_vec())).unwrap();
+        let sf = [self.scale_factor.0, self.scale_factor.1, self.scale_factor.2];
+        let k = &Matrix::diagonal(&sf.map(|s| 1.0 + s)) * &m;
+        let inverse = k.inverse().ok_or_else(|| PyValueError::new_err(
+            "gyro sensitivity matrix is singular"
+        ))?;
+
+        let b = Matrix::column(&[
+            measured.x - self.bias.x,
+            measured.y - self.bias.y,
+            measured.z - self.bias.z,
+        ]);
+        let w = &inverse * &b;
+
+        Ok (AngularVelocity::new(w[(0, 0)], w[(1, 0)], w[(2, 0)]))
+    }
+
+    /// Return a Pythonic representation of this calibration.
+    fn __repr__(&self) -> String {
+        format!(
+            "GyroCalibration({}, {:?}, {:?})",
+            self.bias.__repr__(),
+            self.scale_factor,
+            self.misalignment,
+        )
+    }
+}
+
+#[pyfunction]
+/// Calibrate a magnetometer by fitting an ellipsoid to its raw measurements.
+///
+/// Each raw measurement is paired with the magnitude of the true magnetic field at the
+/// time of the measurement, which may vary along the orbit.  The bias and soft-iron
+/// correction are found by least squares on the squared magnitude of the calibrated
+/// field, by Gauss-Newton iteration from an algebraic ellipsoid fit.  The measurements
+/// should cover as many directions as possible.
+///
+/// Raises `ValueError` if there are fewer than nine measurements, if the numbers of
+/// measurements and magnitudes differ, or if the measurements do not determine an
+/// ellipsoid.
+pub fn magnetometer(measurements: Vec<Vector>, magnitudes: Vec<f64>) -> PyResult<MagnetometerCalibration> {
+    if measurements.len() != magnitudes.len() {
+        return Err (PyValueError::new_err(
+            "magnetometer calibration needs one field magnitude per measurement"
+        ));
+    }
+    if measurements.len() < 9 {
+        return Err (PyValueError::new_err(
+            "magnetometer calibration needs at least nine measurements"
+        ));
+    }
+    let singular = || PyValueError::new_err(
+        "magnetometer measurements do not determine an ellipsoid"
+    );
+
+    // Work in units of the RMS field magnitude, for conditioning
+    let n = measurements.len() as f64;
+    let unit = (magnitudes.iter().map(|h| h * h).sum::<f64>() / n).sqrt();
+    let m: Vec<Vector> = measurements.iter().map(|v| v.scale(unit.recip())).collect();
+    let h2: Vec<f64> = magnitudes.iter().map(|h| (h / unit).powi(2)).collect();
+
+    // Algebraic fit of x.T @ A @ x + b.T @ x = 1
+    let rows: Vec<Vec<f64>> = m.iter().map(|v| {
+        let Vector { x, y, z } = *v;
+        vec![x*x, y*y, z*z, 2.0*y*z, 2.0*x*z, 2.0*x*y, x, y, z]
+    }).collect();
+    let p = least_squares(&rows, &vec![1.0; m.len()]).ok_or_else(singular)?;
+    let a = voigt(&p[..6]);
+    let center = &a.inverse().ok_or_else(singular)? * &Matrix::column(&p[6..]).scale(-0.5);
+    let r = 1.0 + (&(&center.transpose() * &a) * &center)[(0, 0)];
+
+    // Scale to the mean squared magnitude
+    let mut e = a.scale(h2.iter().sum::<f64>() / n / r);
+    let mut o = Vector::from_column(&center);
+
+    // Refine (o, E) to minimize sum((d.T @ E @ d - h^2)^2), where d = m - o
+    for _ in 0..GAUSS_NEWTON_ITERATIONS {
+        let mut jacobian = Vec::with_capacity(m.len());
+        let mut residual = Vec::with_capacity(m.len());
+        for (v, h2) in m.iter().zip(&h2) {
+            let d = (*v - o).column();
+            let ed = &e * &d;
+            let Vector { x, y, z } = Vector::from_column(&d);
+
+            residual.push(h2 - (&d.transpose() * &ed)[(0, 0)]);
+            jacobian.push(vec![
+                x*x, y*y, z*z, 2.0*y*z, 2.0*x*z, 2.0*x*y,
+                -2.0 * ed[(0, 0)], -2.0 * ed[(1, 0)], -2.0 * ed[(2, 0)],
+            ]);
+        }
+
+        let delta = least_squares(&jacobian, &residual).ok_or_else(singular)?;
+        e = &e + &voigt(&delta[..6]);
+        o = o + Vector::new(delta[6], delta[7], delta[8]);
+    }
+
+    // Symmetric square root of E
+    let (values, vectors) = e.symmetric_eigen();
+    if values[0].is_nan() || values[0] <= 0.0 {
+        return Err (singular());
+    }
+    let root = Matrix::diagonal(&values.iter().map(|l| l.sqrt()).collect::<Vec<f64>>());
+    let correction = &(&vectors * &root) * &vectors.transpose();
+
+    Ok (MagnetometerCalibration {
+        bias: o.scale(unit),
+        correction: std::array::from_fn(|i| std::array::from_fn(|j| correction[(i, j)])),
+    })
+}
+
+#[pyfunction]
+/// Calibrate a gyro against a sequence of reference attitudes, such as those of a
+/// star tracker.
+///
+/// The reference angular velocity over each interval is found from consecutive
+/// attitudes, and compared with the mean of the gyro measurements at either end of the
+/// interval.  The bias, scale factors and misalignment are then found by least squares.
+/// The attitudes must be sampled finely enough that the rotation over each interval is
+/// small, and the motion must excite all three axes.
+///
+/// Raises `ValueError` if the sequences have different lengths, if there are fewer
+/// than five samples, if the times are not increasing, or if the motion does not
+/// determine the calibration.
+pub fn gyro(
+    times: Vec<f64>,
+    attitudes: Vec<Quaternion>,
+    measurements: Vec<AngularVelocity>,
+) -> PyResult<GyroCalibration> {
+    if times.len() != attitudes.len() || times.len() != measurements.len() {
+        return Err (PyValueError::new_err(
+            "gyro calibration needs one time and attitude per measurement"
+        ));
+    }
+    if times.len() < 5 {
+        return Err (PyValueError::new_err(
+            "gyro calibration needs at least five samples"
+        ));
+    }
+
+    let mut rows = Vec::with_capacity(times.len() - 1);
+    let mut outputs = Vec::with_capacity(times.len() - 1);
+    for k in 0..times.len() - 1 {
+        let dt = times[k + 1] - times[k];
+        if dt.is_nan() || dt <= 0.0 {
+            return Err (PyValueError::new_err("gyro calibration times must be increasing"));
+        }
+
+        // Body-frame rotation over the interval
+        let q = attitudes[k].normalize()?.inv() * attitudes[k + 1].normalize()?;
+        let (x, y, z) = q.rotation_vector();
+        rows.push(vec![x / dt, y / dt, z / dt, 1.0]);
+
+        let (a, b) = (measurements[k], measurements[k + 1]);
+        outputs.push([a.x + b.x, a.y + b.y, a.z + b.z].map(|s| 0.5 * s));
+    }
+
+    // Each axis is an independent regression on the reference rate
+    let mut k = [[0.0; 3]; 3];
+    let mut bias = [0.0; 3];
+    for i in 0..3 {
+        let y: Vec<f64> = outputs.iter().map(|o| o[i]).collect();
+        let p = least_squares(&rows, &y).ok_or_else(|| PyValueError::new_err(
+            "gyro measurements do not determine the calibration"
+        ))?;
+        k[i] = [p[0], p[1], p[2]];
+        bias[i] = p[3];
+    }
+
+    Ok (GyroCalibration {
+        bias: AngularVelocity::new(bias[0], bias[1], bias[2]),
+        scale_factor: (k[0][0] - 1.0, k[1][1] - 1.0, k[2][2] - 1.0),
+        misalignment: std::array::from_fn(|i| k[i].map(|v| v / k[i][i])),
+    })
+}
+
+/// Solve an overdetermined linear system in the least-squares sense, by the normal
+/// equations.  Returns `None` if the system is rank deficient.
+fn least_squares(rows: &[Vec<f64>], y: &[f64]) -> Option<Vec<f64>> {
+    let a = Matrix::from_rows(rows)?;
+    let at = a.transpose();
+    let x = &(&(&at * &a).inverse()? * &at) * &Matrix::column(y);
+
+    Some ((0..x.rows).map(|i| x[(i, 0)]).collect())
+}
+
+/// Construct a symmetric matrix from its six components in Voigt notation,
+/// as in [`crate::Inertia`].
+fn voigt(p: &[f64]) -> Matrix {
+    Matrix::from_rows(&[
+        vec![p[0], p[5], p[4]],
+        vec![p[5], p[1], p[3]],
+        vec![p[4], p[3], p[2]],
+    ]).unwrap()
+}
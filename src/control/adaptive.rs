@@ -0,0 +1,276 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Adaptive attitude controller.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Torque,
+    Vector,
+};
+use crate::guidance::Reference;
+use crate::inertia::positive;
+
+use super::{
+    Controller,
+    Gains,
+    measured_motion,
+    sliding_variable,
+};
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Adaptive attitude controller with online inertia estimation.
+///
+/// The controller commands the certainty-equivalence torque
+/// ```
+/// torque = J_est @ omega_r_dot - (J_est @ omega) x omega_r - gain * s
+/// ```
+/// where `s = omega_err + slope * q_err.vector` is the sliding variable and
+/// `omega_r = omega - s` is the reference angular velocity, and adapts the estimated
+/// inertia tensor `J_est` along
+/// ```
+/// d(theta)/dt = -adaptation_rate * Y.T @ s
+/// ```
+/// where `theta` holds the six independent components of `J_est` and `Y` is the
+/// regressor, such that the feedforward terms equal `Y @ theta`.  The tracking error
+/// converges for any constant inertia, although the estimate converges to the true
+/// inertia only under sufficiently exciting motion.
+///
+/// As a [`Controller`] of a [`crate::Simulation`], the controller reads the attitude
+/// and angular velocity from the measurements of the named sensors, adapts over the
+/// time elapsed since its previous update, and commands the torque to the named
+/// actuator.
+pub struct AdaptiveControl {
+    #[pyo3(get, set)]
+    /// Estimated inertia tensor of the body.
+    pub inertia: Inertia,
+
+    #[pyo3(get, set)]
+    /// Slope of the sliding surface (rad/s).
+    pub slope: f64,
+
+    #[pyo3(get, set)]
+    /// Feedback gain of each axis.
+    pub gain: Gains,
+
+    #[pyo3(get, set)]
+    /// Adaptation rate.
+    pub adaptation_rate: f64,
+
+    #[pyo3(get, set)]
+    /// Target attitude (rotation from target frame to reference frame).
+    pub target: Quaternion,
+
+    #[pyo3(get, set)]
+    /// Target angular velocity (target frame).
+    pub target_rate: AngularVelocity,
+
+    #[pyo3(get, set)]
+    /// Target angular acceleration (target frame).
+    pub target_acceleration: AngularVelocity,
+
+    #[pyo3(get, set)]
+    /// Name of the sensor measuring the attitude, as a `Quaternion`.
+    pub attitude_sensor: String,
+
+    #[pyo3(get, set)]
+    /// Name of the sensor measuring the angular velocity (body frame).
+    pub rate_sensor: String,
+
+    #[pyo3(get, set)]
+    /// Name of the actuator commanded with the torque.
+    pub actuator: String,
+
+    // Time of the most recent update.
+    last: Option<f64>,
+}
+
+#[pymethods]
+impl AdaptiveControl {
+    #[new]
+    #[pyo3(signature = (inertia, slope, gain, adaptation_rate, target = None, target_rate = None))]
+    /// Construct a new adaptive controller, given the initial estimate of the inertia
+    /// tensor of the body, the slope of the sliding surface, the feedback gain of each
+    /// axis and the adaptation rate.  By default, the target is the reference frame at
+    /// rest.
+    ///
+    /// Raises `ValueError` if the slope, any gain or the adaptation rate is not
+    /// positive.
+    pub fn new(
+        inertia: Inertia,
+        slope: f64,
+        gain: Gains,
+        adaptation_rate: f64,
+        target: Option<Quaternion>,
+        target_rate: Option<AngularVelocity>,
+    ) -> PyResult<Self> {
+        positive("sliding surface slope", slope)?;
+        for g in [gain.0, gain.1, gain.2] {
+            positive("controller gain", g)?;
+        }
+        positive("adaptation rate", adaptation_rate)?;
+
+        Ok (Self {
+            inertia,
+            slope,
+            gain,
+            adaptation_rate,
+            target: target.unwrap_or(Quaternion::new(1.0, 0.0, 0.0, 0.0)).normalize()?,
+            target_rate: target_rate.unwrap_or(AngularVelocity::new(0.0, 0.0, 0.0)),
+            target_acceleration: AngularVelocity::new(0.0, 0.0, 0.0),
+            attitude_sensor: "attitude".to_string(),
+            rate_sensor: "rate".to_string(),
+            actuator: "torque".to_string(),
+            last: None,
+        })
+    }
+
+    /// Track a reference, setting the target attitude, angular velocity and angular
+    /// acceleration.
+    pub fn track(&mut self, reference: Reference) {
+        self.target = reference.quaternion;
+        self.target_rate = reference.angular_velocity;
+        self.target_acceleration = reference.angular_acceleration;
+    }
+
+    /// Compute the sliding variable (body frame), given the attitude and angular
+    /// velocity of the body.
+    pub fn surface(&self, quaternion: Quaternion, angular_velocity: AngularVelocity) -> Vector {
+        let (s, _, _) = self.sliding_variable(quaternion, angular_velocity);
+
+        s
+    }
+
+    /// Compute the commanded torque (body frame), given the attitude and angular
+    /// velocity of the body.
+    pub fn torque(&self, py: Python<'_>, quaternion: Quaternion, angular_velocity: AngularVelocity) -> Torque {
+        let (s, wr, wr_dot) = self.sliding_variable(quaternion, angular_velocity);
+        let j = |v: Vector| {
+            let h = AngularMomentum::product(
+                &py.get_type::<AngularMomentum>(),
+                self.inertia,
+                AngularVelocity::new(v.x, v.y, v.z),
+            );
+            Vector::new(h.x, h.y, h.z)
+        };
+        let w = Vector::new(angular_velocity.x, angular_velocity.y, angular_velocity.z);
+
+        let t = j(wr_dot) - j(w).cross(wr) - Vector::new(
+            self.gain.0 * s.x,
+            self.gain.1 * s.y,
+            self.gain.2 * s.z,
+        );
+        Torque::new(t.x, t.y, t.z)
+    }
+
+    /// Adapt the estimated inertia tensor over a time step, given the attitude and
+    /// angular velocity of the body at the start of the step.
+    ///
+    /// This should be called once per control update.  Raises `ValueError` if the time
+    /// step is not positive.
+    pub fn adapt(&mut self, quaternion: Quaternion, angular_velocity: AngularVelocity, dt: f64) -> PyResult<()> {
+        positive("time step", dt)?;
+
+        let (s, wr, wr_dot) = self.sliding_variable(quaternion, angular_velocity);
+        let w = Vector::new(angular_velocity.x, angular_velocity.y, angular_velocity.z);
+
+        // Y.T @ s, where Y @ theta = J @ omega_r_dot + omega_r x (J @ omega)
+        let a = regressor(wr_dot, s);
+        let b = regressor(w, s.cross(wr));
+        let step = self.adaptation_rate * dt;
+
+        self.inertia = Inertia::new(
+            self.inertia.j1 - step * (a[0] + b[0]),
+            self.inertia.j2 - step * (a[1] + b[1]),
+            self.inertia.j3 - step * (a[2] + b[2]),
+            self.inertia.j4 - step * (a[3] + b[3]),
+            self.inertia.j5 - step * (a[4] + b[4]),
+            self.inertia.j6 - step * (a[5] + b[5]),
+        );
+
+        Ok (())
+    }
+
+    /// Compute the actuator commands at a given time, given the latest measurement of
+    /// each sensor, keyed by sensor name.
+    ///
+    /// The estimated inertia tensor is first adapted over the time elapsed since the
+    /// previous update.  Returns `{actuator: torque}`, or no commands until both the
+    /// attitude and the angular velocity have been measured.
+    pub fn update<'py>(
+        &mut self,
+        time: f64,
+        measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let py = measurements.py();
+        let commands = PyDict::new(py);
+        let last = self.last.replace(time);
+        let Some ((q, w)) = measured_motion(measurements, &self.attitude_sensor, &self.rate_sensor)? else {
+            return Ok (commands);
+        };
+
+        if let Some (last) = last.filter(|&last| time > last) {
+            self.adapt(q, w, time - last)?;
+        }
+        commands.set_item(&self.actuator, self.torque(py, q, w))?;
+
+        Ok (commands)
+    }
+
+    /// Return a Pythonic representation of this controller.
+    fn __repr__(&self) -> String {
+        format!(
+            "AdaptiveControl({}, {}, {:?}, {}, {}, {})",
+            self.inertia.__repr__(),
+            self.slope,
+            self.gain,
+            self.adaptation_rate,
+            self.target.__repr__(),
+            self.target_rate.__repr__(),
+        )
+    }
+}
+
+impl Controller for AdaptiveControl {
+    fn update<'py>(
+        &mut self,
+        time: f64,
+        measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        self.update(time, measurements)
+    }
+}
+
+impl AdaptiveControl {
+    /// Compute the sliding variable, reference angular velocity and its derivative.
+    fn sliding_variable(&self, quaternion: Quaternion, angular_velocity: AngularVelocity) -> (Vector, Vector, Vector) {
+        sliding_variable(
+            quaternion,
+            angular_velocity,
+            self.target,
+            self.target_rate,
+            self.target_acceleration,
+            self.slope,
+        )
+    }
+}
+
+/// Compute `L(v).T @ x`, where `L(v) @ theta == J @ v` for the independent components
+/// `theta = [j1, j2, j3, j4, j5, j6]` of an inertia tensor `J`.
+fn regressor(v: Vector, x: Vector) -> [f64; 6] {
+    [
+        v.x * x.x,
+        v.y * x.y,
+        v.z * x.z,
+        v.z * x.y + v.y * x.z,
+        v.z * x.x + v.x * x.z,
+        v.y * x.x + v.x * x.y,
+    ]
+}
@@ -0,0 +1,167 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Magnetic detumble controllers.
+
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Torque,
+    Vector,
+};
+use crate::inertia::positive;
+
+use super::{
+    magnetic_torque,
+    saturate,
+};
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// B-dot detumble controller.
+///
+/// The B-dot controller commands a magnetic dipole opposing the rate of change of the
+/// magnetic field (body frame), which it estimates by differencing successive
+/// magnetometer readings.
+/// ```
+/// dipole = -gain * dB/dt / |B|^2
+/// ```
+/// For a slowly-varying field, the resulting torque damps the angular velocity
+/// perpendicular to the field, with damping coefficient `gain`.  The dipole is scaled
+/// down, preserving its direction, so that no component exceeds the saturation limit.
+pub struct BDot {
+    #[pyo3(get, set)]
+    /// Damping gain (N m s).
+    pub gain: f64,
+
+    #[pyo3(get, set)]
+    /// Saturation limit of each magnetorquer (A m^2).
+    pub max_dipole: f64,
+
+    // Time and magnetic field of the previous reading.
+    last: Option<(f64, Vector)>,
+}
+
+#[pymethods]
+impl BDot {
+    #[new]
+    /// Construct a new B-dot controller, given its damping gain and the saturation
+    /// limit of each magnetorquer.
+    ///
+    /// Raises `ValueError` if the gain or saturation limit is not positive.
+    pub fn new(gain: f64, max_dipole: f64) -> PyResult<Self> {
+        positive("controller gain", gain)?;
+        positive("maximum dipole", max_dipole)?;
+
+        Ok (Self {
+            gain,
+            max_dipole,
+            last: None,
+        })
+    }
+
+    /// Compute the commanded dipole (body frame), given a magnetometer reading of the
+    /// magnetic field (body frame) and the time of the reading.
+    ///
+    /// The first reading, and any reading that is not later than the previous one,
+    /// only initializes the rate estimate, and commands zero dipole.
+    pub fn dipole(&mut self, time: f64, field: Vector) -> Vector {
+        let last = self.last.replace((time, field));
+
+        match last {
+            Some ((t, previous)) if time > t && field.norm() > 0.0 => {
+                let rate = (field - previous).scale((time - t).recip());
+                saturate(rate.scale(-self.gain / field.dot(field)), self.max_dipole)
+            },
+            _ => Vector::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Compute the commanded torque (body frame), given a magnetometer reading of the
+    /// magnetic field (body frame) and the time of the reading.
+    pub fn torque(&mut self, time: f64, field: Vector) -> Torque {
+        magnetic_torque(self.dipole(time, field), field)
+    }
+
+    /// Discard the previous magnetometer reading.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+
+    /// Return a Pythonic representation of this controller.
+    fn __repr__(&self) -> String {
+        format!(
+            "BDot({}, {})",
+            self.gain,
+            self.max_dipole,
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// B-cross detumble controller.
+///
+/// The B-cross controller uses the measured angular velocity in place of the rate of
+/// change of the magnetic field.
+/// ```
+/// dipole = gain * (omega x B) / |B|^2
+/// ```
+/// The resulting torque, `-gain * omega` projected perpendicular to the field, damps
+/// the angular velocity with damping coefficient `gain`.  The dipole is scaled down,
+/// preserving its direction, so that no component exceeds the saturation limit.
+pub struct BCross {
+    #[pyo3(get, set)]
+    /// Damping gain (N m s).
+    pub gain: f64,
+
+    #[pyo3(get, set)]
+    /// Saturation limit of each magnetorquer (A m^2).
+    pub max_dipole: f64,
+}
+
+#[pymethods]
+impl BCross {
+    #[new]
+    /// Construct a new B-cross controller, given its damping gain and the saturation
+    /// limit of each magnetorquer.
+    ///
+    /// Raises `ValueError` if the gain or saturation limit is not positive.
+    pub fn new(gain: f64, max_dipole: f64) -> PyResult<Self> {
+        positive("controller gain", gain)?;
+        positive("maximum dipole", max_dipole)?;
+
+        Ok (Self {
+            gain,
+            max_dipole,
+        })
+    }
+
+    /// Compute the commanded dipole (body frame), given the magnetic field and the
+    /// angular velocity (both body frame).
+    pub fn dipole(&self, field: Vector, angular_velocity: AngularVelocity) -> Vector {
+        let b2 = field.dot(field);
+        if b2 == 0.0 {
+            return Vector::new(0.0, 0.0, 0.0);
+        }
+
+        let w = Vector::new(angular_velocity.x, angular_velocity.y, angular_velocity.z);
+        saturate(w.cross(field).scale(self.gain / b2), self.max_dipole)
+    }
+
+    /// Compute the commanded torque (body frame), given the magnetic field and the
+    /// angular velocity (both body frame).
+    pub fn torque(&self, field: Vector, angular_velocity: AngularVelocity) -> Torque {
+        magnetic_torque(self.dipole(field, angular_velocity), field)
+    }
+
+    /// Return a Pythonic representation of this controller.
+    fn __repr__(&self) -> String {
+        format!(
+            "BCross({}, {})",
+            self.gain,
+            self.max_dipole,
+        )
+    }
+}
@@ -0,0 +1,71 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Controller base class.
+
+use pyo3::exceptions::PyNotImplementedError;
+use pyo3::prelude::*;
+use pyo3::types::{
+    PyDict,
+    PyTuple,
+};
+
+use super::Controller;
+
+#[pyclass(subclass, name = "Controller")]
+#[derive(Clone, Copy, Debug, Default)]
+/// Base class of closed-loop controllers.
+///
+/// Subclasses override `update`, which is called by [`crate::Simulation`] at the
+/// control rate with the latest measurement of each sensor, and returns a dict of
+/// commands keyed by actuator name.
+pub struct ControllerBase;
+
+#[pymethods]
+impl ControllerBase {
+    #[new]
+    #[pyo3(signature = (*_args, **_kwargs))]
+    /// Construct a new controller.
+    ///
+    /// Any arguments are accepted and ignored, so that subclasses are free to define
+    /// their own constructors.
+    pub fn new(_args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>) -> Self {
+        Self
+    }
+
+    /// Compute the actuator commands at a given time, given the latest measurement of
+    /// each sensor, keyed by sensor name.
+    ///
+    /// Raises `NotImplementedError` unless overridden.
+    pub fn update<'py>(
+        &mut self,
+        _time: f64,
+        _measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        Err (PyNotImplementedError::new_err(
+            "controllers must override update(time, measurements)"
+        ))
+    }
+}
+
+impl Controller for ControllerBase {
+    fn update<'py>(
+        &mut self,
+        time: f64,
+        measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        self.update(time, measurements)
+    }
+}
+
+impl Controller for Bound<'_, PyAny> {
+    fn update<'py>(
+        &mut self,
+        time: f64,
+        measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let commands = self.call_method1("update", (time, measurements))?;
+
+        Ok (commands.downcast_into::<PyDict>()?.unbind().into_bound(measurements.py()))
+    }
+}
@@ -0,0 +1,518 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Linearized attitude dynamics and linear-quadratic regulators.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Derivative,
+    Inertia,
+    Integrator,
+    Quaternion,
+    State,
+    Torque,
+    Vector,
+};
+use crate::inertia::positive;
+use crate::integrator::ForwardEuler;
+use crate::linalg::Matrix;
+
+use super::{
+    gyroscopic,
+    tracking_error,
+};
+
+/// Perturbation of the central finite differences.
+const FINITE_DIFFERENCE_STEP: f64 = 1e-6;
+
+/// Convergence threshold of the Riccati iterations.
+const TOLERANCE: f64 = 1e-12;
+
+/// Maximum number of Riccati iterations before giving up.
+const MAX_ITERATIONS: usize = 100;
+
+/// Dense matrix, as a list of rows.
+type Rows = Vec<Vec<f64>>;
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Linear time-invariant state-space model.
+///
+/// A continuous-time model gives `dx/dt = A @ x + B @ u`; a discrete-time model, with a
+/// time step, gives `x[k+1] = A @ x[k] + B @ u[k]`.
+///
+/// A model of the attitude dynamics has the state `x = [q_err.vector, omega_err]`, the
+/// vector part of the attitude error quaternion and the angular velocity error (body
+/// frame), as computed by the controllers in this module, and the input `u`, the
+/// torque (body frame) in addition to the trim torque.
+pub struct StateSpace {
+    // State matrix.
+    a: Matrix,
+
+    // Input matrix.
+    b: Matrix,
+
+    #[pyo3(get)]
+    /// Torque (body frame) that holds the equilibrium.
+    pub trim: Torque,
+
+    #[pyo3(get)]
+    /// Time step of a discrete-time model, or `None` for a continuous-time model.
+    pub time_step: Option<f64>,
+}
+
+#[pymethods]
+impl StateSpace {
+    #[new]
+    #[pyo3(signature = (a, b, time_step = None))]
+    /// Construct a new state-space model, given its state and input matrices as lists
+    /// of rows, and the time step of a discrete-time model.
+    ///
+    /// Raises `ValueError` if the state matrix is not square, if the input matrix has a
+    /// different number of rows, or if the time step is not positive.
+    pub fn new(a: Rows, b: Rows, time_step: Option<f64>) -> PyResult<Self> {
+        if let Some (dt) = time_step {
+            positive("time step", dt)?;
+        }
+
+        let a = matrix("state matrix", &a)?;
+        let b = matrix("input matrix", &b)?;
+        if a.rows != a.cols || b.rows != a.rows {
+            return Err (PyValueError::new_err(format!(
+                "expected a square state matrix and an input matrix with as many rows, got {}x{} and {}x{}",
+                a.rows,
+                a.cols,
+                b.rows,
+                b.cols,
+            )));
+        }
+
+        Ok (Self {
+            a,
+            b,
+            trim: Torque::new(0.0, 0.0, 0.0),
+            time_step,
+        })
+    }
+
+    #[getter]
+    /// State matrix, as a list of rows.
+    pub fn a(&self) -> Rows {
+        self.a.to_rows()
+    }
+
+    #[getter]
+    /// Input matrix, as a list of rows.
+    pub fn b(&self) -> Rows {
+        self.b.to_rows()
+    }
+
+    /// Discretize this continuous-time model with a zero-order hold on the input, given
+    /// the time step.
+    ///
+    /// Raises `ValueError` if this model is already discrete or if the time step is not
+    /// positive.
+    pub fn discretize(&self, time_step: f64) -> PyResult<Self> {
+        positive("time step", time_step)?;
+        if self.time_step.is_some() {
+            return Err (PyValueError::new_err("state-space model is already discrete"));
+        }
+
+        // exp([[A, B], [0, 0]] * dt) = [[Ad, Bd], [0, I]]
+        let (n, m) = (self.b.rows, self.b.cols);
+        let mut augmented = Matrix::zeros(n + m, n + m);
+        augmented.set_block(0, 0, &self.a);
+        augmented.set_block(0, n, &self.b);
+        let e = augmented.scale(time_step).exp();
+
+        Ok (Self {
+            a: e.block(0, 0, n, n),
+            b: e.block(0, n, n, m),
+            trim: self.trim,
+            time_step: Some (time_step),
+        })
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (state, nadir = None, numeric = false))]
+/// Linearize the attitude dynamics about the attitude and angular velocity of a state.
+///
+/// The reference frame rotates with the body at the angular velocity of the state,
+/// which is constant in the body frame, so the equilibrium may be an inertial hold (zero
+/// angular velocity), a spin, or an orbiting LVLH frame.  If the direction of nadir
+/// (body frame) at equilibrium is given, nadir is fixed in the reference frame, the
+/// body is taken to be in a circular orbit at the rate of its angular velocity, and the
+/// gravity-gradient torque is included.  The trim torque of the model holds the
+/// equilibrium; it is zero for a spin about a principal axis.
+///
+/// The linearization is analytic by default, or by central finite differences of the
+/// rigid-body dynamics of the integrators.  The damper, rotor, flexible modes, slosh
+/// pendulum and articulated bodies of the state, if any, are ignored.
+///
+/// Raises `ValueError` if the direction of nadir is zero.
+pub fn linearize(state: State, nadir: Option<Vector>, numeric: bool) -> PyResult<StateSpace> {
+    let inertia = state.inertia;
+    let w = state.angular_velocity;
+    let rate = (w.x*w.x + w.y*w.y + w.z*w.z).sqrt();
+    let nadir = nadir.map(|c| c.normalize()).transpose()?;
+
+    let gg = |c: Option<Vector>| c.map_or(Torque::new(0.0, 0.0, 0.0), |c| {
+        gravity_gradient(inertia, c, rate)
+    });
+    let trim = gyroscopic(inertia, w) - gg(nadir);
+
+    let (a, b) = if numeric {
+        let model = ForwardEuler::new(1.0, false)?;
+        let q0 = state.quaternion.normalize()?;
+        let frame_rate = Quaternion::new(0.0, w.x, w.y, w.z);
+
+        // Time derivative of the error state, given the error state and input
+        let derivative = |x: &[f64]| -> PyResult<Matrix> {
+            let v = x[0]*x[0] + x[1]*x[1] + x[2]*x[2];
+            let dq = Quaternion::new((1.0 - v).sqrt(), x[0], x[1], x[2]);
+            let dw = Vector::new(x[3], x[4], x[5]);
+
+            // Angular velocity of the reference frame, and nadir, in the body frame
+            let frame = w.rotate(dq.inv());
+            let c = nadir.map(|c| c.rotate(dq.inv()));
+
+            let mut s = state.clone();
+            s.damper = None;
+            s.rotor = None;
+            s.flexible = None;
+            s.slosh = None;
+            s.multibody = None;
+            s.quaternion = q0 * dq;
+            s.angular_velocity = frame + AngularVelocity::new(dw.x, dw.y, dw.z);
+            s.torque = trim + Torque::new(x[6], x[7], x[8]) + gg(c);
+            let Derivative { quaternion: qdot, angular_velocity: wdot, .. } = model.dynamics(&s);
+
+            let dqdot = q0.inv() * qdot - (frame_rate * dq).scale(0.5);
+            let transport = dw.cross(Vector::new(frame.x, frame.y, frame.z));
+
+            Ok (Matrix::column(&[
+                dqdot.x,
+                dqdot.y,
+                dqdot.z,
+                wdot.x + transport.x,
+                wdot.y + transport.y,
+                wdot.z + transport.z,
+            ]))
+        };
+
+        // Central differences about the equilibrium
+        let h = FINITE_DIFFERENCE_STEP;
+        let mut jacobian = Matrix::zeros(6, 9);
+        for i in 0..9 {
+            let mut plus = [0.0; 9];
+            let mut minus = [0.0; 9];
+            plus[i] = h;
+            minus[i] = -h;
+            let column = (&derivative(&plus)? - &derivative(&minus)?).scale(0.5 / h);
+            jacobian.set_block(0, i, &column);
+        }
+
+        (jacobian.block(0, 0, 6, 6), jacobian.block(0, 6, 6, 3))
+    } else {
+        let j = inertia.matrix();
+        let jinv = inertia.invert().matrix();
+        let wx = Matrix::skew(w.x, w.y, w.z);
+        let h = &j * &Matrix::column(&[w.x, w.y, w.z]);
+
+        // Gyroscopic stiffness, d(-omega x J @ omega) / d(omega)
+        let m = &Matrix::skew(h[(0, 0)], h[(1, 0)], h[(2, 0)]) - &(&wx * &j);
+
+        // Gravity-gradient stiffness, d(torque) / d(q_err.vector)
+        let g = if let Some (c) = nadir {
+            let jc = &j * &c.column();
+            let cx = Matrix::skew(c.x, c.y, c.z);
+            let jcx = Matrix::skew(jc[(0, 0)], jc[(1, 0)], jc[(2, 0)]);
+            (&(&(&cx * &j) - &jcx) * &cx).scale(6.0 * rate * rate)
+        } else {
+            Matrix::zeros(3, 3)
+        };
+
+        let mut a = Matrix::zeros(6, 6);
+        a.set_block(0, 3, &Matrix::identity(3).scale(0.5));
+        a.set_block(3, 0, &(&jinv * &(&(&m * &wx).scale(2.0) + &g)));
+        a.set_block(3, 3, &(&(&jinv * &m) - &wx));
+        let mut b = Matrix::zeros(6, 3);
+        b.set_block(3, 0, &jinv);
+
+        (a, b)
+    };
+
+    Ok (StateSpace {
+        a,
+        b,
+        trim,
+        time_step: None,
+    })
+}
+
+#[pyfunction]
+/// Compute the linear-quadratic regulator of a state-space model, given the state and
+/// input weight matrices, as lists of rows.
+///
+/// The regulator `u = -K @ x` minimizes the integral (or, for a discrete-time model,
+/// the sum) of `x.T @ Q @ x + u.T @ R @ u`.  The continuous-time algebraic Riccati
+/// equation is solved by the matrix sign function, and the discrete-time one by the
+/// structure-preserving doubling algorithm.
+///
+/// Returns the gain matrix `K` and the solution `P` of the Riccati equation, as lists
+/// of rows.  Raises `ValueError` if the weight matrices have the wrong shape, if the
+/// input weight is not positive definite, or if there is no stabilizing solution.
+pub fn lqr(system: &StateSpace, q: Rows, r: Rows) -> PyResult<(Rows, Rows)> {
+    let (n, m) = (system.b.rows, system.b.cols);
+    let q = matrix("state weight", &q)?.symmetrize();
+    let r = matrix("input weight", &r)?.symmetrize();
+    if (q.rows, q.cols, r.rows, r.cols) != (n, n, m, m) {
+        return Err (PyValueError::new_err(format!(
+            "expected {}x{} state weight and {}x{} input weight, got {}x{} and {}x{}",
+            n, n, m, m, q.rows, q.cols, r.rows, r.cols,
+        )));
+    }
+    let Some (l) = r.cholesky() else {
+        return Err (PyValueError::new_err("input weight must be positive definite"));
+    };
+
+    // inv(R) == inv(L).T @ inv(L), without inverting R itself
+    let (a, b) = (&system.a, &system.b);
+    let linv = lower_inverse(&l);
+    let rinv = &linv.transpose() * &linv;
+    let bt = b.transpose();
+    let g = &(b * &rinv) * &bt;
+    let failed = || PyValueError::new_err("Riccati equation has no stabilizing solution");
+
+    let (gain, p) = if system.time_step.is_some() {
+        let p = doubling(a, &g, &q).ok_or_else(failed)?;
+        let btp = &bt * &p;
+        let gain = &(&r + &(&btp * b)).inverse().ok_or_else(failed)? * &(&btp * a);
+
+        (gain, p)
+    } else {
+        let p = sign_function(a, &g, &q).ok_or_else(failed)?;
+        let gain = &(&rinv * &bt) * &p;
+
+        (gain, p)
+    };
+
+    if !(gain.norm().is_finite() && p.norm().is_finite()) {
+        return Err (failed());
+    }
+
+    Ok ((gain.to_rows(), p.to_rows()))
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Linear state-feedback attitude controller.
+///
+/// The controller commands the torque
+/// ```
+/// torque = trim - K @ [q_err.vector, omega_err]
+/// ```
+/// where `K` is a gain matrix, such as a linear-quadratic regulator of the linearized
+/// attitude dynamics, `q_err` is the attitude error relative to the target, and
+/// `omega_err` is the angular velocity error relative to the target angular velocity.
+pub struct StateFeedback {
+    // Gain matrix.
+    gain: Matrix,
+
+    #[pyo3(get, set)]
+    /// Target attitude (rotation from target frame to reference frame).
+    pub target: Quaternion,
+
+    #[pyo3(get, set)]
+    /// Target angular velocity (target frame).
+    pub target_rate: AngularVelocity,
+
+    #[pyo3(get, set)]
+    /// Trim torque (body frame).
+    pub trim: Torque,
+}
+
+#[pymethods]
+impl StateFeedback {
+    #[new]
+    #[pyo3(signature = (gain, target = None, target_rate = None, trim = None))]
+    /// Construct a new state-feedback controller, given its 3x6 gain matrix as a list
+    /// of rows.  By default, the target is the reference frame at rest, with no trim
+    /// torque.
+    ///
+    /// Raises `ValueError` if the gain matrix is not 3x6 or if the target is zero.
+    pub fn new(
+        gain: Rows,
+        target: Option<Quaternion>,
+        target_rate: Option<AngularVelocity>,
+        trim: Option<Torque>,
+    ) -> PyResult<Self> {
+        let gain = matrix("gain matrix", &gain)?;
+        if (gain.rows, gain.cols) != (3, 6) {
+            return Err (PyValueError::new_err(format!(
+                "expected 3x6 gain matrix, got {}x{}",
+                gain.rows,
+                gain.cols,
+            )));
+        }
+
+        Ok (Self {
+            gain,
+            target: target.unwrap_or(Quaternion::new(1.0, 0.0, 0.0, 0.0)).normalize()?,
+            target_rate: target_rate.unwrap_or(AngularVelocity::new(0.0, 0.0, 0.0)),
+            trim: trim.unwrap_or(Torque::new(0.0, 0.0, 0.0)),
+        })
+    }
+
+    #[getter]
+    /// Gain matrix, as a list of rows.
+    pub fn gain(&self) -> Rows {
+        self.gain.to_rows()
+    }
+
+    /// Compute the commanded torque (body frame), given the attitude and angular
+    /// velocity of the body.
+    pub fn torque(&self, quaternion: Quaternion, angular_velocity: AngularVelocity) -> Torque {
+        let (q, w) = tracking_error(quaternion, angular_velocity, self.target, self.target_rate);
+        let u = &self.gain * &Matrix::column(&[q.x, q.y, q.z, w.x, w.y, w.z]);
+
+        self.trim - Torque::new(u[(0, 0)], u[(1, 0)], u[(2, 0)])
+    }
+}
+
+/// Compute the gravity-gradient torque (body frame) in a circular orbit, given the
+/// inertia tensor, the unit direction of nadir (body frame) and the orbital rate.
+fn gravity_gradient(inertia: Inertia, nadir: Vector, rate: f64) -> Torque {
+    let j = &inertia.matrix() * &nadir.column();
+    let t = nadir.cross(Vector::new(j[(0, 0)], j[(1, 0)], j[(2, 0)])).scale(3.0 * rate * rate);
+
+    Torque::new(t.x, t.y, t.z)
+}
+
+/// Convert a list of rows into a matrix.
+///
+/// Raises `ValueError` if the rows are empty or ragged.
+fn matrix(name: &str, rows: &[Vec<f64>]) -> PyResult<Matrix> {
+    Matrix::from_rows(rows)
+        .filter(|m| m.rows > 0 && m.cols > 0)
+        .ok_or_else(|| PyValueError::new_err(format!(
+            "{} must be a non-empty list of rows of equal length",
+            name,
+        )))
+}
+
+/// Invert a lower-triangular matrix with a positive diagonal, such as a Cholesky
+/// factor, by forward substitution.
+fn lower_inverse(l: &Matrix) -> Matrix {
+    let n = l.rows;
+    let mut inv = Matrix::zeros(n, n);
+
+    for j in 0..n {
+        inv[(j, j)] = l[(j, j)].recip();
+        for i in (j + 1)..n {
+            let s = (j..i).map(|k| l[(i, k)] * inv[(k, j)]).sum::<f64>();
+            inv[(i, j)] = -s / l[(i, i)];
+        }
+    }
+
+    inv
+}
+
+/// Solve the continuous-time algebraic Riccati equation
+/// ```
+/// A.T @ P + P @ A - P @ G @ P + Q == 0
+/// ```
+/// from the matrix sign function of its Hamiltonian matrix, computed by the scaled
+/// Newton iteration.
+///
+/// The Hamiltonian is first balanced by a change of state coordinates `x == T @ y`,
+/// which diagonalizes `G` and then equalizes the diagonals of `G` and `Q`, since a
+/// nearly singular input weight otherwise makes it too badly scaled to invert.
+///
+/// Returns `None` if the iteration fails to converge.
+fn sign_function(a: &Matrix, g: &Matrix, q: &Matrix) -> Option<Matrix> {
+    let n = a.rows;
+    let (values, v) = g.symmetric_eigen();
+    let vt = v.transpose();
+    let qv = &(&vt * q) * &v;
+    let d: Vec<f64> = (0..n).map(|i| {
+        let ratio = values[i] / qv[(i, i)];
+        if ratio.is_finite() && ratio > 0.0 { ratio.powf(0.25) } else { 1.0 }
+    }).collect();
+    let t = &v * &Matrix::diagonal(&d);
+    let tinv = &Matrix::diagonal(&d.iter().map(|x| x.recip()).collect::<Vec<_>>()) * &vt;
+
+    // In the balanced coordinates, A -> inv(T) @ A @ T, G -> inv(T) @ G @ inv(T).T and
+    // Q -> T.T @ Q @ T
+    let a = &(&tinv * a) * &t;
+    let g = (&(&tinv * g) * &tinv.transpose()).symmetrize();
+    let q = (&(&t.transpose() * q) * &t).symmetrize();
+
+    let mut z = Matrix::zeros(2 * n, 2 * n);
+    z.set_block(0, 0, &a);
+    z.set_block(0, n, &g.scale(-1.0));
+    z.set_block(n, 0, &q.scale(-1.0));
+    z.set_block(n, n, &a.transpose().scale(-1.0));
+
+    let mut converged = false;
+    for _ in 0..MAX_ITERATIONS {
+        let zinv = z.inverse()?;
+        let mu = (zinv.norm() / z.norm()).sqrt();
+        let next = (&z.scale(mu) + &zinv.scale(mu.recip())).scale(0.5);
+        let change = (&next - &z).norm();
+        z = next;
+        if change <= TOLERANCE.sqrt() * z.norm() {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+
+    // The stable invariant subspace, the null space of sign(H) + I, is spanned by [I; P]
+    let w = &z + &Matrix::identity(2 * n);
+    let mut lhs = Matrix::zeros(2 * n, n);
+    lhs.set_block(0, 0, &w.block(0, n, n, n));
+    lhs.set_block(n, 0, &w.block(n, n, n, n));
+    let rhs = w.block(0, 0, 2 * n, n).scale(-1.0);
+
+    let lt = lhs.transpose();
+    let p = &(&lt * &lhs).inverse()? * &(&lt * &rhs);
+
+    // Return to the original coordinates
+    Some ((&(&tinv.transpose() * &p) * &tinv).symmetrize())
+}
+
+/// Solve the discrete-time algebraic Riccati equation
+/// ```
+/// P == A.T @ P @ inv(I + G @ P) @ A + Q
+/// ```
+/// by the structure-preserving doubling algorithm.
+///
+/// Returns `None` if the iteration fails to converge.
+fn doubling(a: &Matrix, g: &Matrix, q: &Matrix) -> Option<Matrix> {
+    let identity = Matrix::identity(a.rows);
+    let (mut a, mut g, mut h) = (a.clone(), g.clone(), q.clone());
+
+    for _ in 0..MAX_ITERATIONS {
+        let winv = (&identity + &(&g * &h)).inverse()?;
+        let at = a.transpose();
+
+        let next = &h + &(&(&at * &h) * &(&winv * &a));
+        g = &g + &(&(&a * &winv) * &(&g * &at));
+        a = &(&a * &winv) * &a;
+
+        let change = (&next - &h).norm();
+        h = next;
+        if change <= TOLERANCE * h.norm() {
+            return Some (h.symmetrize());
+        }
+    }
+
+    None
+}
@@ -0,0 +1,180 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Attitude control laws.
+//!
+//! Each controller in this module computes the commanded torque (body frame) from the
+//! attitude and angular velocity of the body, consistent with [`crate::State`].  The
+//! torque can be written directly to `State.torque` before each integration step.
+//!
+//! Closed-loop controllers driven by a [`crate::Simulation`] implement [`Controller`]
+//! instead, consuming sensor measurements and producing actuator commands.  The
+//! sliding-mode and adaptive controllers provide both interfaces.
+
+mod adaptive;
+mod bdot;
+mod controller;
+mod linear;
+mod pd;
+mod sliding;
+mod unloading;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Torque,
+    Vector,
+};
+
+pub use adaptive::AdaptiveControl;
+pub use bdot::{
+    BCross,
+    BDot,
+};
+pub use controller::ControllerBase;
+pub use linear::{
+    StateFeedback,
+    StateSpace,
+    linearize,
+    lqr,
+};
+pub use pd::QuaternionPd;
+pub use sliding::SlidingMode;
+pub use unloading::MomentumUnloading;
+
+/// Gains of each body axis.
+pub(crate) type Gains = (f64, f64, f64);
+
+/// Closed-loop controller.
+pub trait Controller {
+    /// Compute the actuator commands at a given time, given the latest measurement of
+    /// each sensor.  Both are keyed by name.
+    fn update<'py>(
+        &mut self,
+        time: f64,
+        measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>>;
+}
+
+/// Read the attitude and angular velocity (body frame) of the body from the latest
+/// measurement of each sensor, given the names of the sensors measuring them, or
+/// `None` if either has not been measured yet.
+pub(crate) fn measured_motion(
+    measurements: &Bound<'_, PyDict>,
+    attitude_sensor: &str,
+    rate_sensor: &str,
+) -> PyResult<Option<(Quaternion, AngularVelocity)>> {
+    let (Some (q), Some (w)) = (
+        measurements.get_item(attitude_sensor)?,
+        measurements.get_item(rate_sensor)?,
+    ) else {
+        return Ok (None);
+    };
+
+    Ok (Some ((q.extract()?, w.extract()?)))
+}
+
+/// Compute the attitude error quaternion of a body relative to a target attitude, and
+/// the angular velocity error (body frame) relative to a target angular velocity
+/// (target frame).
+///
+/// The error quaternion rotates from the body frame to the target frame, and is chosen
+/// with non-negative scalar part, so that it describes the shorter rotation.
+pub(crate) fn tracking_error(
+    quaternion: Quaternion,
+    angular_velocity: AngularVelocity,
+    target: Quaternion,
+    target_rate: AngularVelocity,
+) -> (Quaternion, AngularVelocity) {
+    let q = target.inv() * quaternion;
+    let q = if q.w < 0.0 { -q } else { q };
+
+    (q, angular_velocity - target_rate.rotate(q.inv()))
+}
+
+/// Compute the sliding variable `s = omega_err + slope * q_err.vector` of a body
+/// tracking a target, together with the reference angular velocity `omega - s` and its
+/// time derivative along the error dynamics (all body frame).
+pub(crate) fn sliding_variable(
+    quaternion: Quaternion,
+    angular_velocity: AngularVelocity,
+    target: Quaternion,
+    target_rate: AngularVelocity,
+    target_acceleration: AngularVelocity,
+    slope: f64,
+) -> (Vector, Vector, Vector) {
+    let (q, dw) = tracking_error(quaternion, angular_velocity, target, target_rate);
+    let vector = |w: AngularVelocity| Vector::new(w.x, w.y, w.z);
+
+    // Target angular velocity and acceleration (body frame)
+    let wt = vector(target_rate.rotate(q.inv()));
+    let at = vector(target_acceleration.rotate(q.inv()));
+
+    let qv = Vector::new(q.x, q.y, q.z);
+    let dw = vector(dw);
+    let qv_dot = (dw.scale(q.w) + qv.cross(dw)).scale(0.5);
+
+    let s = dw + qv.scale(slope);
+    let wr = vector(angular_velocity) - s;
+    let wr_dot = at - dw.cross(wt) - qv_dot.scale(slope);
+
+    (s, wr, wr_dot)
+}
+
+/// Compute the gyroscopic torque `omega x (J @ omega)` of a rotating body.
+pub(crate) fn gyroscopic(inertia: Inertia, angular_velocity: AngularVelocity) -> Torque {
+    let w = angular_velocity;
+    let h = product(inertia, w);
+
+    Torque::new(
+        w.y*h.z - w.z*h.y,
+        w.z*h.x - w.x*h.z,
+        w.x*h.y - w.y*h.x,
+    )
+}
+
+/// Compute the torque `J @ alpha + omega x (J @ omega)` that produces a given angular
+/// acceleration of a rotating body.
+pub(crate) fn feedforward(
+    inertia: Inertia,
+    angular_velocity: AngularVelocity,
+    angular_acceleration: AngularVelocity,
+) -> Torque {
+    let h = product(inertia, angular_acceleration);
+
+    Torque::new(h.x, h.y, h.z) + gyroscopic(inertia, angular_velocity)
+}
+
+/// Compute the product of an inertia tensor and a vector.
+fn product(inertia: Inertia, w: AngularVelocity) -> AngularMomentum {
+    AngularMomentum::new(
+        inertia.j1*w.x + inertia.j6*w.y + inertia.j5*w.z,
+        inertia.j6*w.x + inertia.j2*w.y + inertia.j4*w.z,
+        inertia.j5*w.x + inertia.j4*w.y + inertia.j3*w.z,
+    )
+}
+
+/// Compute the torque `dipole x field` of a magnetic dipole (A m^2) in a magnetic
+/// field (T).
+pub(crate) fn magnetic_torque(dipole: Vector, field: Vector) -> Torque {
+    let t = dipole.cross(field);
+
+    Torque::new(t.x, t.y, t.z)
+}
+
+/// Scale a vector down, preserving its direction, so that no component exceeds a limit,
+/// such as the saturation limit of each magnetorquer.
+pub(crate) fn saturate(v: Vector, limit: f64) -> Vector {
+    let largest = v.x.abs().max(v.y.abs()).max(v.z.abs());
+
+    if largest > limit {
+        v.scale(limit / largest)
+    } else {
+        v
+    }
+}
@@ -0,0 +1,194 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Quaternion feedback PD controller.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+use crate::{
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Torque,
+};
+use crate::guidance::Reference;
+use crate::inertia::positive;
+
+use super::{
+    Gains,
+    feedforward,
+    tracking_error,
+};
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Quaternion feedback proportional-derivative controller.
+///
+/// The controller commands the torque
+/// ```
+/// torque = -kp * q_err.vector - kd * omega_err + J @ alpha + omega x (J @ omega)
+/// ```
+/// where the gains act on each body axis independently, `q_err` is the attitude error
+/// relative to the target, `omega_err` is the angular velocity error relative to the
+/// target angular velocity, and `alpha` is the target angular acceleration.  The
+/// feedforward terms can be disabled.
+pub struct QuaternionPd {
+    #[pyo3(get, set)]
+    /// Inertia tensor of the body.
+    pub inertia: Inertia,
+
+    #[pyo3(get, set)]
+    /// Proportional gain of each axis.
+    pub kp: Gains,
+
+    #[pyo3(get, set)]
+    /// Derivative gain of each axis.
+    pub kd: Gains,
+
+    #[pyo3(get, set)]
+    /// Target attitude (rotation from target frame to reference frame).
+    pub target: Quaternion,
+
+    #[pyo3(get, set)]
+    /// Target angular velocity (target frame).
+    pub target_rate: AngularVelocity,
+
+    #[pyo3(get, set)]
+    /// Target angular acceleration (target frame).
+    pub target_acceleration: AngularVelocity,
+
+    #[pyo3(get, set)]
+    /// Whether to apply the feedforward torque.
+    pub feedforward: bool,
+}
+
+#[pymethods]
+impl QuaternionPd {
+    #[new]
+    #[pyo3(signature = (inertia, kp, kd, target = None, target_rate = None))]
+    /// Construct a new PD controller, given the inertia tensor of the body and the
+    /// gains of each axis.  By default, the target is the reference frame at rest.
+    ///
+    /// Raises `ValueError` if any gain is not positive.
+    pub fn new(
+        inertia: Inertia,
+        kp: Gains,
+        kd: Gains,
+        target: Option<Quaternion>,
+        target_rate: Option<AngularVelocity>,
+    ) -> PyResult<Self> {
+        for gain in [kp.0, kp.1, kp.2, kd.0, kd.1, kd.2] {
+            positive("controller gain", gain)?;
+        }
+
+        Ok (Self {
+            inertia,
+            kp,
+            kd,
+            target: target.unwrap_or(Quaternion::new(1.0, 0.0, 0.0, 0.0)).normalize()?,
+            target_rate: target_rate.unwrap_or(AngularVelocity::new(0.0, 0.0, 0.0)),
+            target_acceleration: AngularVelocity::new(0.0, 0.0, 0.0),
+            feedforward: true,
+        })
+    }
+
+    #[classmethod]
+    #[pyo3(signature = (inertia, natural_frequency, damping_ratio, target = None, target_rate = None))]
+    /// Construct a new PD controller whose gains place the closed-loop poles of each
+    /// axis at the given natural frequency (rad/s) and damping ratio.
+    ///
+    /// For small errors, each axis behaves as a second-order system with
+    /// ```
+    /// kp = 2 * J * natural_frequency^2
+    /// kd = 2 * damping_ratio * natural_frequency * J
+    /// ```
+    /// where `J` is the moment of inertia about that axis.
+    ///
+    /// Raises `ValueError` if any natural frequency, damping ratio or moment of inertia
+    /// is not positive.
+    pub fn from_natural_frequency(
+        _cls: &Bound<'_, PyType>,
+        inertia: Inertia,
+        natural_frequency: (f64, f64, f64),
+        damping_ratio: (f64, f64, f64),
+        target: Option<Quaternion>,
+        target_rate: Option<AngularVelocity>,
+    ) -> PyResult<Self> {
+        let (kp, kd) = Self::gains(inertia, natural_frequency, damping_ratio)?;
+
+        Self::new(inertia, kp, kd, target, target_rate)
+    }
+
+    #[staticmethod]
+    /// Compute the proportional and derivative gains of each axis that place its
+    /// closed-loop poles at the given natural frequency (rad/s) and damping ratio.
+    ///
+    /// Raises `ValueError` if any natural frequency, damping ratio or moment of inertia
+    /// is not positive.
+    pub fn gains(
+        inertia: Inertia,
+        natural_frequency: (f64, f64, f64),
+        damping_ratio: (f64, f64, f64),
+    ) -> PyResult<(Gains, Gains)> {
+        let j = [inertia.j1, inertia.j2, inertia.j3];
+        let wn = [natural_frequency.0, natural_frequency.1, natural_frequency.2];
+        let zeta = [damping_ratio.0, damping_ratio.1, damping_ratio.2];
+
+        for k in 0..3 {
+            positive("natural frequency", wn[k])?;
+            positive("damping ratio", zeta[k])?;
+            if j[k].is_nan() || j[k] <= 0.0 {
+                return Err (PyValueError::new_err(format!(
+                    "moment of inertia must be positive, got {}",
+                    j[k],
+                )));
+            }
+        }
+
+        let kp: [f64; 3] = std::array::from_fn(|k| 2.0 * j[k] * wn[k].powi(2));
+        let kd: [f64; 3] = std::array::from_fn(|k| 2.0 * zeta[k] * wn[k] * j[k]);
+
+        Ok (((kp[0], kp[1], kp[2]), (kd[0], kd[1], kd[2])))
+    }
+
+    /// Track a reference, setting the target attitude, angular velocity and angular
+    /// acceleration.
+    pub fn track(&mut self, reference: Reference) {
+        self.target = reference.quaternion;
+        self.target_rate = reference.angular_velocity;
+        self.target_acceleration = reference.angular_acceleration;
+    }
+
+    /// Compute the commanded torque (body frame), given the attitude and angular
+    /// velocity of the body.
+    pub fn torque(&self, quaternion: Quaternion, angular_velocity: AngularVelocity) -> Torque {
+        let (q, w) = tracking_error(quaternion, angular_velocity, self.target, self.target_rate);
+
+        let feedback = -Torque::new(
+            self.kp.0 * q.x + self.kd.0 * w.x,
+            self.kp.1 * q.y + self.kd.1 * w.y,
+            self.kp.2 * q.z + self.kd.2 * w.z,
+        );
+
+        if self.feedforward {
+            let alpha = self.target_acceleration.rotate(q.inv());
+            feedback + feedforward(self.inertia, angular_velocity, alpha)
+        } else {
+            feedback
+        }
+    }
+
+    /// Return a Pythonic representation of this controller.
+    fn __repr__(&self) -> String {
+        format!(
+            "QuaternionPd({}, {:?}, {:?}, {}, {})",
+            self.inertia.__repr__(),
+            self.kp,
+            self.kd,
+            self.target.__repr__(),
+            self.target_rate.__repr__(),
+        )
+    }
+}
@@ -0,0 +1,224 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Sliding-mode attitude controller.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Torque,
+    Vector,
+};
+use crate::guidance::Reference;
+use crate::inertia::positive;
+
+use super::{
+    Controller,
+    Gains,
+    measured_motion,
+    sliding_variable,
+};
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Sliding-mode attitude controller.
+///
+/// The controller drives the body onto the sliding surface
+/// ```
+/// s = omega_err + slope * q_err.vector == 0
+/// ```
+/// on which the attitude error decays exponentially, by commanding the torque
+/// ```
+/// torque = J @ omega_r_dot - (J @ omega) x omega_r - gain * sat(s / boundary_layer)
+/// ```
+/// where `J` is the nominal inertia tensor, `omega_r = omega - s` is the reference
+/// angular velocity, and `sat` clips each component to `[-1, 1]`.  The switching term
+/// rejects inertia errors and disturbances whose torque on each axis is less than the
+/// gain of that axis; within the boundary layer, it is linear, which prevents chattering
+/// at the cost of a small residual error.
+///
+/// As a [`Controller`] of a [`crate::Simulation`], the controller reads the attitude
+/// and angular velocity from the measurements of the named sensors, and commands the
+/// torque to the named actuator.
+pub struct SlidingMode {
+    #[pyo3(get, set)]
+    /// Nominal inertia tensor of the body.
+    pub inertia: Inertia,
+
+    #[pyo3(get, set)]
+    /// Slope of the sliding surface (rad/s).
+    pub slope: f64,
+
+    #[pyo3(get, set)]
+    /// Switching gain of each axis.
+    pub gain: Gains,
+
+    #[pyo3(get, set)]
+    /// Thickness of the boundary layer of each axis.
+    pub boundary_layer: f64,
+
+    #[pyo3(get, set)]
+    /// Target attitude (rotation from target frame to reference frame).
+    pub target: Quaternion,
+
+    #[pyo3(get, set)]
+    /// Target angular velocity (target frame).
+    pub target_rate: AngularVelocity,
+
+    #[pyo3(get, set)]
+    /// Target angular acceleration (target frame).
+    pub target_acceleration: AngularVelocity,
+
+    #[pyo3(get, set)]
+    /// Name of the sensor measuring the attitude, as a `Quaternion`.
+    pub attitude_sensor: String,
+
+    #[pyo3(get, set)]
+    /// Name of the sensor measuring the angular velocity (body frame).
+    pub rate_sensor: String,
+
+    #[pyo3(get, set)]
+    /// Name of the actuator commanded with the torque.
+    pub actuator: String,
+}
+
+#[pymethods]
+impl SlidingMode {
+    #[new]
+    #[pyo3(signature = (inertia, slope, gain, boundary_layer, target = None, target_rate = None))]
+    /// Construct a new sliding-mode controller, given the nominal inertia tensor of the
+    /// body, the slope of the sliding surface, the switching gain of each axis and the
+    /// thickness of the boundary layer.  By default, the target is the reference frame
+    /// at rest.
+    ///
+    /// Raises `ValueError` if the slope, any gain or the boundary layer is not positive.
+    pub fn new(
+        inertia: Inertia,
+        slope: f64,
+        gain: Gains,
+        boundary_layer: f64,
+        target: Option<Quaternion>,
+        target_rate: Option<AngularVelocity>,
+    ) -> PyResult<Self> {
+        positive("sliding surface slope", slope)?;
+        for g in [gain.0, gain.1, gain.2] {
+            positive("controller gain", g)?;
+        }
+        positive("boundary layer", boundary_layer)?;
+
+        Ok (Self {
+            inertia,
+            slope,
+            gain,
+            boundary_layer,
+            target: target.unwrap_or(Quaternion::new(1.0, 0.0, 0.0, 0.0)).normalize()?,
+            target_rate: target_rate.unwrap_or(AngularVelocity::new(0.0, 0.0, 0.0)),
+            target_acceleration: AngularVelocity::new(0.0, 0.0, 0.0),
+            attitude_sensor: "attitude".to_string(),
+            rate_sensor: "rate".to_string(),
+            actuator: "torque".to_string(),
+        })
+    }
+
+    /// Track a reference, setting the target attitude, angular velocity and angular
+    /// acceleration.
+    pub fn track(&mut self, reference: Reference) {
+        self.target = reference.quaternion;
+        self.target_rate = reference.angular_velocity;
+        self.target_acceleration = reference.angular_acceleration;
+    }
+
+    /// Compute the sliding variable (body frame), given the attitude and angular
+    /// velocity of the body.
+    pub fn surface(&self, quaternion: Quaternion, angular_velocity: AngularVelocity) -> Vector {
+        let (s, _, _) = self.sliding_variable(quaternion, angular_velocity);
+
+        s
+    }
+
+    /// Compute the commanded torque (body frame), given the attitude and angular
+    /// velocity of the body.
+    pub fn torque(&self, py: Python<'_>, quaternion: Quaternion, angular_velocity: AngularVelocity) -> Torque {
+        let (s, wr, wr_dot) = self.sliding_variable(quaternion, angular_velocity);
+        let j = |v: Vector| {
+            let h = AngularMomentum::product(
+                &py.get_type::<AngularMomentum>(),
+                self.inertia,
+                AngularVelocity::new(v.x, v.y, v.z),
+            );
+            Vector::new(h.x, h.y, h.z)
+        };
+        let w = Vector::new(angular_velocity.x, angular_velocity.y, angular_velocity.z);
+
+        let sat = |v: f64| (v / self.boundary_layer).clamp(-1.0, 1.0);
+        let switching = Vector::new(
+            self.gain.0 * sat(s.x),
+            self.gain.1 * sat(s.y),
+            self.gain.2 * sat(s.z),
+        );
+
+        let t = j(wr_dot) - j(w).cross(wr) - switching;
+        Torque::new(t.x, t.y, t.z)
+    }
+
+    /// Compute the actuator commands at a given time, given the latest measurement of
+    /// each sensor, keyed by sensor name.
+    ///
+    /// Returns `{actuator: torque}`, or no commands until both the attitude and the
+    /// angular velocity have been measured.
+    pub fn update<'py>(
+        &mut self,
+        _time: f64,
+        measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let py = measurements.py();
+        let commands = PyDict::new(py);
+        if let Some ((q, w)) = measured_motion(measurements, &self.attitude_sensor, &self.rate_sensor)? {
+            commands.set_item(&self.actuator, self.torque(py, q, w))?;
+        }
+
+        Ok (commands)
+    }
+
+    /// Return a Pythonic representation of this controller.
+    fn __repr__(&self) -> String {
+        format!(
+            "SlidingMode({}, {}, {:?}, {}, {}, {})",
+            self.inertia.__repr__(),
+            self.slope,
+            self.gain,
+            self.boundary_layer,
+            self.target.__repr__(),
+            self.target_rate.__repr__(),
+        )
+    }
+}
+
+impl Controller for SlidingMode {
+    fn update<'py>(
+        &mut self,
+        time: f64,
+        measurements: &Bound<'py, PyDict>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        self.update(time, measurements)
+    }
+}
+
+impl SlidingMode {
+    /// Compute the sliding variable, reference angular velocity and its derivative.
+    fn sliding_variable(&self, quaternion: Quaternion, angular_velocity: AngularVelocity) -> (Vector, Vector, Vector) {
+        sliding_variable(
+            quaternion,
+            angular_velocity,
+            self.target,
+            self.target_rate,
+            self.target_acceleration,
+            self.slope,
+        )
+    }
+}
@@ -0,0 +1,135 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Momentum unloading controller.
+
+use pyo3::prelude::*;
+
+use crate::{
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    Torque,
+    Vector,
+};
+use crate::inertia::positive;
+
+use super::{
+    magnetic_torque,
+    saturate,
+};
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Momentum unloading (desaturation) controller.
+///
+/// Reaction wheels absorb external disturbance torques, so the angular momentum of the
+/// system (bus and wheels) grows over time.  The unloading controller requests an
+/// external torque that removes the excess momentum, relative to a target, at the rate
+/// set by its gain.
+/// ```
+/// torque = -gain * excess
+/// ```
+/// With magnetorquers, the cross-product law achieves the component of this torque
+/// perpendicular to the magnetic field.
+/// ```
+/// dipole = gain * (excess x B) / |B|^2
+/// ```
+/// With thrusters, the requested torque is passed to the thruster firing logic.
+///
+/// The external torque acts on the body, so the attitude controller must keep pointing
+/// by commanding the wheels to absorb it; the momentum is then removed from the wheels.
+pub struct MomentumUnloading {
+    #[pyo3(get, set)]
+    /// Unloading gain (1/s).
+    pub gain: f64,
+
+    #[pyo3(get, set)]
+    /// Target system angular momentum (body frame).
+    pub target: AngularMomentum,
+
+    #[pyo3(get, set)]
+    /// Saturation limit of each magnetorquer (A m^2).
+    pub max_dipole: f64,
+
+    #[pyo3(get, set)]
+    /// Torque limit of each body axis for thruster unloading.
+    pub max_torque: f64,
+}
+
+#[pymethods]
+impl MomentumUnloading {
+    #[new]
+    #[pyo3(signature = (gain, target = None))]
+    /// Construct a new unloading controller, given its gain and the target system
+    /// angular momentum (by default, zero).  The dipole and torque are unlimited by
+    /// default.
+    ///
+    /// Raises `ValueError` if the gain is not positive.
+    pub fn new(gain: f64, target: Option<AngularMomentum>) -> PyResult<Self> {
+        positive("controller gain", gain)?;
+
+        Ok (Self {
+            gain,
+            target: target.unwrap_or(AngularMomentum::new(0.0, 0.0, 0.0)),
+            max_dipole: f64::INFINITY,
+            max_torque: f64::INFINITY,
+        })
+    }
+
+    /// Compute the excess system angular momentum (body frame), given the inertia
+    /// tensor and angular velocity of the bus, and the total momentum of the wheels
+    /// (body frame).
+    pub fn excess(
+        &self,
+        py: Python<'_>,
+        inertia: Inertia,
+        angular_velocity: AngularVelocity,
+        wheel_momentum: AngularMomentum,
+    ) -> AngularMomentum {
+        let bus = AngularMomentum::product(&py.get_type::<AngularMomentum>(), inertia, angular_velocity);
+
+        bus + wheel_momentum - self.target
+    }
+
+    /// Compute the magnetorquer dipole (body frame) that unloads a given excess
+    /// momentum, given the magnetic field (both body frame).
+    ///
+    /// The dipole is scaled down, preserving its direction, so that no component
+    /// exceeds the saturation limit.
+    pub fn dipole(&self, excess: AngularMomentum, field: Vector) -> Vector {
+        let b2 = field.dot(field);
+        if b2 == 0.0 {
+            return Vector::new(0.0, 0.0, 0.0);
+        }
+
+        let h = Vector::new(excess.x, excess.y, excess.z);
+        saturate(h.cross(field).scale(self.gain / b2), self.max_dipole)
+    }
+
+    /// Compute the magnetic torque (body frame) that unloads a given excess momentum,
+    /// given the magnetic field (both body frame).
+    pub fn magnetic_torque(&self, excess: AngularMomentum, field: Vector) -> Torque {
+        magnetic_torque(self.dipole(excess, field), field)
+    }
+
+    /// Compute the torque (body frame) to request from the thrusters to unload a given
+    /// excess momentum (body frame).
+    ///
+    /// The torque is scaled down, preserving its direction, so that no component
+    /// exceeds the torque limit.
+    pub fn thruster_torque(&self, excess: AngularMomentum) -> Torque {
+        let t = saturate(Vector::new(excess.x, excess.y, excess.z).scale(-self.gain), self.max_torque);
+
+        Torque::new(t.x, t.y, t.z)
+    }
+
+    /// Return a Pythonic representation of this controller.
+    fn __repr__(&self) -> String {
+        format!(
+            "MomentumUnloading({}, {})",
+            self.gain,
+            self.target.__repr__(),
+        )
+    }
+}
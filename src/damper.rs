@@ -3,6 +3,7 @@
 //! 
 //! Rigid-body damper.
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::{
@@ -23,7 +24,7 @@ use crate::{
 /// is proportional to the vector difference of the sphere's angular velocity
 /// and the rigid body's angular velocity.
 pub struct KaneDamper {
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     /// Damper inertia.
     pub inertia: Inertia,
 
@@ -45,19 +46,50 @@ impl KaneDamper {
     /// This is because the Kane damper model of energy dissipation conceptually
     /// models the damper as a solid sphere.  Because the inertia of a sphere is equal
     /// about any axis, a positive scalar is sufficient.
-    pub fn new(inertia: f64, coefficient: f64) -> Self {
-        let inertia = Inertia::new(
+    ///
+    /// Raises `ValueError` if the inertia is not positive or the damping coefficient is negative.
+    pub fn new(inertia: f64, coefficient: f64) -> PyResult<Self> {
+        let inertia = Inertia::try_new(
             inertia,
             inertia,
             inertia,
             0.0,
             0.0,
             0.0,
-        );
-        Self {
+        )?;
+        let damper = Self {
             inertia,
             coefficient,
             angular_velocity: AngularVelocity::new(0.0, 0.0, 0.0),
+        };
+        damper.validate()?;
+
+        Ok (damper)
+    }
+
+    #[setter]
+    /// Set the damper inertia, checking that it is physically realizable.
+    fn set_inertia(&mut self, inertia: Inertia) -> PyResult<()> {
+        inertia.validate()?;
+        self.inertia = inertia;
+
+        Ok (())
+    }
+
+    /// Check that this damper is physically realizable.
+    ///
+    /// Raises `ValueError` if the damper inertia is not physical or the damping coefficient
+    /// is negative or not finite.
+    pub fn validate(&self) -> PyResult<()> {
+        self.inertia.validate()?;
+
+        if !(self.coefficient.is_finite() && self.coefficient >= 0.0) {
+            return Err (PyValueError::new_err(format!(
+                "damping coefficient must be finite and non-negative, got {}",
+                self.coefficient,
+            )));
         }
+
+        Ok (())
     }
 }
@@ -0,0 +1,65 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Second EStimator of the Optimal Quaternion (ESOQ2).
+
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    Vector,
+    VectorMeasurement,
+};
+use crate::linalg::Matrix;
+
+use super::{
+    covariance,
+    unrotate,
+    AttitudeEstimate,
+    Profile,
+};
+
+#[pyfunction]
+/// Determine attitude from two or more vector measurements using Mortari's ESOQ2
+/// algorithm.
+///
+/// ESOQ2 shares the eigenvalue computation of QUEST, but finds the rotation axis as
+/// the null vector of a symmetric 3x3 matrix and the rotation angle from the axis,
+/// which avoids inverting a matrix.  The rotation axis is ill-defined for rotation
+/// angles near zero and 180 degrees, so the reference frame is first rotated by 90
+/// degrees about whichever axis brings the rotation angle closest to 90 degrees.
+///
+/// Raises `ValueError` if the measurements do not determine the attitude.
+pub fn esoq2(measurements: Vec<VectorMeasurement>) -> PyResult<AttitudeEstimate> {
+    let covariance = covariance(&measurements)?;
+
+    let profile = Profile::new(&measurements);
+    let lambda = profile.lambda_max();
+    let c = std::f64::consts::FRAC_1_SQRT_2;
+    let rotation = profile.select_rotation(lambda, &[
+        Quaternion::new(1.0, 0.0, 0.0, 0.0),
+        Quaternion::new(c, c, 0.0, 0.0),
+        Quaternion::new(c, 0.0, c, 0.0),
+        Quaternion::new(c, 0.0, 0.0, c),
+    ], 0.5);
+    let p = profile.rotate(rotation);
+
+    // Rotation axis lies in the null space of M
+    let t = lambda - p.sigma;
+    let m = &(&Matrix::identity(3).scale(lambda + p.sigma) - &p.s).scale(t) - &(&p.z * &p.z.transpose());
+    let rows: Vec<Vector> = (0..3).map(|i| Vector::new(m[(i, 0)], m[(i, 1)], m[(i, 2)])).collect();
+    let e = [
+        rows[0].cross(rows[1]),
+        rows[1].cross(rows[2]),
+        rows[2].cross(rows[0]),
+    ].into_iter().max_by(|a, b| a.norm().total_cmp(&b.norm())).unwrap().normalize()?;
+
+    // Rotation angle follows from the scalar row of the eigenvalue problem
+    let ze = e.dot(Vector::from_column(&p.z));
+    let q = Quaternion::new(ze, t * e.x, t * e.y, t * e.z).normalize()?;
+
+    Ok (AttitudeEstimate {
+        quaternion: unrotate(q, rotation),
+        covariance: covariance.to_rows(),
+    })
+}
@@ -0,0 +1,230 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Static attitude determination.
+//!
+//! Each algorithm in this module determines the attitude of the body from a set of
+//! simultaneous vector measurements, by solving (exactly or approximately) Wahba's
+//! problem.  The estimated quaternion rotates from the body frame to the reference
+//! frame, consistent with [`crate::State`].
+
+pub(crate) mod esoq2;
+pub(crate) mod q_method;
+pub(crate) mod quest;
+pub(crate) mod triad;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    VectorMeasurement,
+};
+use crate::linalg::Matrix;
+
+/// Maximum number of Newton-Raphson iterations for the largest eigenvalue.
+const NEWTON_ITERATIONS: usize = 50;
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Attitude estimate.
+pub struct AttitudeEstimate {
+    #[pyo3(get)]
+    /// Estimated attitude (rotation from body frame to reference frame).
+    pub quaternion: Quaternion,
+
+    #[pyo3(get)]
+    /// Covariance of the attitude error angles (body frame), in square radians.
+    pub covariance: Vec<Vec<f64>>,
+}
+
+#[pymethods]
+impl AttitudeEstimate {
+    /// Return a Pythonic representation of this attitude estimate.
+    fn __repr__(&self) -> String {
+        format!(
+            "AttitudeEstimate({}, {:?})",
+            self.quaternion.__repr__(),
+            self.covariance,
+        )
+    }
+}
+
+/// Davenport's decomposition of the attitude profile matrix.
+struct Profile {
+    /// Attitude profile matrix, `B = sum(a * b @ r.T)`, with normalized weights.
+    b: Matrix,
+
+    /// Trace of the attitude profile matrix.
+    sigma: f64,
+
+    /// Symmetric part, `S = B + B.T`.
+    s: Matrix,
+
+    /// Antisymmetric part, as a vector.
+    z: Matrix,
+}
+
+impl Profile {
+    /// Construct the attitude profile of a set of measurements.
+    fn new(measurements: &[VectorMeasurement]) -> Self {
+        let total: f64 = measurements.iter().map(|m| m.weight()).sum();
+
+        let mut b = Matrix::zeros(3, 3);
+        for m in measurements {
+            let outer = &m.body.column() * &m.reference.column().transpose();
+            b = &b + &outer.scale(m.weight() / total);
+        }
+
+        Self::from_matrix(b)
+    }
+
+    /// Construct the attitude profile corresponding to a given profile matrix.
+    fn from_matrix(b: Matrix) -> Self {
+        let sigma = b.trace();
+        let s = &b + &b.transpose();
+        let z = Matrix::column(&[
+            b[(1, 2)] - b[(2, 1)],
+            b[(2, 0)] - b[(0, 2)],
+            b[(0, 1)] - b[(1, 0)],
+        ]);
+
+        Self {
+            b,
+            sigma,
+            s,
+            z,
+        }
+    }
+
+    /// Return Davenport's K matrix, whose dominant eigenvector is the optimal quaternion.
+    fn k(&self) -> Matrix {
+        let mut k = Matrix::zeros(4, 4);
+        k[(0, 0)] = self.sigma;
+        for i in 0..3 {
+            k[(0, i + 1)] = self.z[(i, 0)];
+            k[(i + 1, 0)] = self.z[(i, 0)];
+            for j in 0..3 {
+                k[(i + 1, j + 1)] = self.s[(i, j)];
+            }
+            k[(i + 1, i + 1)] -= self.sigma;
+        }
+        k
+    }
+
+    /// Compute the coefficients of the characteristic polynomial of Davenport's K matrix,
+    /// `f(x) = x^4 - (a + b) x^2 - c x + (a b + c sigma - d)`, as `(a, b, c, d)`.
+    fn characteristic(&self) -> (f64, f64, f64, f64) {
+        let (s, z) = (&self.s, &self.z);
+        let kappa = adjugate(s).trace();
+        let delta = determinant(s);
+        let zz = (&z.transpose() * z)[(0, 0)];
+        let zsz = (&(&z.transpose() * s) * z)[(0, 0)];
+        let zssz = (&(&(&z.transpose() * s) * s) * z)[(0, 0)];
+
+        (
+            self.sigma.powi(2) - kappa,
+            self.sigma.powi(2) + zz,
+            delta + zsz,
+            zssz,
+        )
+    }
+
+    /// Evaluate the derivative of the characteristic polynomial of Davenport's K matrix.
+    fn characteristic_slope(&self, lambda: f64) -> f64 {
+        let (a, b, c, _) = self.characteristic();
+
+        4.0 * lambda.powi(3) - 2.0 * (a + b) * lambda - c
+    }
+
+    /// Compute the largest eigenvalue of Davenport's K matrix by Newton-Raphson
+    /// iteration on its characteristic equation, starting from the sum of the weights.
+    fn lambda_max(&self) -> f64 {
+        let (a, b, c, d) = self.characteristic();
+
+        let mut lambda: f64 = 1.0;
+        for _ in 0..NEWTON_ITERATIONS {
+            let f = lambda.powi(4) - (a + b) * lambda.powi(2) - c * lambda + (a * b + c * self.sigma - d);
+            let df = 4.0 * lambda.powi(3) - 2.0 * (a + b) * lambda - c;
+            let step = f / df;
+            lambda -= step;
+            if step.abs() < 1e-15 {
+                break;
+            }
+        }
+        lambda
+    }
+
+    /// Express this profile in a rotated reference frame.
+    ///
+    /// If this profile is solved by `q`, the rotated profile is solved by `rotation * q`.
+    fn rotate(&self, rotation: Quaternion) -> Self {
+        Self::from_matrix(&self.b * &rotation.dcm().transpose())
+    }
+
+    /// Select, among candidate rotations of the reference frame, the one for which the
+    /// squared scalar part of the optimal quaternion is closest to a target.
+    ///
+    /// The squared scalar part is obtained without solving for the quaternion, as the
+    /// ratio of the leading cofactor of `lambda I - K` to the derivative of the
+    /// characteristic polynomial.  This generalizes Shuster's method of sequential
+    /// rotations, which avoids the singularities of the optimal quaternion algorithms.
+    fn select_rotation(&self, lambda: f64, candidates: &[Quaternion], target: f64) -> Quaternion {
+        let slope = self.characteristic_slope(lambda);
+        let error = |rotation: &Quaternion| {
+            let p = self.rotate(*rotation);
+            let m = &Matrix::identity(3).scale(lambda + p.sigma) - &p.s;
+            (determinant(&m) / slope - target).abs()
+        };
+
+        *candidates.iter().min_by(|a, b| error(a).total_cmp(&error(b))).unwrap()
+    }
+}
+
+/// Undo a rotation of the reference frame (see [`Profile::rotate`]).
+fn unrotate(q: Quaternion, rotation: Quaternion) -> Quaternion {
+    canonical(rotation.inv() * q)
+}
+
+/// Return the quaternion with non-negative scalar part representing the same rotation.
+fn canonical(q: Quaternion) -> Quaternion {
+    if q.w < 0.0 { -q } else { q }
+}
+
+/// Compute the determinant of a 3x3 matrix.
+fn determinant(m: &Matrix) -> f64 {
+    m[(0, 0)] * (m[(1, 1)] * m[(2, 2)] - m[(1, 2)] * m[(2, 1)])
+        - m[(0, 1)] * (m[(1, 0)] * m[(2, 2)] - m[(1, 2)] * m[(2, 0)])
+        + m[(0, 2)] * (m[(1, 0)] * m[(2, 1)] - m[(1, 1)] * m[(2, 0)])
+}
+
+/// Compute the adjugate of a 3x3 matrix.
+fn adjugate(m: &Matrix) -> Matrix {
+    let mut adj = Matrix::zeros(3, 3);
+    for i in 0..3 {
+        for j in 0..3 {
+            let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+            let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+            adj[(i, j)] = m[(r0, c0)] * m[(r1, c1)] - m[(r0, c1)] * m[(r1, c0)];
+        }
+    }
+    adj
+}
+
+/// Compute the covariance of the attitude error angles (body frame) for the
+/// optimal solution of Wahba's problem with the given measurements.
+///
+/// Raises `ValueError` if the measurements do not determine the attitude, that is,
+/// if fewer than two non-parallel vectors were measured.
+fn covariance(measurements: &[VectorMeasurement]) -> PyResult<Matrix> {
+    let mut information = Matrix::zeros(3, 3);
+    for m in measurements {
+        let b = m.body.column();
+        let projection = &Matrix::identity(3) - &(&b * &b.transpose());
+        information = &information + &projection.scale(m.weight());
+    }
+
+    information.inverse().ok_or_else(|| PyValueError::new_err(
+        "measurements do not determine the attitude (at least two non-parallel vectors are required)"
+    ))
+}
@@ -0,0 +1,44 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Davenport's q-method.
+
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    VectorMeasurement,
+};
+
+use super::{
+    canonical,
+    covariance,
+    AttitudeEstimate,
+    Profile,
+};
+
+#[pyfunction]
+/// Determine attitude from two or more vector measurements using Davenport's q-method.
+///
+/// The q-method solves Wahba's problem exactly, by finding the eigenvector of
+/// Davenport's K matrix with the largest eigenvalue.  It is the most robust, but
+/// also the slowest, of the optimal algorithms.
+///
+/// Raises `ValueError` if the measurements do not determine the attitude.
+pub fn q_method(measurements: Vec<VectorMeasurement>) -> PyResult<AttitudeEstimate> {
+    let covariance = covariance(&measurements)?;
+
+    // Dominant eigenvector (eigenvalues are sorted in ascending order)
+    let (_, vectors) = Profile::new(&measurements).k().symmetric_eigen();
+    let q = Quaternion::new(
+        vectors[(0, 3)],
+        vectors[(1, 3)],
+        vectors[(2, 3)],
+        vectors[(3, 3)],
+    );
+
+    Ok (AttitudeEstimate {
+        quaternion: canonical(q.normalize()?),
+        covariance: covariance.to_rows(),
+    })
+}
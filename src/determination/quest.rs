@@ -0,0 +1,56 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! QUaternion ESTimator (QUEST).
+
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    VectorMeasurement,
+};
+use crate::linalg::Matrix;
+
+use super::{
+    adjugate,
+    covariance,
+    determinant,
+    unrotate,
+    AttitudeEstimate,
+    Profile,
+};
+
+#[pyfunction]
+/// Determine attitude from two or more vector measurements using Shuster's QUEST
+/// algorithm.
+///
+/// QUEST finds the largest eigenvalue of Davenport's K matrix by Newton-Raphson
+/// iteration on its characteristic equation, then solves for the optimal quaternion
+/// directly.  Rotations near 180 degrees are handled by the method of sequential
+/// rotations, using 180-degree rotations of the reference frame.
+///
+/// Raises `ValueError` if the measurements do not determine the attitude.
+pub fn quest(measurements: Vec<VectorMeasurement>) -> PyResult<AttitudeEstimate> {
+    let covariance = covariance(&measurements)?;
+
+    let profile = Profile::new(&measurements);
+    let lambda = profile.lambda_max();
+    let rotation = profile.select_rotation(lambda, &[
+        Quaternion::new(1.0, 0.0, 0.0, 0.0),
+        Quaternion::new(0.0, 1.0, 0.0, 0.0),
+        Quaternion::new(0.0, 0.0, 1.0, 0.0),
+        Quaternion::new(0.0, 0.0, 0.0, 1.0),
+    ], 1.0);
+    let p = profile.rotate(rotation);
+
+    // Optimal quaternion is proportional to (gamma, adj(M) @ z)
+    let m = &Matrix::identity(3).scale(lambda + p.sigma) - &p.s;
+    let gamma = determinant(&m);
+    let x = &adjugate(&m) * &p.z;
+    let q = Quaternion::new(gamma, x[(0, 0)], x[(1, 0)], x[(2, 0)]).normalize()?;
+
+    Ok (AttitudeEstimate {
+        quaternion: unrotate(q, rotation),
+        covariance: covariance.to_rows(),
+    })
+}
@@ -0,0 +1,64 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! TRIAD algorithm.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    Vector,
+    VectorMeasurement,
+};
+use crate::linalg::Matrix;
+
+use super::AttitudeEstimate;
+
+#[pyfunction]
+/// Determine attitude from two vector measurements using the TRIAD algorithm.
+///
+/// TRIAD constructs an orthonormal triad from each pair of vectors and matches
+/// them.  The first measurement is trusted exactly, so it should be the more
+/// accurate of the two.  The covariance is that of Shuster and Oh (1981).
+///
+/// Raises `ValueError` if the two measured vectors are parallel.
+pub fn triad(first: VectorMeasurement, second: VectorMeasurement) -> PyResult<AttitudeEstimate> {
+    let (b1, b2) = (first.body, second.body);
+    let (r1, r2) = (first.reference, second.reference);
+
+    let cross = b1.cross(b2);
+    if cross.norm() < 1e-12 || r1.cross(r2).norm() < 1e-12 {
+        return Err (PyValueError::new_err("TRIAD requires two non-parallel vectors"));
+    }
+
+    // Rotation from body frame to reference frame
+    let rotation = &frame(r1, r2)? * &frame(b1, b2)?.transpose();
+
+    // Covariance of attitude error angles (body frame)
+    let (s1, s2) = (first.sigma.powi(2), second.sigma.powi(2));
+    let (c1, c2) = (b1.column(), b2.column());
+    let correction = &(&c1 * &c1.transpose()).scale(s2 - s1) + &(
+        &(&c1 * &c2.transpose()) + &(&c2 * &c1.transpose())
+    ).scale(s1 * b1.dot(b2));
+    let covariance = &Matrix::identity(3).scale(s1) + &correction.scale(cross.norm().powi(-2));
+
+    Ok (AttitudeEstimate {
+        quaternion: Quaternion::from_dcm(&rotation),
+        covariance: covariance.to_rows(),
+    })
+}
+
+/// Construct the orthonormal TRIAD frame of two vectors, with the vectors of the
+/// frame as the columns of a matrix.
+fn frame(v1: Vector, v2: Vector) -> PyResult<Matrix> {
+    let t1 = v1;
+    let t2 = v1.cross(v2).normalize()?;
+    let t3 = t1.cross(t2);
+
+    Ok (Matrix::from_rows(&[
+        vec![t1.x, t2.x, t3.x],
+        vec![t1.y, t2.y, t3.y],
+        vec![t1.z, t2.z, t3.z],
+    ]).unwrap())
+}
@@ -0,0 +1,223 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Multiplicative Extended Kalman Filter.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Quaternion,
+    QuaternionMeasurement,
+    VectorMeasurement,
+};
+use crate::inertia::positive;
+use crate::linalg::Matrix;
+
+use super::{
+    apply_error,
+    error,
+    normalized_square,
+    propagate_quaternion,
+    AttitudeEstimator,
+    Observation,
+};
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Multiplicative Extended Kalman Filter (MEKF) for attitude and gyro bias.
+///
+/// The MEKF estimates the attitude quaternion globally, and a 6-dimensional error
+/// state (three attitude error angles in the body frame and three gyro bias errors)
+/// locally.  After each measurement update, the attitude error is folded into the
+/// quaternion by multiplication and reset to zero.
+///
+/// The gyro is modelled as the true angular velocity plus a bias driven by rate
+/// random walk (RRW) and white noise from angle random walk (ARW).
+///
+/// The error state is linearized about the estimate, so the MEKF assumes a small
+/// initial attitude error.  After a large one, the filter may remain inconsistent
+/// for a long time; prefer the [`super::Usque`] in that case.
+pub struct Mekf {
+    #[pyo3(get)]
+    /// Estimated attitude (rotation from body frame to inertial frame).
+    pub quaternion: Quaternion,
+
+    #[pyo3(get)]
+    /// Estimated gyro bias (body frame).
+    pub bias: AngularVelocity,
+
+    #[pyo3(get)]
+    /// Angle random walk of the gyro (rad/s/sqrt(Hz)).
+    pub arw: f64,
+
+    #[pyo3(get)]
+    /// Rate random walk of the gyro (rad/s^2/sqrt(Hz)).
+    pub rrw: f64,
+
+    #[pyo3(get)]
+    /// Normalized innovation squared (NIS) of the most recent measurement update.
+    pub nis: f64,
+
+    // Error-state covariance.
+    p: Matrix,
+}
+
+#[pymethods]
+impl Mekf {
+    #[new]
+    /// Construct a new MEKF, given an initial attitude and gyro bias, the standard
+    /// deviations of their errors (per axis), and the gyro noise parameters.
+    ///
+    /// Raises `ValueError` if any standard deviation is not positive or any noise
+    /// parameter is negative.
+    pub fn new(
+        quaternion: Quaternion,
+        bias: AngularVelocity,
+        attitude_sigma: f64,
+        bias_sigma: f64,
+        arw: f64,
+        rrw: f64,
+    ) -> PyResult<Self> {
+        positive("attitude standard deviation", attitude_sigma)?;
+        positive("bias standard deviation", bias_sigma)?;
+        if !(arw >= 0.0 && rrw >= 0.0) {
+            return Err (PyValueError::new_err("gyro noise parameters must be non-negative"));
+        }
+
+        let (a2, b2) = (attitude_sigma.powi(2), bias_sigma.powi(2));
+
+        Ok (Self {
+            quaternion: quaternion.normalize()?,
+            bias,
+            arw,
+            rrw,
+            nis: 0.0,
+            p: Matrix::diagonal(&[a2, a2, a2, b2, b2, b2]),
+        })
+    }
+
+    #[getter]
+    /// Covariance of the attitude error angles and gyro bias error, as a 6x6 matrix.
+    pub fn get_covariance(&self) -> Vec<Vec<f64>> {
+        self.p.to_rows()
+    }
+
+    /// Propagate the estimate over a time step, given the gyro measurement of angular
+    /// velocity (body frame), held constant over the step.
+    pub fn propagate(&mut self, angular_velocity: AngularVelocity, dt: f64) -> PyResult<()> {
+        positive("time step", dt)?;
+
+        let w = angular_velocity - self.bias;
+        self.quaternion = propagate_quaternion(self.quaternion, w, dt)?;
+
+        // Closed-form state transition of the error state
+        let n = (w.x*w.x + w.y*w.y + w.z*w.z).sqrt();
+        let wx = Matrix::skew(w.x, w.y, w.z);
+        let wx2 = &wx * &wx;
+        let i3 = Matrix::identity(3);
+        let (phi11, phi12) = if n * dt < 1e-8 {
+            (
+                &(&i3 - &wx.scale(dt)) + &wx2.scale(dt * dt / 2.0),
+                &(&wx.scale(dt * dt / 2.0) - &i3.scale(dt)) - &wx2.scale(dt.powi(3) / 6.0),
+            )
+        } else {
+            let (s, c) = ((n * dt).sin(), (n * dt).cos());
+            (
+                &(&i3 - &wx.scale(s / n)) + &wx2.scale((1.0 - c) / n.powi(2)),
+                &(&wx.scale((1.0 - c) / n.powi(2)) - &i3.scale(dt)) - &wx2.scale((n * dt - s) / n.powi(3)),
+            )
+        };
+        let mut phi = Matrix::identity(6);
+        phi.set_block(0, 0, &phi11);
+        phi.set_block(0, 3, &phi12);
+
+        // Discrete process noise
+        let (v2, u2) = (self.arw.powi(2), self.rrw.powi(2));
+        let mut q = Matrix::zeros(6, 6);
+        q.set_block(0, 0, &i3.scale(v2 * dt + u2 * dt.powi(3) / 3.0));
+        q.set_block(0, 3, &i3.scale(-u2 * dt.powi(2) / 2.0));
+        q.set_block(3, 0, &i3.scale(-u2 * dt.powi(2) / 2.0));
+        q.set_block(3, 3, &i3.scale(u2 * dt));
+
+        self.p = (&(&(&phi * &self.p) * &phi.transpose()) + &q).symmetrize();
+
+        Ok (())
+    }
+
+    /// Update the estimate with a vector measurement, returning the normalized
+    /// innovation squared (NIS).
+    pub fn update_vector(&mut self, measurement: VectorMeasurement) -> PyResult<f64> {
+        self.update(Observation::Vector (measurement))
+    }
+
+    /// Update the estimate with a quaternion measurement, returning the normalized
+    /// innovation squared (NIS).
+    pub fn update_quaternion(&mut self, measurement: QuaternionMeasurement) -> PyResult<f64> {
+        self.update(Observation::Quaternion (measurement))
+    }
+
+    /// Compute the normalized estimation error squared (NEES) of this estimate, given
+    /// the true attitude and gyro bias.
+    pub fn nees(&self, quaternion: Quaternion, bias: AngularVelocity) -> PyResult<f64> {
+        let e = error(self.quaternion, self.bias, quaternion, bias);
+
+        normalized_square(&e, &self.p)
+    }
+}
+
+impl Mekf {
+    /// Update the estimate with a measurement, using the Joseph form of the covariance
+    /// update, and return the normalized innovation squared (NIS).
+    fn update(&mut self, observation: Observation) -> PyResult<f64> {
+        let y = observation.residual(self.quaternion);
+        let r = observation.noise();
+        let mut h = Matrix::zeros(3, 6);
+        h.set_block(0, 0, &observation.jacobian(self.quaternion));
+
+        // Innovation covariance and Kalman gain
+        let s = &(&(&h * &self.p) * &h.transpose()) + &r;
+        let s_inv = s.inverse().ok_or_else(|| PyValueError::new_err("innovation covariance is singular"))?;
+        let k = &(&self.p * &h.transpose()) * &s_inv;
+        let nis = (&(&y.transpose() * &s_inv) * &y)[(0, 0)];
+
+        // Joseph-form covariance update
+        let a = &Matrix::identity(6) - &(&k * &h);
+        self.p = (&(&(&a * &self.p) * &a.transpose()) + &(&(&k * &r) * &k.transpose())).symmetrize();
+
+        // Fold the error state into the global estimate (and reset it to zero)
+        let dx = &k * &y;
+        self.quaternion = apply_error(self.quaternion, &dx.block(0, 0, 3, 1))?;
+        self.bias = self.bias + AngularVelocity::new(dx[(3, 0)], dx[(4, 0)], dx[(5, 0)]);
+        self.nis = nis;
+
+        Ok (nis)
+    }
+}
+
+impl AttitudeEstimator for Mekf {
+    fn propagate(&mut self, angular_velocity: AngularVelocity, dt: f64) -> PyResult<()> {
+        self.propagate(angular_velocity, dt)
+    }
+
+    fn update_vector(&mut self, measurement: VectorMeasurement) -> PyResult<f64> {
+        self.update_vector(measurement)
+    }
+
+    fn update_quaternion(&mut self, measurement: QuaternionMeasurement) -> PyResult<f64> {
+        self.update_quaternion(measurement)
+    }
+
+    fn quaternion(&self) -> Quaternion {
+        self.quaternion
+    }
+
+    fn bias(&self) -> AngularVelocity {
+        self.bias
+    }
+
+    fn covariance(&self) -> Vec<Vec<f64>> {
+        self.get_covariance()
+    }
+}
@@ -0,0 +1,143 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Recursive attitude estimation.
+//!
+//! The estimators in this module track the attitude of the body and the bias of its
+//! rate gyros.  They share the same propagation input (a gyro measurement of angular
+//! velocity held over a time step) and the same measurement models, so different
+//! filters can be compared on the same truth trajectory.
+//!
+//! Attitude errors are small rotations expressed in the _body frame_: the true attitude
+//! is the estimated attitude multiplied on the right by the error rotation.
+
+mod mekf;
+mod usque;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Quaternion,
+    QuaternionMeasurement,
+    Vector,
+    VectorMeasurement,
+};
+use crate::linalg::Matrix;
+
+pub use mekf::Mekf;
+pub use usque::Usque;
+
+/// Recursive estimator of attitude and gyro bias.
+pub trait AttitudeEstimator {
+    /// Propagate the estimate over a time step, given the gyro measurement of angular
+    /// velocity (body frame), held constant over the step.
+    fn propagate(&mut self, angular_velocity: AngularVelocity, dt: f64) -> PyResult<()>;
+
+    /// Update the estimate with a vector measurement, returning the normalized
+    /// innovation squared (NIS).
+    fn update_vector(&mut self, measurement: VectorMeasurement) -> PyResult<f64>;
+
+    /// Update the estimate with a quaternion measurement, returning the normalized
+    /// innovation squared (NIS).
+    fn update_quaternion(&mut self, measurement: QuaternionMeasurement) -> PyResult<f64>;
+
+    /// Estimated attitude (rotation from body frame to inertial frame).
+    fn quaternion(&self) -> Quaternion;
+
+    /// Estimated gyro bias (body frame).
+    fn bias(&self) -> AngularVelocity;
+
+    /// Covariance of the attitude error angles and gyro bias error, as a 6x6 matrix.
+    fn covariance(&self) -> Vec<Vec<f64>>;
+
+    /// Compute the normalized estimation error squared (NEES) of this estimate, given
+    /// the true attitude and gyro bias.
+    fn nees(&self, quaternion: Quaternion, bias: AngularVelocity) -> PyResult<f64> {
+        let p = Matrix::from_rows(&self.covariance()).unwrap();
+        let e = error(self.quaternion(), self.bias(), quaternion, bias);
+
+        normalized_square(&e, &p)
+    }
+}
+
+/// Measurement available to an attitude estimator.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Observation {
+    /// Direction measured in the body frame.
+    Vector (VectorMeasurement),
+
+    /// Attitude measured directly.
+    Quaternion (QuaternionMeasurement),
+}
+
+impl Observation {
+    /// Compute the residual (measured minus predicted) of this measurement, for a given
+    /// attitude.
+    pub fn residual(&self, q: Quaternion) -> Matrix {
+        match self {
+            Self::Vector (m) => {
+                let predicted = m.reference.rotate(q.inv());
+                (m.body - predicted).column()
+            },
+            Self::Quaternion (m) => {
+                let (x, y, z) = (q.inv() * m.quaternion).rotation_vector();
+                Matrix::column(&[x, y, z])
+            },
+        }
+    }
+
+    /// Compute the Jacobian of the predicted measurement with respect to the attitude
+    /// error angles, for a given attitude.
+    pub fn jacobian(&self, q: Quaternion) -> Matrix {
+        match self {
+            Self::Vector (m) => {
+                let Vector { x, y, z } = m.reference.rotate(q.inv());
+                Matrix::skew(x, y, z)
+            },
+            Self::Quaternion (_) => Matrix::identity(3),
+        }
+    }
+
+    /// Return the covariance of the measurement noise.
+    pub fn noise(&self) -> Matrix {
+        match self {
+            Self::Vector (m) => Matrix::identity(3).scale(m.sigma.powi(2)),
+            Self::Quaternion (m) => m.covariance_matrix(),
+        }
+    }
+}
+
+/// Propagate an attitude over a time step at a constant angular velocity (body frame).
+///
+/// This is the exact solution of the kinematics of [`Quaternion::diff`] for a constant
+/// angular velocity.
+pub(crate) fn propagate_quaternion(q: Quaternion, w: AngularVelocity, dt: f64) -> PyResult<Quaternion> {
+    (q * Quaternion::from_rotation_vector(w.x * dt, w.y * dt, w.z * dt)).normalize()
+}
+
+/// Apply an attitude error (body frame rotation vector) to an attitude.
+pub(crate) fn apply_error(q: Quaternion, e: &Matrix) -> PyResult<Quaternion> {
+    (q * Quaternion::from_rotation_vector(e[(0, 0)], e[(1, 0)], e[(2, 0)])).normalize()
+}
+
+/// Compute the 6-dimensional error between an estimate and the truth.
+pub(crate) fn error(
+    q: Quaternion,
+    b: AngularVelocity,
+    q_true: Quaternion,
+    b_true: AngularVelocity,
+) -> Matrix {
+    let (x, y, z) = (q.inv() * q_true).rotation_vector();
+    let db = b_true - b;
+
+    Matrix::column(&[x, y, z, db.x, db.y, db.z])
+}
+
+/// Compute the squared Mahalanobis norm `e.T @ inv(P) @ e`.
+pub(crate) fn normalized_square(e: &Matrix, p: &Matrix) -> PyResult<f64> {
+    let inv = p.inverse().ok_or_else(|| PyValueError::new_err("covariance matrix is singular"))?;
+
+    Ok ((&(&e.transpose() * &inv) * e)[(0, 0)])
+}
@@ -0,0 +1,317 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Unscented Quaternion Estimator.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Quaternion,
+    QuaternionMeasurement,
+    VectorMeasurement,
+};
+use crate::inertia::positive;
+use crate::linalg::Matrix;
+
+use super::{
+    error,
+    normalized_square,
+    propagate_quaternion,
+    AttitudeEstimator,
+    Observation,
+};
+
+/// Dimension of the error state.
+const N: usize = 6;
+
+/// Parameter `a` of the generalized Rodrigues parameters.
+const GRP_A: f64 = 1.0;
+
+/// Scale factor `f` of the generalized Rodrigues parameters.
+///
+/// With this choice, the parameters are equal to the rotation angles to first order.
+const GRP_F: f64 = 2.0 * (GRP_A + 1.0);
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Unscented Quaternion Estimator (USQUE) for attitude and gyro bias.
+///
+/// USQUE is an unscented Kalman filter in which the attitude part of each sigma point
+/// is a set of generalized Rodrigues parameters (GRPs), describing a rotation away
+/// from the mean quaternion.  Because it propagates sigma points through the full
+/// nonlinear kinematics and measurement models, it tolerates large initial errors
+/// and high rates better than the MEKF.
+///
+/// The gyro model and the error-state conventions are those of [`crate::Mekf`].
+pub struct Usque {
+    #[pyo3(get)]
+    /// Estimated attitude (rotation from body frame to inertial frame).
+    pub quaternion: Quaternion,
+
+    #[pyo3(get)]
+    /// Estimated gyro bias (body frame).
+    pub bias: AngularVelocity,
+
+    #[pyo3(get)]
+    /// Angle random walk of the gyro (rad/s/sqrt(Hz)).
+    pub arw: f64,
+
+    #[pyo3(get)]
+    /// Rate random walk of the gyro (rad/s^2/sqrt(Hz)).
+    pub rrw: f64,
+
+    #[pyo3(get)]
+    /// Sigma-point spread parameter (lambda).
+    pub spread: f64,
+
+    #[pyo3(get)]
+    /// Normalized innovation squared (NIS) of the most recent measurement update.
+    pub nis: f64,
+
+    // Error-state covariance.
+    p: Matrix,
+}
+
+#[pymethods]
+impl Usque {
+    #[new]
+    #[pyo3(signature = (quaternion, bias, attitude_sigma, bias_sigma, arw, rrw, spread = 1.0))]
+    /// Construct a new USQUE, given an initial attitude and gyro bias, the standard
+    /// deviations of their errors (per axis), and the gyro noise parameters.
+    ///
+    /// The spread parameter scales the distance of the sigma points from the mean.
+    /// Raises `ValueError` if any standard deviation is not positive, any noise
+    /// parameter is negative, or the spread is not greater than `-6`.
+    pub fn new(
+        quaternion: Quaternion,
+        bias: AngularVelocity,
+        attitude_sigma: f64,
+        bias_sigma: f64,
+        arw: f64,
+        rrw: f64,
+        spread: f64,
+    ) -> PyResult<Self> {
+        positive("attitude standard deviation", attitude_sigma)?;
+        positive("bias standard deviation", bias_sigma)?;
+        positive("sigma-point scale", N as f64 + spread)?;
+        if !(arw >= 0.0 && rrw >= 0.0) {
+            return Err (PyValueError::new_err("gyro noise parameters must be non-negative"));
+        }
+
+        let (a2, b2) = (attitude_sigma.powi(2), bias_sigma.powi(2));
+
+        Ok (Self {
+            quaternion: quaternion.normalize()?,
+            bias,
+            arw,
+            rrw,
+            spread,
+            nis: 0.0,
+            p: Matrix::diagonal(&[a2, a2, a2, b2, b2, b2]),
+        })
+    }
+
+    #[getter]
+    /// Covariance of the attitude error angles and gyro bias error, as a 6x6 matrix.
+    pub fn get_covariance(&self) -> Vec<Vec<f64>> {
+        self.p.to_rows()
+    }
+
+    /// Propagate the estimate over a time step, given the gyro measurement of angular
+    /// velocity (body frame), held constant over the step.
+    pub fn propagate(&mut self, angular_velocity: AngularVelocity, dt: f64) -> PyResult<()> {
+        positive("time step", dt)?;
+
+        // Process noise, split evenly before and after propagation
+        let (v2, u2) = (self.arw.powi(2), self.rrw.powi(2));
+        let q = Matrix::diagonal(&[
+            v2 - u2 * dt * dt / 6.0,
+            v2 - u2 * dt * dt / 6.0,
+            v2 - u2 * dt * dt / 6.0,
+            u2,
+            u2,
+            u2,
+        ]).scale(dt / 2.0);
+
+        // Propagate sigma attitudes through the kinematics with their own biases
+        let sigma = self.sigma_points(&(&self.p + &q))?;
+        let propagated = sigma.iter().map(|x| {
+            let b = self.bias + AngularVelocity::new(x[3], x[4], x[5]);
+            propagate_quaternion(self.quaternion * from_grp(x), angular_velocity - b, dt)
+        }).collect::<PyResult<Vec<Quaternion>>>()?;
+
+        // Express the propagated sigma points relative to the propagated central point
+        let center = propagated[0];
+        let points: Vec<[f64; N]> = propagated.iter().zip(&sigma).map(|(qi, x)| {
+            let p = to_grp(center.inv() * *qi);
+            [p[0], p[1], p[2], x[3], x[4], x[5]]
+        }).collect();
+
+        let (mean, covariance) = self.statistics(&points);
+        self.p = (&covariance + &q).symmetrize();
+
+        // Fold the mean error state into the global estimate
+        self.quaternion = (center * from_grp(&mean)).normalize()?;
+        self.bias = self.bias + AngularVelocity::new(mean[3], mean[4], mean[5]);
+
+        Ok (())
+    }
+
+    /// Update the estimate with a vector measurement, returning the normalized
+    /// innovation squared (NIS).
+    pub fn update_vector(&mut self, measurement: VectorMeasurement) -> PyResult<f64> {
+        self.update(Observation::Vector (measurement))
+    }
+
+    /// Update the estimate with a quaternion measurement, returning the normalized
+    /// innovation squared (NIS).
+    pub fn update_quaternion(&mut self, measurement: QuaternionMeasurement) -> PyResult<f64> {
+        self.update(Observation::Quaternion (measurement))
+    }
+
+    /// Compute the normalized estimation error squared (NEES) of this estimate, given
+    /// the true attitude and gyro bias.
+    pub fn nees(&self, quaternion: Quaternion, bias: AngularVelocity) -> PyResult<f64> {
+        let e = error(self.quaternion, self.bias, quaternion, bias);
+
+        normalized_square(&e, &self.p)
+    }
+}
+
+impl Usque {
+    /// Generate the sigma points (error states relative to the current estimate) of a
+    /// zero-mean distribution with the given covariance.
+    fn sigma_points(&self, p: &Matrix) -> PyResult<Vec<[f64; N]>> {
+        let l = p.scale(N as f64 + self.spread)
+            .cholesky()
+            .ok_or_else(|| PyValueError::new_err("estimate covariance is not positive definite"))?;
+
+        let mut points = vec![[0.0; N]];
+        for sign in [1.0, -1.0] {
+            for j in 0..N {
+                let mut x = [0.0; N];
+                for (i, xi) in x.iter_mut().enumerate() {
+                    *xi = sign * l[(i, j)];
+                }
+                points.push(x);
+            }
+        }
+
+        Ok (points)
+    }
+
+    /// Return the weight of the sigma point with a given index.
+    fn weight(&self, i: usize) -> f64 {
+        if i == 0 {
+            self.spread / (N as f64 + self.spread)
+        } else {
+            0.5 / (N as f64 + self.spread)
+        }
+    }
+
+    /// Compute the weighted mean and covariance of a set of sigma points.
+    fn statistics(&self, points: &[[f64; N]]) -> ([f64; N], Matrix) {
+        let mut mean = [0.0; N];
+        for (i, x) in points.iter().enumerate() {
+            for k in 0..N {
+                mean[k] += self.weight(i) * x[k];
+            }
+        }
+
+        let mut covariance = Matrix::zeros(N, N);
+        for (i, x) in points.iter().enumerate() {
+            let d = Matrix::column(&std::array::from_fn::<f64, N, _>(|k| x[k] - mean[k]));
+            covariance = &covariance + &(&d * &d.transpose()).scale(self.weight(i));
+        }
+
+        (mean, covariance)
+    }
+
+    /// Update the estimate with a measurement, and return the normalized innovation
+    /// squared (NIS).
+    fn update(&mut self, observation: Observation) -> PyResult<f64> {
+        let sigma = self.sigma_points(&self.p)?;
+
+        // Measurement residuals of each sigma point
+        let residuals: Vec<Matrix> = sigma.iter()
+            .map(|x| observation.residual(self.quaternion * from_grp(x)))
+            .collect();
+        let mut mean = Matrix::zeros(3, 1);
+        for (i, r) in residuals.iter().enumerate() {
+            mean = &mean + &r.scale(self.weight(i));
+        }
+
+        // Innovation covariance and cross-covariance (the predicted measurement is the
+        // measurement minus the residual)
+        let mut pvv = observation.noise();
+        let mut pxv = Matrix::zeros(N, 3);
+        for (i, (r, x)) in residuals.iter().zip(&sigma).enumerate() {
+            let dr = r - &mean;
+            let dx = Matrix::column(x);
+            pvv = &pvv + &(&dr * &dr.transpose()).scale(self.weight(i));
+            pxv = &pxv - &(&dx * &dr.transpose()).scale(self.weight(i));
+        }
+
+        let pvv_inv = pvv.inverse().ok_or_else(|| PyValueError::new_err("innovation covariance is singular"))?;
+        let k = &pxv * &pvv_inv;
+        let nis = (&(&mean.transpose() * &pvv_inv) * &mean)[(0, 0)];
+
+        self.p = (&self.p - &(&(&k * &pvv) * &k.transpose())).symmetrize();
+
+        // Fold the error state into the global estimate (and reset it to zero)
+        let dx = &k * &mean;
+        self.quaternion = (self.quaternion * from_grp(&[dx[(0, 0)], dx[(1, 0)], dx[(2, 0)]])).normalize()?;
+        self.bias = self.bias + AngularVelocity::new(dx[(3, 0)], dx[(4, 0)], dx[(5, 0)]);
+        self.nis = nis;
+
+        Ok (nis)
+    }
+}
+
+/// Convert generalized Rodrigues parameters (the first three entries of an error
+/// state) to an error quaternion.
+fn from_grp(x: &[f64]) -> Quaternion {
+    let n2 = x[0] * x[0] + x[1] * x[1] + x[2] * x[2];
+    let (a, f) = (GRP_A, GRP_F);
+
+    let w = (-a * n2 + f * (f * f + (1.0 - a * a) * n2).sqrt()) / (f * f + n2);
+    let s = (a + w) / f;
+
+    Quaternion::new(w, s * x[0], s * x[1], s * x[2])
+}
+
+/// Convert an error quaternion to generalized Rodrigues parameters.
+fn to_grp(q: Quaternion) -> [f64; 3] {
+    let q = if q.w < 0.0 { -q } else { q };
+    let s = GRP_F / (GRP_A + q.w);
+
+    [s * q.x, s * q.y, s * q.z]
+}
+
+impl AttitudeEstimator for Usque {
+    fn propagate(&mut self, angular_velocity: AngularVelocity, dt: f64) -> PyResult<()> {
+        self.propagate(angular_velocity, dt)
+    }
+
+    fn update_vector(&mut self, measurement: VectorMeasurement) -> PyResult<f64> {
+        self.update_vector(measurement)
+    }
+
+    fn update_quaternion(&mut self, measurement: QuaternionMeasurement) -> PyResult<f64> {
+        self.update_quaternion(measurement)
+    }
+
+    fn quaternion(&self) -> Quaternion {
+        self.quaternion
+    }
+
+    fn bias(&self) -> AngularVelocity {
+        self.bias
+    }
+
+    fn covariance(&self) -> Vec<Vec<f64>> {
+        self.get_covariance()
+    }
+}
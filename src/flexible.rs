@@ -0,0 +1,201 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Flexible appendage modes.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularMomentum,
+    Inertia,
+    Vector,
+};
+use crate::inertia::{
+    non_negative,
+    positive,
+};
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Structural modes of flexible appendages, such as solar arrays.
+///
+/// Each mode is a modal coordinate `eta`, normalized to unit modal mass, with a natural
+/// frequency, a damping ratio, and a rotational coupling vector `delta` to the bus.
+/// The modes obey
+/// ```
+/// eta_ddot + 2 * zeta * Omega * eta_dot + Omega**2 * eta = -delta . omega_dot
+/// ```
+/// and contribute `delta * eta_dot` to the angular momentum of the spacecraft.  The
+/// inertia tensor of the state is that of the whole undeformed spacecraft, including
+/// the appendages.
+pub struct FlexibleModes {
+    #[pyo3(get)]
+    /// Natural frequency of each mode (rad/s).
+    pub frequencies: Vec<f64>,
+
+    #[pyo3(get)]
+    /// Damping ratio of each mode.
+    pub damping_ratios: Vec<f64>,
+
+    #[pyo3(get)]
+    /// Rotational coupling vector of each mode to the bus (body frame).
+    pub coupling: Vec<Vector>,
+
+    #[pyo3(get)]
+    /// Modal displacements.
+    pub displacements: Vec<f64>,
+
+    #[pyo3(get)]
+    /// Modal velocities.
+    pub velocities: Vec<f64>,
+}
+
+#[pymethods]
+impl FlexibleModes {
+    #[new]
+    /// Construct new flexible modes, given the natural frequency, damping ratio and
+    /// rotational coupling vector of each mode.  The modes are initially at rest.
+    ///
+    /// Raises `ValueError` if the numbers of frequencies, damping ratios and coupling
+    /// vectors differ, if any frequency is not positive, or if any damping ratio is
+    /// negative.
+    pub fn new(frequencies: Vec<f64>, damping_ratios: Vec<f64>, coupling: Vec<Vector>) -> PyResult<Self> {
+        let n = frequencies.len();
+        let modes = Self {
+            frequencies,
+            damping_ratios,
+            coupling,
+            displacements: vec![0.0; n],
+            velocities: vec![0.0; n],
+        };
+        modes.validate()?;
+
+        Ok (modes)
+    }
+
+    #[setter]
+    /// Set the modal displacements, checking that there is one per mode.
+    fn set_displacements(&mut self, displacements: Vec<f64>) -> PyResult<()> {
+        self.check("modal displacements", &displacements)?;
+        self.displacements = displacements;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the modal velocities, checking that there is one per mode.
+    fn set_velocities(&mut self, velocities: Vec<f64>) -> PyResult<()> {
+        self.check("modal velocities", &velocities)?;
+        self.velocities = velocities;
+
+        Ok (())
+    }
+
+    /// Check that these modes are physically meaningful.
+    ///
+    /// Raises `ValueError` if the numbers of frequencies, damping ratios, coupling
+    /// vectors, displacements and velocities differ, if any frequency is not positive,
+    /// or if any damping ratio is negative.
+    pub fn validate(&self) -> PyResult<()> {
+        self.check("damping ratios", &self.damping_ratios)?;
+        self.check("modal displacements", &self.displacements)?;
+        self.check("modal velocities", &self.velocities)?;
+        if self.coupling.len() != self.len() {
+            return Err (PyValueError::new_err(format!(
+                "expected {} coupling vectors, got {}",
+                self.len(),
+                self.coupling.len(),
+            )));
+        }
+
+        for &frequency in &self.frequencies {
+            positive("modal frequency", frequency)?;
+        }
+        for &ratio in &self.damping_ratios {
+            non_negative("modal damping ratio", ratio)?;
+        }
+
+        Ok (())
+    }
+
+    /// Compute the angular momentum (body frame) of the modes relative to the bus.
+    pub fn momentum(&self) -> AngularMomentum {
+        let h = self.coupling.iter()
+            .zip(&self.velocities)
+            .fold(Vector::new(0.0, 0.0, 0.0), |h, (&delta, &v)| h + delta.scale(v));
+
+        AngularMomentum::new(h.x, h.y, h.z)
+    }
+
+    /// Compute the vibrational energy of the modes, kinetic and elastic.
+    pub fn energy(&self) -> f64 {
+        self.frequencies.iter()
+            .zip(&self.displacements)
+            .zip(&self.velocities)
+            .map(|((&f, &x), &v)| 0.5 * (v*v + f*f*x*x))
+            .sum()
+    }
+
+    /// Return the number of modes.
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    /// Return a Pythonic representation of these modes.
+    fn __repr__(&self) -> String {
+        format!(
+            "FlexibleModes({:?}, {:?}, [{}])",
+            self.frequencies,
+            self.damping_ratios,
+            self.coupling.iter().map(Vector::__repr__).collect::<Vec<_>>().join(", "),
+        )
+    }
+}
+
+impl FlexibleModes {
+    /// Return the number of modes.
+    pub fn len(&self) -> usize {
+        self.frequencies.len()
+    }
+
+    /// Determine whether there are no modes.
+    pub fn is_empty(&self) -> bool {
+        self.frequencies.is_empty()
+    }
+
+    /// Compute the inertia tensor `sum(delta @ delta.T)` coupled to the modes.
+    pub(crate) fn coupled_inertia(&self) -> Inertia {
+        self.coupling.iter().fold(Inertia::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0), |j, d| Inertia::new(
+            j.j1 + d.x*d.x,
+            j.j2 + d.y*d.y,
+            j.j3 + d.z*d.z,
+            j.j4 + d.y*d.z,
+            j.j5 + d.x*d.z,
+            j.j6 + d.x*d.y,
+        ))
+    }
+
+    /// Compute the restoring force `-(2 * zeta * Omega * eta_dot + Omega**2 * eta)` of
+    /// each mode.
+    pub(crate) fn restoring(&self) -> Vec<f64> {
+        (0..self.len()).map(|i| {
+            let f = self.frequencies[i];
+            -(2.0 * self.damping_ratios[i] * f * self.velocities[i] + f * f * self.displacements[i])
+        }).collect()
+    }
+
+    /// Raise `ValueError` unless a named list has one entry per mode.
+    fn check(&self, name: &str, values: &[f64]) -> PyResult<()> {
+        if values.len() == self.len() {
+            Ok (())
+        } else {
+            Err (PyValueError::new_err(format!(
+                "expected {} {}, got {}",
+                self.len(),
+                name,
+                values.len(),
+            )))
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Attitude guidance.
+//!
+//! Guidance produces the reference attitude, angular velocity and angular acceleration
+//! that a controller tracks.  The reference attitude rotates from the target frame to
+//! the reference (inertial) frame, consistent with [`crate::State`], and the reference
+//! rates are given in the target frame.
+
+mod pointing;
+mod slew;
+
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Torque,
+    Vector,
+};
+use crate::control::feedforward;
+
+pub use pointing::{
+    InertialHold,
+    NadirPointing,
+    SunPointing,
+    TargetTracking,
+};
+pub use slew::Slew;
+
+/// Gravitational parameter of the Earth (m^3/s^2).
+const EARTH_MU: f64 = 3.986004418e14;
+
+/// Rotation rate of the Earth (rad/s).
+const EARTH_RATE: f64 = 7.2921150e-5;
+
+/// Equatorial radius of the WGS84 ellipsoid (m).
+const WGS84_RADIUS: f64 = 6378137.0;
+
+/// Square of the eccentricity of the WGS84 ellipsoid.
+const WGS84_ECCENTRICITY_SQUARED: f64 = 6.69437999014e-3;
+
+/// Number of Runge-Kutta steps used to propagate an orbit.
+const ORBIT_STEPS: usize = 10;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Attitude reference.
+pub struct Reference {
+    #[pyo3(get)]
+    /// Reference attitude (rotation from target frame to reference frame).
+    pub quaternion: Quaternion,
+
+    #[pyo3(get)]
+    /// Reference angular velocity (target frame).
+    pub angular_velocity: AngularVelocity,
+
+    #[pyo3(get)]
+    /// Reference angular acceleration (target frame).
+    pub angular_acceleration: AngularVelocity,
+}
+
+#[pymethods]
+impl Reference {
+    #[new]
+    #[pyo3(signature = (quaternion, angular_velocity = None, angular_acceleration = None))]
+    /// Construct a new reference.  By default, the reference is at rest.
+    ///
+    /// The quaternion is normalized automatically.  Raises `ValueError` if the
+    /// quaternion is zero.
+    pub fn new(
+        quaternion: Quaternion,
+        angular_velocity: Option<AngularVelocity>,
+        angular_acceleration: Option<AngularVelocity>,
+    ) -> PyResult<Self> {
+        Ok (Self {
+            quaternion: quaternion.normalize()?,
+            angular_velocity: angular_velocity.unwrap_or(AngularVelocity::new(0.0, 0.0, 0.0)),
+            angular_acceleration: angular_acceleration.unwrap_or(AngularVelocity::new(0.0, 0.0, 0.0)),
+        })
+    }
+
+    /// Compute the torque (target frame) that makes a body with the given inertia
+    /// tensor follow this reference exactly.
+    /// ```
+    /// torque = J @ alpha + omega x (J @ omega)
+    /// ```
+    pub fn feedforward(&self, inertia: Inertia) -> Torque {
+        feedforward(inertia, self.angular_velocity, self.angular_acceleration)
+    }
+
+    /// Return a Pythonic representation of this reference.
+    fn __repr__(&self) -> String {
+        format!(
+            "Reference({}, {}, {})",
+            self.quaternion.__repr__(),
+            self.angular_velocity.__repr__(),
+            self.angular_acceleration.__repr__(),
+        )
+    }
+}
+
+/// Propagate a Keplerian orbit by a short time, given the position and velocity of the
+/// body (reference frame, relative to the centre of the Earth).
+fn propagate(position: Vector, velocity: Vector, dt: f64) -> (Vector, Vector) {
+    let gravity = |r: Vector| r.scale(-EARTH_MU / r.norm().powi(3));
+    let h = dt / ORBIT_STEPS as f64;
+
+    let (mut r, mut v) = (position, velocity);
+    for _ in 0..ORBIT_STEPS {
+        let (k1r, k1v) = (v, gravity(r));
+        let (k2r, k2v) = (v + k1v.scale(h / 2.0), gravity(r + k1r.scale(h / 2.0)));
+        let (k3r, k3v) = (v + k2v.scale(h / 2.0), gravity(r + k2r.scale(h / 2.0)));
+        let (k4r, k4v) = (v + k3v.scale(h), gravity(r + k3r.scale(h)));
+
+        r = r + (k1r + k2r.scale(2.0) + k3r.scale(2.0) + k4r).scale(h / 6.0);
+        v = v + (k1v + k2v.scale(2.0) + k3v.scale(2.0) + k4v).scale(h / 6.0);
+    }
+
+    (r, v)
+}
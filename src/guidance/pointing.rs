@@ -0,0 +1,303 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Pointing modes.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Quaternion,
+    Vector,
+};
+use crate::linalg::Matrix;
+
+use super::{
+    EARTH_RATE,
+    Reference,
+    WGS84_ECCENTRICITY_SQUARED,
+    WGS84_RADIUS,
+    propagate,
+};
+
+/// Time step of the finite-difference reference rates (s).
+const FINITE_DIFFERENCE_STEP: f64 = 0.1;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Inertial hold.
+///
+/// The reference holds a fixed attitude, at rest.
+pub struct InertialHold {
+    #[pyo3(get, set)]
+    /// Held attitude (rotation from target frame to reference frame).
+    pub quaternion: Quaternion,
+}
+
+#[pymethods]
+impl InertialHold {
+    #[new]
+    /// Construct a new inertial hold of a given attitude.
+    ///
+    /// The quaternion is normalized automatically.  Raises `ValueError` if the
+    /// quaternion is zero.
+    pub fn new(quaternion: Quaternion) -> PyResult<Self> {
+        Ok (Self {
+            quaternion: quaternion.normalize()?,
+        })
+    }
+
+    /// Sample the reference.
+    pub fn sample(&self) -> PyResult<Reference> {
+        Reference::new(self.quaternion, None, None)
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Sun pointing with a secondary constraint.
+///
+/// The reference points the primary body axis at the sun, and turns about it to bring
+/// the secondary body axis as close as possible to a secondary direction, which is
+/// fixed in the reference frame (for example, the ecliptic pole or the orbit normal).
+/// Since both directions are inertially fixed over the short term, the reference is
+/// at rest.
+pub struct SunPointing {
+    #[pyo3(get, set)]
+    /// Primary body axis, pointed at the sun (body frame).
+    pub primary: Vector,
+
+    #[pyo3(get, set)]
+    /// Secondary body axis (body frame).
+    pub secondary: Vector,
+
+    #[pyo3(get, set)]
+    /// Secondary direction (reference frame).
+    pub secondary_target: Vector,
+}
+
+#[pymethods]
+impl SunPointing {
+    #[new]
+    /// Construct a new sun-pointing mode, given the primary and secondary body axes and
+    /// the secondary direction.
+    pub fn new(primary: Vector, secondary: Vector, secondary_target: Vector) -> Self {
+        Self {
+            primary,
+            secondary,
+            secondary_target,
+        }
+    }
+
+    /// Sample the reference, given the direction of the sun (reference frame).
+    ///
+    /// Raises `ValueError` if either pair of axes is parallel or zero.
+    pub fn sample(&self, sun: Vector) -> PyResult<Reference> {
+        let q = align(self.primary, sun, self.secondary, self.secondary_target)?;
+
+        Reference::new(q, None, None)
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Nadir (local-vertical, local-horizontal) pointing.
+///
+/// The target frame is the LVLH frame of the orbit, rotated by a fixed offset.  The
+/// LVLH frame has its Z axis towards nadir, its Y axis opposite the orbit normal, and
+/// its X axis completing the right-handed frame (along the velocity, for a circular
+/// orbit).  The reference rates are exact for Keplerian motion.
+pub struct NadirPointing {
+    #[pyo3(get, set)]
+    /// Offset (rotation from target frame to LVLH frame).
+    pub offset: Quaternion,
+}
+
+#[pymethods]
+impl NadirPointing {
+    #[new]
+    #[pyo3(signature = (offset = None))]
+    /// Construct a new nadir-pointing mode, with an optional offset from the LVLH frame.
+    ///
+    /// The offset is normalized automatically.  Raises `ValueError` if the offset is
+    /// zero.
+    pub fn new(offset: Option<Quaternion>) -> PyResult<Self> {
+        Ok (Self {
+            offset: offset.unwrap_or(Quaternion::new(1.0, 0.0, 0.0, 0.0)).normalize()?,
+        })
+    }
+
+    /// Sample the reference, given the position and velocity of the body (reference
+    /// frame, relative to the centre of the Earth).
+    ///
+    /// Raises `ValueError` if the position is zero or parallel to the velocity.
+    pub fn sample(&self, position: Vector, velocity: Vector) -> PyResult<Reference> {
+        let h = position.cross(velocity);
+        let r = position.norm();
+        let lvlh = align(
+            Vector::new(0.0, 0.0, 1.0),
+            -position,
+            Vector::new(0.0, 1.0, 0.0),
+            -h,
+        )?;
+
+        // Orbital rate about the orbit normal, which is the negative LVLH Y axis
+        let rate = h.norm() / (r * r);
+        let acceleration = -2.0 * rate * position.dot(velocity) / (r * r);
+
+        let inv = self.offset.inv();
+        Reference::new(
+            lvlh * self.offset,
+            Some (AngularVelocity::new(0.0, -rate, 0.0).rotate(inv)),
+            Some (AngularVelocity::new(0.0, -acceleration, 0.0).rotate(inv)),
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Ground-target tracking.
+///
+/// The reference points the boresight at a target fixed on the rotating Earth, and
+/// turns about it to bring the secondary body axis as close as possible to the
+/// negative orbit normal.  The target is given by its geodetic coordinates on the
+/// WGS84 ellipsoid, and the Earth rotates about the Z axis of the reference frame.
+/// The reference rates are found by finite differences along the Keplerian orbit.
+///
+/// Positions are in metres and times in seconds.
+pub struct TargetTracking {
+    #[pyo3(get, set)]
+    /// Geodetic latitude of the target (rad).
+    pub latitude: f64,
+
+    #[pyo3(get, set)]
+    /// Longitude of the target (rad).
+    pub longitude: f64,
+
+    #[pyo3(get, set)]
+    /// Altitude of the target above the ellipsoid (m).
+    pub altitude: f64,
+
+    #[pyo3(get, set)]
+    /// Boresight, pointed at the target (body frame).
+    pub boresight: Vector,
+
+    #[pyo3(get, set)]
+    /// Secondary body axis (body frame).
+    pub secondary: Vector,
+
+    #[pyo3(get, set)]
+    /// Rotation angle of the Earth at time zero (rad).
+    pub earth_angle: f64,
+}
+
+#[pymethods]
+impl TargetTracking {
+    #[new]
+    #[pyo3(signature = (latitude, longitude, altitude = 0.0, boresight = None, secondary = None, earth_angle = 0.0))]
+    /// Construct a new ground-target tracking mode, given the geodetic coordinates of
+    /// the target.  By default, the boresight is the body Z axis, and the secondary
+    /// axis is the body Y axis, so that the reference matches nadir pointing with the
+    /// target directly below.
+    pub fn new(
+        latitude: f64,
+        longitude: f64,
+        altitude: f64,
+        boresight: Option<Vector>,
+        secondary: Option<Vector>,
+        earth_angle: f64,
+    ) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+            boresight: boresight.unwrap_or(Vector::new(0.0, 0.0, 1.0)),
+            secondary: secondary.unwrap_or(Vector::new(0.0, 1.0, 0.0)),
+            earth_angle,
+        }
+    }
+
+    /// Compute the position of the target (reference frame) at a given time.
+    pub fn target(&self, time: f64) -> Vector {
+        let (sin_lat, cos_lat) = self.latitude.sin_cos();
+        let n = WGS84_RADIUS / (1.0 - WGS84_ECCENTRICITY_SQUARED * sin_lat * sin_lat).sqrt();
+
+        let (sin_lon, cos_lon) = (self.longitude + self.earth_angle + EARTH_RATE * time).sin_cos();
+        Vector::new(
+            (n + self.altitude) * cos_lat * cos_lon,
+            (n + self.altitude) * cos_lat * sin_lon,
+            (n * (1.0 - WGS84_ECCENTRICITY_SQUARED) + self.altitude) * sin_lat,
+        )
+    }
+
+    /// Sample the reference, given the time and the position and velocity of the body
+    /// (reference frame, relative to the centre of the Earth).
+    ///
+    /// Raises `ValueError` if the body is at the target, or if the line of sight is
+    /// parallel to the orbit normal.
+    pub fn sample(&self, time: f64, position: Vector, velocity: Vector) -> PyResult<Reference> {
+        let h = FINITE_DIFFERENCE_STEP;
+        let (r0, v0) = propagate(position, velocity, -h);
+        let (r2, v2) = propagate(position, velocity, h);
+
+        let q0 = self.attitude(time - h, r0, v0)?;
+        let q1 = self.attitude(time, position, velocity)?;
+        let q2 = self.attitude(time + h, r2, v2)?;
+
+        // Angular velocities over each half-interval, in the target frame
+        let rate = |a: Quaternion, b: Quaternion| {
+            let (x, y, z) = (a.inv() * b).rotation_vector();
+            AngularVelocity::new(x, y, z).scale(h.recip())
+        };
+        let backward = rate(q0, q1);
+        let forward = rate(q1, q2);
+
+        Reference::new(
+            q1,
+            Some ((backward + forward).scale(0.5)),
+            Some ((forward - backward).scale(h.recip())),
+        )
+    }
+}
+
+impl TargetTracking {
+    /// Compute the tracking attitude at a given time, given the position and velocity
+    /// of the body.
+    fn attitude(&self, time: f64, position: Vector, velocity: Vector) -> PyResult<Quaternion> {
+        let line_of_sight = self.target(time) - position;
+
+        align(self.boresight, line_of_sight, self.secondary, -position.cross(velocity))
+    }
+}
+
+/// Compute the attitude that aligns a primary body axis with a primary direction, and
+/// brings a secondary body axis as close as possible to a secondary direction.
+///
+/// Raises `ValueError` if either pair of axes is parallel or zero.
+fn align(
+    primary: Vector,
+    primary_target: Vector,
+    secondary: Vector,
+    secondary_target: Vector,
+) -> PyResult<Quaternion> {
+    let triad = |a: Vector, b: Vector| -> PyResult<Matrix> {
+        let t1 = a.normalize()?;
+        let t2 = t1.cross(b).normalize().map_err(|_| PyValueError::new_err(
+            "primary and secondary pointing axes must not be parallel"
+        ))?;
+        let t3 = t1.cross(t2);
+
+        let mut m = Matrix::zeros(3, 3);
+        m.set_block(0, 0, &t1.column());
+        m.set_block(0, 1, &t2.column());
+        m.set_block(0, 2, &t3.column());
+
+        Ok (m)
+    };
+
+    let body = triad(primary, secondary)?;
+    let reference = triad(primary_target, secondary_target)?;
+
+    Ok (Quaternion::from_dcm(&(&reference * &body.transpose())))
+}
@@ -0,0 +1,250 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Eigenaxis slew planner.
+
+use std::f64::consts::PI;
+
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Torque,
+    Vector,
+};
+use crate::inertia::positive;
+
+use super::Reference;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Rest-to-rest eigenaxis slew.
+///
+/// The slew rotates the body from the start attitude to the end attitude about a fixed
+/// axis (the eigenaxis), along the shorter path.  The rotation angle follows a
+/// bang-coast-bang profile: a phase of maximum angular acceleration, a coast at the
+/// rate limit (if reached), and a symmetric phase of maximum deceleration.
+///
+/// The peak angular acceleration is the largest for which the torque `J @ axis * alpha`
+/// respects the torque limit on every body axis.  The gyroscopic torque, which is zero
+/// when the eigenaxis is a principal axis, is not included in the limit.
+///
+/// If smoothing is enabled, each acceleration phase is a versine pulse rather than a
+/// rectangle, so that the angular acceleration (and hence the torque) is continuous.
+/// Smoothing lengthens the acceleration phases, since the mean acceleration is half
+/// the peak.
+pub struct Slew {
+    #[pyo3(get)]
+    /// Start attitude.
+    pub start: Quaternion,
+
+    #[pyo3(get)]
+    /// End attitude.
+    pub end: Quaternion,
+
+    #[pyo3(get)]
+    /// Inertia tensor of the body.
+    pub inertia: Inertia,
+
+    #[pyo3(get)]
+    /// Torque limit of each body axis.
+    pub max_torque: f64,
+
+    #[pyo3(get)]
+    /// Angular velocity limit about the eigenaxis (rad/s).
+    pub max_rate: f64,
+
+    #[pyo3(get)]
+    /// Whether the acceleration phases are smoothed.
+    pub smooth: bool,
+
+    #[pyo3(get)]
+    /// Time at which the slew starts.
+    pub start_time: f64,
+
+    #[pyo3(get)]
+    /// Eigenaxis (body frame).
+    pub axis: Vector,
+
+    #[pyo3(get)]
+    /// Total rotation angle (rad).
+    pub angle: f64,
+
+    // Peak angular acceleration.
+    peak: f64,
+
+    // Cruise angular velocity.
+    cruise: f64,
+
+    // Duration of each acceleration phase.
+    ramp: f64,
+
+    // Duration of the coast phase.
+    coast: f64,
+}
+
+#[pymethods]
+impl Slew {
+    #[new]
+    #[pyo3(signature = (start, end, inertia, max_torque, max_rate, smooth = false, start_time = 0.0))]
+    /// Plan a rest-to-rest eigenaxis slew, given the start and end attitudes, the inertia
+    /// tensor of the body, the torque limit of each body axis and the angular velocity
+    /// limit.
+    ///
+    /// Raises `ValueError` if either attitude is zero, or if either limit is not positive.
+    pub fn new(
+        start: Quaternion,
+        end: Quaternion,
+        inertia: Inertia,
+        max_torque: f64,
+        max_rate: f64,
+        smooth: bool,
+        start_time: f64,
+    ) -> PyResult<Self> {
+        positive("maximum torque", max_torque)?;
+        positive("maximum rate", max_rate)?;
+
+        let start = start.normalize()?;
+        let end = end.normalize()?;
+
+        // Eigenaxis rotation, in the body frame at the start
+        let (x, y, z) = (start.inv() * end).rotation_vector();
+        let angle = (x*x + y*y + z*z).sqrt();
+        let axis = if angle > 0.0 {
+            Vector::new(x / angle, y / angle, z / angle)
+        } else {
+            Vector::new(1.0, 0.0, 0.0)
+        };
+
+        // Largest acceleration respecting the torque limit on every axis
+        let j = Vector::new(
+            inertia.j1*axis.x + inertia.j6*axis.y + inertia.j5*axis.z,
+            inertia.j6*axis.x + inertia.j2*axis.y + inertia.j4*axis.z,
+            inertia.j5*axis.x + inertia.j4*axis.y + inertia.j3*axis.z,
+        );
+        let peak = max_torque / j.x.abs().max(j.y.abs()).max(j.z.abs());
+        positive("peak angular acceleration", peak)?;
+
+        // Mean acceleration over an acceleration phase
+        let mean = if smooth { 0.5 * peak } else { peak };
+
+        // Cruise at the rate limit, unless the angle is too small to reach it
+        let cruise = max_rate.min((angle * mean).sqrt());
+        let ramp = cruise / mean;
+        let coast = if cruise > 0.0 { (angle / cruise - ramp).max(0.0) } else { 0.0 };
+
+        Ok (Self {
+            start,
+            end,
+            inertia,
+            max_torque,
+            max_rate,
+            smooth,
+            start_time,
+            axis,
+            angle,
+            peak,
+            cruise,
+            ramp,
+            coast,
+        })
+    }
+
+    #[getter]
+    /// Duration of the slew.
+    pub fn duration(&self) -> f64 {
+        2.0 * self.ramp + self.coast
+    }
+
+    #[getter]
+    /// Time at which the slew ends.
+    pub fn end_time(&self) -> f64 {
+        self.start_time + self.duration()
+    }
+
+    /// Sample the reference at a given time.
+    ///
+    /// Before the slew, the reference rests at the start attitude; after the slew, it
+    /// rests at the end attitude.
+    pub fn sample(&self, time: f64) -> Reference {
+        let t = (time - self.start_time).clamp(0.0, self.duration());
+        let (angle, rate, acceleration) = self.profile(t);
+
+        let a = self.axis;
+        Reference {
+            quaternion: self.start * Quaternion::from_rotation_vector(
+                angle * a.x,
+                angle * a.y,
+                angle * a.z,
+            ),
+            angular_velocity: AngularVelocity::new(rate * a.x, rate * a.y, rate * a.z),
+            angular_acceleration: AngularVelocity::new(
+                acceleration * a.x,
+                acceleration * a.y,
+                acceleration * a.z,
+            ),
+        }
+    }
+
+    /// Compute the feedforward torque (body frame) of the slew at a given time,
+    /// assuming the body follows the reference exactly.
+    pub fn feedforward(&self, time: f64) -> Torque {
+        self.sample(time).feedforward(self.inertia)
+    }
+
+    /// Return a Pythonic representation of this slew.
+    fn __repr__(&self) -> String {
+        format!(
+            "Slew({}, {}, {}, {}, {}, {}, {})",
+            self.start.__repr__(),
+            self.end.__repr__(),
+            self.inertia.__repr__(),
+            self.max_torque,
+            self.max_rate,
+            if self.smooth { "True" } else { "False" },
+            self.start_time,
+        )
+    }
+}
+
+impl Slew {
+    /// Compute the rotation angle, angular velocity and angular acceleration about the
+    /// eigenaxis at a given time since the start of the slew.
+    fn profile(&self, t: f64) -> (f64, f64, f64) {
+        let total = self.duration();
+
+        if t <= 0.0 {
+            (0.0, 0.0, 0.0)
+        } else if t >= total {
+            (self.angle, 0.0, 0.0)
+        } else if t <= self.ramp {
+            self.accelerate(t)
+        } else if t < self.ramp + self.coast {
+            (0.5 * self.cruise * self.ramp + self.cruise * (t - self.ramp), self.cruise, 0.0)
+        } else {
+            // Deceleration mirrors acceleration
+            let (angle, rate, acceleration) = self.accelerate(total - t);
+            (self.angle - angle, rate, -acceleration)
+        }
+    }
+
+    /// Compute the rotation angle, angular velocity and angular acceleration at a given
+    /// time into the acceleration phase.
+    fn accelerate(&self, t: f64) -> (f64, f64, f64) {
+        let a = self.peak;
+
+        if !self.smooth {
+            return (0.5 * a * t * t, a * t, a);
+        }
+
+        // Versine pulse, a * sin^2(pi * t / ramp)
+        let w = 2.0 * PI / self.ramp;
+        (
+            a * (t * t / 4.0 + ((w * t).cos() - 1.0) / (2.0 * w * w)),
+            a * (t / 2.0 - (w * t).sin() / (2.0 * w)),
+            a * (PI * t / self.ramp).sin().powi(2),
+        )
+    }
+}
@@ -3,7 +3,20 @@
 //!
 //! Inertia Tensor.
 
+use std::ops::Add;
+
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+use crate::{
+    Quaternion,
+    Vector,
+};
+use crate::linalg::Matrix;
+
+/// Relative tolerance on the triangle inequality, admitting thin plates.
+const TRIANGLE_TOLERANCE: f64 = 1e-9;
 
 #[pyclass]
 #[derive(Clone, Copy, Debug)]
@@ -24,40 +37,268 @@ use pyo3::prelude::*;
 ///      [ J5 J4 J3 ]]
 /// ```
 pub struct Inertia {
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub j1: f64,
 
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub j2: f64,
 
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub j3: f64,
 
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub j4: f64,
 
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub j5: f64,
 
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub j6: f64,
 }
 
 #[pymethods]
 impl Inertia {
     #[new]
-    /// Construct a new inertia tensor.
-    pub fn new(j1: f64, j2: f64, j3: f64, j4: f64, j5: f64, j6: f64) -> Self {
+    /// Construct a new inertia tensor, checking that it is physically realizable.
+    ///
+    /// Raises `ValueError` if the tensor is not finite, not positive definite, or if its
+    /// principal moments violate the triangle inequality.
+    pub fn try_new(j1: f64, j2: f64, j3: f64, j4: f64, j5: f64, j6: f64) -> PyResult<Self> {
+        let inertia = Self::new(j1, j2, j3, j4, j5, j6);
+        inertia.validate()?;
+
+        Ok (inertia)
+    }
+
+    #[setter]
+    /// Set component J1, checking that the tensor remains physically realizable.
+    fn set_j1(&mut self, j1: f64) -> PyResult<()> {
+        self.assign(Self { j1, ..*self })
+    }
+
+    #[setter]
+    /// Set component J2, checking that the tensor remains physically realizable.
+    fn set_j2(&mut self, j2: f64) -> PyResult<()> {
+        self.assign(Self { j2, ..*self })
+    }
+
+    #[setter]
+    /// Set component J3, checking that the tensor remains physically realizable.
+    fn set_j3(&mut self, j3: f64) -> PyResult<()> {
+        self.assign(Self { j3, ..*self })
+    }
+
+    #[setter]
+    /// Set component J4, checking that the tensor remains physically realizable.
+    fn set_j4(&mut self, j4: f64) -> PyResult<()> {
+        self.assign(Self { j4, ..*self })
+    }
+
+    #[setter]
+    /// Set component J5, checking that the tensor remains physically realizable.
+    fn set_j5(&mut self, j5: f64) -> PyResult<()> {
+        self.assign(Self { j5, ..*self })
+    }
+
+    #[setter]
+    /// Set component J6, checking that the tensor remains physically realizable.
+    fn set_j6(&mut self, j6: f64) -> PyResult<()> {
+        self.assign(Self { j6, ..*self })
+    }
+
+    /// Check that this inertia tensor is physically realizable.
+    ///
+    /// A physical inertia tensor is finite and positive definite, and its principal
+    /// moments satisfy the triangle inequality (no principal moment exceeds the sum
+    /// of the other two).  Raises `ValueError` otherwise.
+    pub fn validate(&self) -> PyResult<()> {
+        let components = [self.j1, self.j2, self.j3, self.j4, self.j5, self.j6];
+        if components.iter().any(|j| !j.is_finite()) {
+            return Err (PyValueError::new_err(format!(
+                "inertia tensor has non-finite components: {}",
+                self.__repr__(),
+            )));
+        }
+
+        let ((a, b, c), _) = self.principal();
+        if a <= 0.0 {
+            return Err (PyValueError::new_err(format!(
+                "inertia tensor is not positive definite (principal moments {}, {}, {})",
+                a,
+                b,
+                c,
+            )));
+        }
+
+        // Only the largest moment can violate the triangle inequality
+        if c > (a + b) * (1.0 + TRIANGLE_TOLERANCE) {
+            return Err (PyValueError::new_err(format!(
+                "principal moments of inertia violate the triangle inequality ({} > {} + {})",
+                c,
+                a,
+                b,
+            )));
+        }
+
+        Ok (())
+    }
+
+    #[classmethod]
+    #[pyo3(name = "box")]
+    /// Construct the inertia tensor of a solid rectangular box about its centre of mass,
+    /// given its mass and its side lengths along the X, Y and Z axes.
+    pub fn cuboid(_cls: &Bound<'_, PyType>, m: f64, a: f64, b: f64, c: f64) -> PyResult<Self> {
+        positive("mass", m)?;
+        positive("side length", a)?;
+        positive("side length", b)?;
+        positive("side length", c)?;
+
+        Ok (Self::new(
+            m * (b*b + c*c) / 12.0,
+            m * (a*a + c*c) / 12.0,
+            m * (a*a + b*b) / 12.0,
+            0.0,
+            0.0,
+            0.0,
+        ))
+    }
+
+    #[classmethod]
+    /// Construct the inertia tensor of a solid cylinder about its centre of mass,
+    /// given its mass, radius and length.  The cylinder axis is the Z axis.
+    pub fn cylinder(_cls: &Bound<'_, PyType>, m: f64, r: f64, h: f64) -> PyResult<Self> {
+        positive("mass", m)?;
+        positive("radius", r)?;
+        positive("length", h)?;
+
+        let transverse = m * (3.0*r*r + h*h) / 12.0;
+
+        Ok (Self::new(
+            transverse,
+            transverse,
+            m * r*r / 2.0,
+            0.0,
+            0.0,
+            0.0,
+        ))
+    }
+
+    #[classmethod]
+    /// Construct the inertia tensor of a solid sphere about its centre,
+    /// given its mass and radius.
+    pub fn sphere(_cls: &Bound<'_, PyType>, m: f64, r: f64) -> PyResult<Self> {
+        positive("mass", m)?;
+        positive("radius", r)?;
+
+        let j = 2.0 * m * r*r / 5.0;
+
+        Ok (Self::new(j, j, j, 0.0, 0.0, 0.0))
+    }
+
+    #[classmethod]
+    /// Construct the inertia tensor of a thin spherical shell about its centre,
+    /// given its mass and radius.
+    pub fn hollow_sphere(_cls: &Bound<'_, PyType>, m: f64, r: f64) -> PyResult<Self> {
+        positive("mass", m)?;
+        positive("radius", r)?;
+
+        let j = 2.0 * m * r*r / 3.0;
+
+        Ok (Self::new(j, j, j, 0.0, 0.0, 0.0))
+    }
+
+    #[classmethod]
+    /// Construct the inertia tensor of a point mass about the origin, given its mass
+    /// and position.
+    ///
+    /// Note that the inertia tensor of a point mass is singular, so it is not
+    /// physically realizable on its own.  It is intended to be combined with
+    /// other components.
+    pub fn point_mass(_cls: &Bound<'_, PyType>, m: f64, r: Vector) -> PyResult<Self> {
+        positive("mass", m)?;
+
+        Ok (Self::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0).shift(m, r))
+    }
+
+    /// Compute the principal moments of inertia and the principal axes of this tensor.
+    ///
+    /// Returns the principal moments in ascending order, together with the quaternion
+    /// that rotates from the principal frame to the body frame.  That is, this tensor
+    /// is equal to the diagonal tensor of principal moments rotated by the quaternion.
+    pub fn principal(&self) -> ((f64, f64, f64), Quaternion) {
+        let (moments, mut axes) = self.matrix().symmetric_eigen();
+
+        // Ensure the principal axes form a right-handed frame
+        let handedness = axes[(0, 0)] * (axes[(1, 1)] * axes[(2, 2)] - axes[(2, 1)] * axes[(1, 2)])
+            - axes[(0, 1)] * (axes[(1, 0)] * axes[(2, 2)] - axes[(2, 0)] * axes[(1, 2)])
+            + axes[(0, 2)] * (axes[(1, 0)] * axes[(2, 1)] - axes[(2, 0)] * axes[(1, 1)]);
+        if handedness < 0.0 {
+            for k in 0..3 {
+                axes[(k, 2)] = -axes[(k, 2)];
+            }
+        }
+
+        (
+            (moments[0], moments[1], moments[2]),
+            Quaternion::from_dcm(&axes),
+        )
+    }
+
+    /// Return the inverse of this inertia tensor.
+    ///
+    /// Raises `ValueError` if this inertia tensor is singular.
+    pub fn inverse(&self) -> PyResult<Self> {
+        if self.determinant() == 0.0 {
+            return Err (PyValueError::new_err("cannot invert a singular inertia tensor"));
+        }
+
+        Ok (self.invert())
+    }
+
+    /// Compute the determinant of this inertia tensor.
+    pub fn determinant(&self) -> f64 {
+        self.j1*(
+            self.j2 * self.j3 - self.j4.powi(2)
+        ) + self.j6*(
+            self.j4 * self.j5 - self.j3 * self.j6
+        ) + self.j5*(
+            self.j4 * self.j6 - self.j2 * self.j5
+        )
+    }
+
+    /// Rotate this inertia tensor by a given unit quaternion.
+    ///
+    /// If this tensor is expressed in frame A, and the quaternion rotates from
+    /// frame A to frame B, the returned tensor is expressed in frame B.
+    pub fn rotate(&self, q: Quaternion) -> Self {
+        let r = q.dcm();
+
+        Self::from_matrix(&(&(&r * &self.matrix()) * &r.transpose()))
+    }
+
+    /// Shift this inertia tensor from the centre of mass to a parallel set of axes
+    /// using the parallel-axis theorem.
+    ///
+    /// This tensor must be taken about the centre of mass of a body of the given mass.
+    /// The offset is the position of the centre of mass relative to the new point.
+    pub fn shift(&self, mass: f64, offset: Vector) -> Self {
+        let Vector { x, y, z } = offset;
+
         Self {
-            j1,
-            j2,
-            j3,
-            j4,
-            j5,
-            j6,
+            j1: self.j1 + mass * (y*y + z*z),
+            j2: self.j2 + mass * (x*x + z*z),
+            j3: self.j3 + mass * (x*x + y*y),
+            j4: self.j4 - mass * y*z,
+            j5: self.j5 - mass * x*z,
+            j6: self.j6 - mass * x*y,
         }
     }
 
+    /// Add two inertia tensors, taken about the same point and in the same frame.
+    fn __add__(&self, other: Self) -> Self {
+        *self + other
+    }
+
     /// Return a human-readable string for this inertia tensor.
     fn __str__(&self) -> String {
         format!(
@@ -75,7 +316,7 @@ impl Inertia {
     }
 
     /// Return a Pythonic representation of this inertia tensor.
-    fn __repr__(&self) -> String {
+    pub(crate) fn __repr__(&self) -> String {
         format!(
             "Inertia({}, {}, {}, {}, {}, {})",
             self.j1,
@@ -87,3 +328,117 @@ impl Inertia {
         )
     }
 }
+
+impl Inertia {
+    /// Construct a new inertia tensor, without checking that it is physically realizable.
+    pub fn new(j1: f64, j2: f64, j3: f64, j4: f64, j5: f64, j6: f64) -> Self {
+        Self {
+            j1,
+            j2,
+            j3,
+            j4,
+            j5,
+            j6,
+        }
+    }
+
+    /// Replace this inertia tensor by another, checking that it is physically realizable.
+    fn assign(&mut self, inertia: Self) -> PyResult<()> {
+        inertia.validate()?;
+        *self = inertia;
+
+        Ok (())
+    }
+
+    /// Return the inverse of this inertia tensor, without checking for singularity.
+    pub(crate) fn invert(&self) -> Self {
+        // Determinant of inertia matrix
+        let det = self.determinant();
+
+        Self {
+            j1: (self.j2*self.j3 - self.j4.powi(2)) / det,
+            j2: (self.j1*self.j3 - self.j5.powi(2)) / det,
+            j3: (self.j1*self.j2 - self.j6.powi(2)) / det,
+            j4: (self.j5*self.j6 - self.j1*self.j4) / det,
+            j5: (self.j4*self.j6 - self.j2*self.j5) / det,
+            j6: (self.j5*self.j4 - self.j3*self.j6) / det,
+        }
+    }
+
+    /// Return this inertia tensor as a 3x3 matrix.
+    pub(crate) fn matrix(&self) -> Matrix {
+        Matrix::from_rows(&[
+            vec![self.j1, self.j6, self.j5],
+            vec![self.j6, self.j2, self.j4],
+            vec![self.j5, self.j4, self.j3],
+        ]).unwrap()
+    }
+
+    /// Construct an inertia tensor from a symmetric 3x3 matrix.
+    pub(crate) fn from_matrix(m: &Matrix) -> Self {
+        let m = m.symmetrize();
+
+        Self::new(
+            m[(0, 0)],
+            m[(1, 1)],
+            m[(2, 2)],
+            m[(1, 2)],
+            m[(0, 2)],
+            m[(0, 1)],
+        )
+    }
+}
+
+impl Add<Inertia> for Inertia {
+    type Output = Inertia;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            j1: self.j1 + other.j1,
+            j2: self.j2 + other.j2,
+            j3: self.j3 + other.j3,
+            j4: self.j4 + other.j4,
+            j5: self.j5 + other.j5,
+            j6: self.j6 + other.j6,
+        }
+    }
+}
+
+/// Raise `ValueError` unless a named quantity is finite and positive.
+pub(crate) fn positive(name: &str, value: f64) -> PyResult<()> {
+    if value.is_finite() && value > 0.0 {
+        Ok (())
+    } else {
+        Err (PyValueError::new_err(format!(
+            "{} must be finite and positive, got {}",
+            name,
+            value,
+        )))
+    }
+}
+
+/// Raise `ValueError` unless a named quantity is positive, allowing infinity.
+pub(crate) fn positive_or_infinite(name: &str, value: f64) -> PyResult<()> {
+    if value > 0.0 {
+        Ok (())
+    } else {
+        Err (PyValueError::new_err(format!(
+            "{} must be positive or infinite, got {}",
+            name,
+            value,
+        )))
+    }
+}
+
+/// Raise `ValueError` unless a named quantity is finite and non-negative.
+pub(crate) fn non_negative(name: &str, value: f64) -> PyResult<()> {
+    if value.is_finite() && value >= 0.0 {
+        Ok (())
+    } else {
+        Err (PyValueError::new_err(format!(
+            "{} must be finite and non-negative, got {}",
+            name,
+            value,
+        )))
+    }
+}
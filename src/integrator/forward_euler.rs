@@ -3,55 +3,86 @@
 //!
 //! Forward Euler integrator.
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::{
     Integrator,
     State,
 };
+use crate::actuator::Firing;
+
+use super::{
+    advance,
+    force,
+    piecewise,
+};
 
 #[pyclass]
 /// Forward Euler integrator for rigid-body motion.
 pub struct ForwardEuler {
     // Time step.
     h: f64,
+
+    // Check for non-finite states after each step.
+    strict: bool,
 }
 
 #[pymethods]
 impl ForwardEuler {
     #[new]
+    #[pyo3(signature = (h, strict = false))]
     /// Construct a new forward Euler integrator.
-    pub fn new(h: f64) -> Self {
-        Self {
-            h,
+    ///
+    /// In strict mode, the integrator raises `FloatingPointError` as soon as a step
+    /// produces a non-finite state, instead of silently propagating NaN.
+    pub fn new(h: f64, strict: bool) -> PyResult<Self> {
+        if !(h.is_finite() && h > 0.0) {
+            return Err (PyValueError::new_err(format!(
+                "time step must be finite and positive, got {}",
+                h,
+            )));
         }
+
+        Ok (Self {
+            h,
+            strict,
+        })
     }
 
-    /// Integrate one step.
-    pub fn step(&self, state: State) -> State {
-        let (qdot, wdot, wddot) = self.dynamics(state);
+    #[pyo3(signature = (state, torque = None))]
+    /// Integrate one step, with an optional time-varying torque (body frame), such as a
+    /// thruster `Firing`, added to the state torque.
+    ///
+    /// A `Firing` is integrated exactly, by splitting the step at the start and end of
+    /// every pulse.  Any other time-varying torque is a callable `torque(time) -> Torque`,
+    /// evaluated at the start of the step.
+    pub fn step(&self, state: State, torque: Option<&Bound<'_, PyAny>>) -> PyResult<State> {
+        let newstate = match torque.map(|t| t.downcast::<Firing>()) {
+            Some (Ok (firing)) => piecewise(state, &firing.borrow(), self.h, |s, h| self.integrate(s, None, h))?,
+            _ => self.integrate(&state, torque, self.h)?,
+        };
 
-        // Construct new state
-        let mut newstate = state.clone();
-        newstate.quaternion = (state.quaternion + qdot.scale(self.h)).normalize();
-        newstate.angular_velocity = state.angular_velocity + wdot.scale(self.h);
-        if let Some (d) = state.damper {
-            // This is safe because we cloned the original state
-            let mut newd = newstate.damper.unwrap();
-
-            newd.angular_velocity = d.angular_velocity + wddot.scale(self.h);
-            newstate.damper = Some (newd);
+        if self.strict {
+            newstate.check_finite()?;
         }
-    
-        // Step time
-        newstate.time = state.time + self.h;
 
-        newstate
+        Ok (newstate)
+    }
+}
+
+impl ForwardEuler {
+    /// Integrate a state over a time interval, with an optional time-varying torque.
+    fn integrate(&self, state: &State, torque: Option<&Bound<'_, PyAny>>, h: f64) -> PyResult<State> {
+        let derivative = self.dynamics(&force(state.clone(), torque, state.time)?);
+
+        // Construct new state
+        advance(state, &derivative, h)
     }
 }
 
 impl Integrator for ForwardEuler {
-    fn step(&self, state: State) -> State {
-        self.step(state)
+    fn step(&self, state: State) -> PyResult<State> {
+        self.step(state, None)
     }
 }
@@ -6,22 +6,94 @@
 mod forward_euler;
 mod runge_kutta;
 
+use std::ops::Add;
+
+use pyo3::prelude::*;
+
 use crate::{
     AngularVelocity,
     Quaternion,
     State,
     Torque,
+    Vector,
 };
+use crate::actuator::Firing;
 
 pub use forward_euler::ForwardEuler;
 pub use runge_kutta::RungeKutta4;
 
+/// Time derivative of a state.
+#[derive(Clone, Debug)]
+pub struct Derivative {
+    /// Time derivative of attitude.
+    pub quaternion: Quaternion,
+
+    /// Angular acceleration (body frame) of the rigid body.
+    pub angular_velocity: AngularVelocity,
+
+    /// Angular acceleration of the damper.
+    pub damper: AngularVelocity,
+
+    /// Time derivative of the spin rate of the rotor.
+    pub rotor: f64,
+
+    /// Time derivatives of the modal displacement and velocity of each flexible mode.
+    pub modes: Vec<(f64, f64)>,
+
+    /// Time derivatives of the direction and rate of the slosh pendulum.
+    pub slosh: (Vector, Vector),
+
+    /// Time derivatives of the angle and rate of each joint of the articulated bodies.
+    pub joints: Vec<(f64, f64)>,
+}
+
+impl Derivative {
+    /// Scale this derivative by a scalar.
+    pub fn scale(&self, s: f64) -> Self {
+        Self {
+            quaternion: self.quaternion.scale(s),
+            angular_velocity: self.angular_velocity.scale(s),
+            damper: self.damper.scale(s),
+            rotor: self.rotor * s,
+            modes: self.modes.iter().map(|&(x, v)| (x * s, v * s)).collect(),
+            slosh: (self.slosh.0.scale(s), self.slosh.1.scale(s)),
+            joints: self.joints.iter().map(|&(x, v)| (x * s, v * s)).collect(),
+        }
+    }
+}
+
+impl Add<Derivative> for Derivative {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            quaternion: self.quaternion + other.quaternion,
+            angular_velocity: self.angular_velocity + other.angular_velocity,
+            damper: self.damper + other.damper,
+            rotor: self.rotor + other.rotor,
+            modes: self.modes.iter()
+                .zip(&other.modes)
+                .map(|(&(x1, v1), &(x2, v2))| (x1 + x2, v1 + v2))
+                .collect(),
+            slosh: (self.slosh.0 + other.slosh.0, self.slosh.1 + other.slosh.1),
+            joints: self.joints.iter()
+                .zip(&other.joints)
+                .map(|(&(x1, v1), &(x2, v2))| (x1 + x2, v1 + v2))
+                .collect(),
+        }
+    }
+}
+
 /// Numerical integrator for Ordinary Differential Equations (ODEs).
 pub trait Integrator {
-    /// Determine the time derivatives of both attitude and angular velocity for the
-    /// rigid body, and the time derivative of angular velocity for the simulated damper.
-    fn dynamics(&self, state: State) -> (Quaternion, AngularVelocity, AngularVelocity) {
+    /// Determine the time derivative of a state: that of attitude and angular velocity
+    /// for the rigid body, of angular velocity for the simulated damper, of the spin
+    /// rate of the rotor, of the coordinates of the flexible modes, of the direction and
+    /// rate of the slosh pendulum, and of the joint angles and rates of the articulated
+    /// bodies.
+    fn dynamics(&self, state: &State) -> Derivative {
         let (q, w) = (state.quaternion, state.angular_velocity);
+        let wv = Vector::new(w.x, w.y, w.z);
 
         // Applied torque
         let mut t = state.torque;
@@ -46,15 +118,175 @@ pub trait Integrator {
             AngularVelocity::new(0.0, 0.0, 0.0)
         };
 
+        // Momentum of the bus not carried by its effective inertia, and the torques of
+        // the rotor bearing, of the modal restoring forces and of the slosh pendulum on
+        // the bus
+        let mut h = Vector::new(0.0, 0.0, 0.0);
+        if let Some (r) = state.rotor {
+            let a = r.axis;
+            h = h + a.scale(r.inertia * (a.dot(wv) + r.spin));
+            t = t - Torque::new(a.x, a.y, a.z).scale(r.bearing_torque());
+        }
+        let restoring = state.flexible.as_ref().map(|f| f.restoring()).unwrap_or_default();
+        if let Some (f) = &state.flexible {
+            for (i, &delta) in f.coupling.iter().enumerate() {
+                h = h + delta.scale(delta.dot(wv) + f.velocities[i]);
+                t = t + Torque::new(delta.x, delta.y, delta.z).scale(-restoring[i]);
+            }
+        }
+        let mut slosh = Vector::new(0.0, 0.0, 0.0);
+        if let Some (p) = state.slosh {
+            let (e, u, l, mu) = (p.direction, p.rate, p.length, p.reduced_mass());
+            let r = p.position();
+            let k = p.lever();
+
+            // Force on the slosh mass normal to the rod, less that of angular acceleration
+            let apparent = p.acceleration.scale(-p.mass);
+            let g = apparent - (wv.cross(u).scale(2.0 * l) + wv.cross(wv.cross(r))).scale(mu);
+            slosh = g - e.scale(g.dot(e)) - u.scale(p.damping / l);
+
+            h = h + p.momentum_vector(w) - k.scale(mu * k.dot(wv));
+            let ts = r.cross(apparent)
+                - (u.cross(wv.cross(r)) + r.cross(wv.cross(u))).scale(mu * l)
+                - r.cross(slosh)
+                + r.cross(e).scale(mu * l * u.dot(u));
+            t = t + Torque::new(ts.x, ts.y, ts.z);
+        }
+        let gyro = wv.cross(h);
+        t = t - Torque::new(gyro.x, gyro.y, gyro.z);
+
         // Rigid-body orientation derivative
         let qdot = q.diff(w);
 
-        // Rigid-body velocity derivative
-        let wdot = w.diff(state.inertia, t);
+        // Rigid-body velocity and joint rate derivatives (if articulated bodies present)
+        let (wdot, jointdot) = match &state.multibody {
+            Some (m) => m.accelerations(state.effective_inertia(), w, t),
+            None => (w.diff(state.effective_inertia(), t), Vec::new()),
+        };
+        let wdotv = Vector::new(wdot.x, wdot.y, wdot.z);
+
+        // Rotor spin derivative (if rotor present)
+        let spindot = state.rotor.map_or(0.0, |r| r.bearing_torque() / r.inertia - r.axis.dot(wdotv));
+
+        // Modal coordinate derivatives (if flexible modes present)
+        let modes = state.flexible.as_ref().map(|f| {
+            f.coupling.iter()
+                .enumerate()
+                .map(|(i, delta)| (f.velocities[i], restoring[i] - delta.dot(wdotv)))
+                .collect()
+        }).unwrap_or_default();
+
+        // Slosh pendulum derivatives (if pendulum present)
+        let sloshdot = state.slosh.map_or((Vector::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0)), |p| {
+            let (e, u, mu) = (p.direction, p.rate, p.reduced_mass());
+            let f = slosh - wdotv.cross(p.position()).scale(mu);
+            let f = f - e.scale(f.dot(e));
 
-        (qdot, wdot, wddot)
+            (u, f.scale(1.0 / (mu * p.length)) - e.scale(u.dot(u)))
+        });
+
+        Derivative {
+            quaternion: qdot,
+            angular_velocity: wdot,
+            damper: wddot,
+            rotor: spindot,
+            modes,
+            slosh: sloshdot,
+            joints: state.multibody.as_ref().map(|m| {
+                m.rates.iter().copied().zip(jointdot).collect()
+            }).unwrap_or_default(),
+        }
     }
 
     /// Perform one integration step.
-    fn step(&self, state: State) -> State;
+    fn step(&self, state: State) -> PyResult<State>;
+}
+
+/// Advance a state along a derivative over a time interval.
+pub(crate) fn advance(state: &State, derivative: &Derivative, h: f64) -> PyResult<State> {
+    let mut newstate = state.clone();
+    newstate.quaternion = (state.quaternion + derivative.quaternion.scale(h)).normalize()?;
+    newstate.angular_velocity = state.angular_velocity + derivative.angular_velocity.scale(h);
+    if let Some (d) = &mut newstate.damper {
+        d.angular_velocity = d.angular_velocity + derivative.damper.scale(h);
+    }
+    if let Some (r) = &mut newstate.rotor {
+        r.spin += derivative.rotor * h;
+    }
+    if let Some (f) = &mut newstate.flexible {
+        for (i, &(x, v)) in derivative.modes.iter().enumerate() {
+            f.displacements[i] += x * h;
+            f.velocities[i] += v * h;
+        }
+    }
+    if let Some (p) = &mut newstate.slosh {
+        let (de, du) = derivative.slosh;
+        p.direction = (p.direction + de.scale(h)).normalize()?;
+
+        // Keep the rate normal to the direction
+        let u = p.rate + du.scale(h);
+        p.rate = u - p.direction.scale(u.dot(p.direction));
+    }
+    if let Some (m) = &mut newstate.multibody {
+        for (i, &(x, v)) in derivative.joints.iter().enumerate() {
+            m.angles[i] += x * h;
+            m.rates[i] += v * h;
+        }
+    }
+
+    // Step time
+    newstate.time = state.time + h;
+
+    Ok (newstate)
+}
+
+/// Integrate a state over a time interval under a thruster firing, given an integrator
+/// of the state over a sub-interval.
+///
+/// The interval is split at the start and end of every pulse, so that the torque of the
+/// firing is constant over each sub-interval and its impulse is delivered exactly,
+/// however the pulses fall relative to the integration stages.
+pub(crate) fn piecewise(
+    state: State,
+    firing: &Firing,
+    h: f64,
+    integrate: impl Fn(&State, f64) -> PyResult<State>,
+) -> PyResult<State> {
+    let (start, end) = (state.time, state.time + h);
+    let torque = state.torque;
+
+    let mut edges: Vec<f64> = firing.edges().filter(|&e| start < e && e < end).collect();
+    edges.push(end);
+    edges.sort_by(f64::total_cmp);
+    edges.dedup();
+
+    let mut state = state;
+    let mut a = start;
+    for b in edges {
+        state.torque = torque + firing.torque(0.5 * (a + b));
+        state = integrate(&state, b - a)?;
+        a = b;
+    }
+
+    // Restore the torque of the state, and the time without accumulated rounding
+    state.torque = torque;
+    state.time = end;
+
+    Ok (state)
+}
+
+/// Add a time-varying torque to the torque of a state, at a given integration stage.
+///
+/// The torque is given by any callable `torque(time) -> Torque`.
+pub(crate) fn force(state: State, torque: Option<&Bound<'_, PyAny>>, time: f64) -> PyResult<State> {
+    let Some (torque) = torque else {
+        return Ok (state);
+    };
+
+    let extra: Torque = torque.call1((time,))?.extract()?;
+
+    let mut forced = state;
+    forced.torque = forced.torque + extra;
+
+    Ok (forced)
 }
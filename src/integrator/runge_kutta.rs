@@ -3,100 +3,104 @@
 //!
 //! Runge-Kutta integrators.
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use crate::{
     Integrator,
     State,
 };
+use crate::actuator::Firing;
+
+use super::{
+    advance,
+    force,
+    piecewise,
+};
 
 #[pyclass]
 /// Fourth-order Runge-Kutta integrator for rigid-body motion.
 pub struct RungeKutta4 {
     // Time step.
     h: f64,
+
+    // Check for non-finite states after each step.
+    strict: bool,
 }
 
 #[pymethods]
 impl RungeKutta4 {
     #[new]
+    #[pyo3(signature = (h, strict = false))]
     /// Construct a new fourth-order Runge-Kutta integrator.
-    pub fn new(h: f64) -> Self {
-        Self {
+    ///
+    /// In strict mode, the integrator raises `FloatingPointError` as soon as a step
+    /// produces a non-finite state, instead of silently propagating NaN.
+    pub fn new(h: f64, strict: bool) -> PyResult<Self> {
+        if !(h.is_finite() && h > 0.0) {
+            return Err (PyValueError::new_err(format!(
+                "time step must be finite and positive, got {}",
+                h,
+            )));
+        }
+
+        Ok (Self {
             h,
+            strict,
+        })
+    }
+
+    #[pyo3(signature = (state, torque = None))]
+    /// Integrate one step, with an optional time-varying torque (body frame), such as a
+    /// thruster `Firing`, added to the state torque.
+    ///
+    /// A `Firing` is integrated exactly, by splitting the step at the start and end of
+    /// every pulse.  Any other time-varying torque is a callable `torque(time) -> Torque`,
+    /// evaluated at the time of every integration stage.
+    pub fn step(&self, state: State, torque: Option<&Bound<'_, PyAny>>) -> PyResult<State> {
+        let newstate = match torque.map(|t| t.downcast::<Firing>()) {
+            Some (Ok (firing)) => piecewise(state, &firing.borrow(), self.h, |s, h| self.integrate(s, None, h))?,
+            _ => self.integrate(&state, torque, self.h)?,
+        };
+
+        if self.strict {
+            newstate.check_finite()?;
         }
+
+        Ok (newstate)
     }
+}
+
+impl RungeKutta4 {
+    /// Integrate a state over a time interval, with an optional time-varying torque.
+    fn integrate(&self, state: &State, torque: Option<&Bound<'_, PyAny>>, h: f64) -> PyResult<State> {
+        let t = state.time;
 
-    /// Integrate one step.
-    pub fn step(&self, state: State) -> State {
         // First step
-        let (qdot1, wdot1, wddot1) = self.dynamics(state);
+        let d1 = self.dynamics(&force(state.clone(), torque, t)?);
 
         // Second step
-        let mut k2 = state.clone();
-        k2.quaternion = (state.quaternion + qdot1.scale(0.5 * self.h)).normalize();
-        k2.angular_velocity = state.angular_velocity + wdot1.scale(0.5 * self.h);
-        if let Some (d) = state.damper {
-            // This is safe because we cloned the original state
-            let mut newd = k2.damper.unwrap();
-
-            newd.angular_velocity = d.angular_velocity + wddot1.scale(0.5 * self.h);
-            k2.damper = Some (newd);
-        }
-        let (qdot2, wdot2, wddot2) = self.dynamics(k2);
+        let k2 = advance(state, &d1, 0.5 * h)?;
+        let d2 = self.dynamics(&force(k2, torque, t + 0.5 * h)?);
 
         // Third step
-        let mut k3 = state.clone();
-        k3.quaternion = (state.quaternion + qdot2.scale(0.5 * self.h)).normalize();
-        k3.angular_velocity = state.angular_velocity + wdot2.scale(0.5 * self.h);
-        if let Some (d) = state.damper {
-            // This is safe because we cloned the original state
-            let mut newd = k3.damper.unwrap();
-
-            newd.angular_velocity = d.angular_velocity + wddot2.scale(0.5 * self.h);
-            k3.damper = Some (newd);
-        }
-        let (qdot3, wdot3, wddot3) = self.dynamics(k3);
+        let k3 = advance(state, &d2, 0.5 * h)?;
+        let d3 = self.dynamics(&force(k3, torque, t + 0.5 * h)?);
 
         // Fourth step
-        let mut k4 = state.clone();
-        k4.quaternion = (state.quaternion + qdot3.scale(self.h)).normalize();
-        k4.angular_velocity = state.angular_velocity + wdot3.scale(self.h);
-        if let Some (d) = state.damper {
-            // This is safe because we cloned the original state
-            let mut newd = k4.damper.unwrap();
-
-            newd.angular_velocity = d.angular_velocity + wddot3.scale(self.h);
-            k4.damper = Some (newd);
-        }
-        let (qdot4, wdot4, wddot4) = self.dynamics(k4);
+        let k4 = advance(state, &d3, h)?;
+        let d4 = self.dynamics(&force(k4, torque, t + h)?);
 
         // Combine
-        let qdot = (qdot1 + qdot2.scale(2.0) + qdot3.scale(2.0) + qdot4).scale(1.0/6.0);
-        let wdot = (wdot1 + wdot2.scale(2.0) + wdot3.scale(2.0) + wdot4).scale(1.0/6.0);
-        let wddot = (wddot1 + wddot2.scale(2.0) + wddot3.scale(2.0) + wddot4).scale(1.0/6.0);
+        let derivative = (d1 + d2.scale(2.0) + d3.scale(2.0) + d4).scale(1.0/6.0);
 
         // Construct new state
-        let mut newstate = state.clone();
-        newstate.quaternion = (state.quaternion + qdot.scale(self.h)).normalize();
-        newstate.angular_velocity = state.angular_velocity + wdot.scale(self.h);
-        if let Some (d) = state.damper {
-            // This is safe because we cloned the original state
-            let mut newd = newstate.damper.unwrap();
-
-            newd.angular_velocity = d.angular_velocity + wddot.scale(self.h);
-            newstate.damper = Some (newd);
-        }
-
-        // Step time
-        newstate.time = state.time + self.h;
-
-        newstate
+        advance(state, &derivative, h)
     }
 }
 
 impl Integrator for RungeKutta4 {
-    fn step(&self, state: State) -> State {
-        self.step(state)
+    fn step(&self, state: State) -> PyResult<State> {
+        self.step(state, None)
     }
 }
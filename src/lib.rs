@@ -3,25 +3,69 @@
 //!
 //! A modern software package for blazingly fast simulation of rigid-body mechanics.
 
+mod actuator;
 mod angular_momentum;
 mod angular_velocity;
+mod calibration;
+mod control;
 mod damper;
+mod determination;
+mod estimation;
+mod flexible;
+mod guidance;
 mod inertia;
 mod integrator;
+mod linalg;
+mod mass_properties;
+mod measurement;
+mod multibody;
+mod optimize;
 mod quaternion;
+mod random;
+mod rotor;
+mod sensor;
+mod simulation;
+mod slosh;
 mod state;
 mod torque;
+mod vector;
 
 use pyo3::prelude::*;
 
 pub use angular_momentum::AngularMomentum;
 pub use angular_velocity::AngularVelocity;
 pub use damper::KaneDamper;
+pub use determination::AttitudeEstimate;
+pub use estimation::{
+    AttitudeEstimator,
+    Mekf,
+    Usque,
+};
+pub use flexible::FlexibleModes;
 pub use inertia::Inertia;
-pub use integrator::Integrator;
+pub use integrator::{
+    Derivative,
+    Integrator,
+};
+pub use mass_properties::MassProperties;
+pub use measurement::{
+    QuaternionMeasurement,
+    VectorMeasurement,
+};
+pub use multibody::{
+    Body,
+    Multibody,
+};
 pub use quaternion::Quaternion;
+pub use rotor::Rotor;
+pub use simulation::{
+    Simulation,
+    SimulationLog,
+};
+pub use slosh::SloshPendulum;
 pub use state::State;
 pub use torque::Torque;
+pub use vector::Vector;
 
 #[pymodule]
 /// Blazingly fast rigid-body mechanics simulation.
@@ -32,23 +76,99 @@ mod adcs {
     #[pymodule_export]
     use crate::AngularVelocity;
 
+    #[pymodule_export]
+    use crate::Body;
+
+    #[pymodule_export]
+    use crate::FlexibleModes;
+
     #[pymodule_export]
     use crate::KaneDamper;
 
     #[pymodule_export]
     use crate::Inertia;
 
+    #[pymodule_export]
+    use crate::MassProperties;
+
+    #[pymodule_export]
+    use crate::Multibody;
+
     #[pymodule_export]
     use crate::Quaternion;
 
+    #[pymodule_export]
+    use crate::QuaternionMeasurement;
+
+    #[pymodule_export]
+    use crate::Rotor;
+
+    #[pymodule_export]
+    use crate::Simulation;
+
+    #[pymodule_export]
+    use crate::SimulationLog;
+
+    #[pymodule_export]
+    use crate::SloshPendulum;
+
     #[pymodule_export]
     use crate::State;
 
     #[pymodule_export]
     use crate::Torque;
 
+    #[pymodule_export]
+    use crate::Vector;
+
+    #[pymodule_export]
+    use crate::VectorMeasurement;
+
+    #[pymodule_export]
+    use crate::actuators;
+
+    #[pymodule_export]
+    use crate::calibration_module;
+
+    #[pymodule_export]
+    use crate::control_module;
+
+    #[pymodule_export]
+    use crate::determination_module;
+
+    #[pymodule_export]
+    use crate::estimation_module;
+
+    #[pymodule_export]
+    use crate::guidance_module;
+
     #[pymodule_export]
     use crate::integrators;
+
+    #[pymodule_export]
+    use crate::sensors;
+}
+
+#[pymodule]
+#[pyo3(name = "guidance")]
+mod guidance_module {
+    #[pymodule_export]
+    use crate::guidance::InertialHold;
+
+    #[pymodule_export]
+    use crate::guidance::NadirPointing;
+
+    #[pymodule_export]
+    use crate::guidance::Reference;
+
+    #[pymodule_export]
+    use crate::guidance::Slew;
+
+    #[pymodule_export]
+    use crate::guidance::SunPointing;
+
+    #[pymodule_export]
+    use crate::guidance::TargetTracking;
 }
 
 #[pymodule]
@@ -59,3 +179,136 @@ mod integrators {
     #[pymodule_export]
     use crate::integrator::RungeKutta4;
 }
+
+#[pymodule]
+mod sensors {
+    #[pymodule_export]
+    use crate::sensor::CoarseSunSensor;
+
+    #[pymodule_export]
+    use crate::sensor::FineSunSensor;
+
+    #[pymodule_export]
+    use crate::sensor::Gyro;
+
+    #[pymodule_export]
+    use crate::sensor::HorizonSensor;
+
+    #[pymodule_export]
+    use crate::sensor::Magnetometer;
+
+    #[pymodule_export]
+    use crate::sensor::StarTracker;
+
+    #[pymodule_export]
+    use crate::sensor::StarTrackerReading;
+}
+
+#[pymodule]
+mod actuators {
+    #[pymodule_export]
+    use crate::actuator::ActuatorEffects;
+
+    #[pymodule_export]
+    use crate::actuator::Allocation;
+
+    #[pymodule_export]
+    use crate::actuator::Firing;
+
+    #[pymodule_export]
+    use crate::actuator::JointMotors;
+
+    #[pymodule_export]
+    use crate::actuator::Pwpf;
+
+    #[pymodule_export]
+    use crate::actuator::ReactionWheels;
+
+    #[pymodule_export]
+    use crate::actuator::Thruster;
+
+    #[pymodule_export]
+    use crate::actuator::ThrusterCluster;
+}
+
+#[pymodule]
+#[pyo3(name = "calibration")]
+mod calibration_module {
+    #[pymodule_export]
+    use crate::calibration::GyroCalibration;
+
+    #[pymodule_export]
+    use crate::calibration::MagnetometerCalibration;
+
+    #[pymodule_export]
+    use crate::calibration::gyro;
+
+    #[pymodule_export]
+    use crate::calibration::magnetometer;
+}
+
+#[pymodule]
+#[pyo3(name = "control")]
+mod control_module {
+    #[pymodule_export]
+    use crate::control::AdaptiveControl;
+
+    #[pymodule_export]
+    use crate::control::BCross;
+
+    #[pymodule_export]
+    use crate::control::BDot;
+
+    #[pymodule_export]
+    use crate::control::ControllerBase;
+
+    #[pymodule_export]
+    use crate::control::MomentumUnloading;
+
+    #[pymodule_export]
+    use crate::control::QuaternionPd;
+
+    #[pymodule_export]
+    use crate::control::SlidingMode;
+
+    #[pymodule_export]
+    use crate::control::StateFeedback;
+
+    #[pymodule_export]
+    use crate::control::StateSpace;
+
+    #[pymodule_export]
+    use crate::control::linearize;
+
+    #[pymodule_export]
+    use crate::control::lqr;
+}
+
+#[pymodule]
+#[pyo3(name = "determination")]
+mod determination_module {
+    #[pymodule_export]
+    use crate::AttitudeEstimate;
+
+    #[pymodule_export]
+    use crate::determination::esoq2::esoq2;
+
+    #[pymodule_export]
+    use crate::determination::q_method::q_method;
+
+    #[pymodule_export]
+    use crate::determination::quest::quest;
+
+    #[pymodule_export]
+    use crate::determination::triad::triad;
+}
+
+#[pymodule]
+#[pyo3(name = "estimation")]
+mod estimation_module {
+    #[pymodule_export]
+    use crate::Mekf;
+
+    #[pymodule_export]
+    use crate::Usque;
+}
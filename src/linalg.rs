@@ -0,0 +1,397 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Dense linear algebra helpers.
+//!
+//! This module is internal to the package.  It provides just enough matrix
+//! arithmetic to support the estimation, control and structural models built
+//! on top of the rigid-body types, without pulling in an external dependency.
+
+use std::ops::{
+    Add,
+    Index,
+    IndexMut,
+    Mul,
+    Sub,
+};
+
+/// Convergence threshold for iterative decompositions.
+const TOLERANCE: f64 = 1e-14;
+
+/// Maximum number of Jacobi sweeps before giving up.
+const MAX_SWEEPS: usize = 100;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Dense, row-major matrix of real numbers.
+pub(crate) struct Matrix {
+    /// Number of rows.
+    pub rows: usize,
+
+    /// Number of columns.
+    pub cols: usize,
+
+    /// Row-major entries.
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// Construct a matrix of zeros.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    /// Construct the identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = 1.0;
+        }
+        m
+    }
+
+    /// Construct a diagonal matrix.
+    pub fn diagonal(d: &[f64]) -> Self {
+        let mut m = Self::zeros(d.len(), d.len());
+        for (i, v) in d.iter().enumerate() {
+            m[(i, i)] = *v;
+        }
+        m
+    }
+
+    /// Construct a column vector.
+    pub fn column(v: &[f64]) -> Self {
+        Self {
+            rows: v.len(),
+            cols: 1,
+            data: v.to_vec(),
+        }
+    }
+
+    /// Construct a matrix from a list of rows.
+    ///
+    /// Returns `None` if the rows are not all of equal length.
+    pub fn from_rows(rows: &[Vec<f64>]) -> Option<Self> {
+        let cols = rows.first().map(|r| r.len()).unwrap_or(0);
+        if rows.iter().any(|r| r.len() != cols) {
+            return None;
+        }
+
+        Some (Self {
+            rows: rows.len(),
+            cols,
+            data: rows.iter().flatten().copied().collect(),
+        })
+    }
+
+    /// Convert this matrix to a list of rows.
+    pub fn to_rows(&self) -> Vec<Vec<f64>> {
+        self.data.chunks(self.cols.max(1)).take(self.rows).map(|r| r.to_vec()).collect()
+    }
+
+    /// Construct the skew-symmetric cross-product matrix of a 3-vector.
+    pub fn skew(x: f64, y: f64, z: f64) -> Self {
+        Self {
+            rows: 3,
+            cols: 3,
+            data: vec![
+                0.0, -z, y,
+                z, 0.0, -x,
+                -y, x, 0.0,
+            ],
+        }
+    }
+
+    /// Return the transpose of this matrix.
+    pub fn transpose(&self) -> Self {
+        let mut t = Self::zeros(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t[(j, i)] = self[(i, j)];
+            }
+        }
+        t
+    }
+
+    /// Scale this matrix by a given scalar.
+    pub fn scale(&self, s: f64) -> Self {
+        Self {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|v| s * v).collect(),
+        }
+    }
+
+    /// Return the trace of this (square) matrix.
+    pub fn trace(&self) -> f64 {
+        (0..self.rows.min(self.cols)).map(|i| self[(i, i)]).sum()
+    }
+
+    /// Return the symmetric part of this (square) matrix.
+    pub fn symmetrize(&self) -> Self {
+        (self + &self.transpose()).scale(0.5)
+    }
+
+    /// Extract a block of this matrix.
+    pub fn block(&self, row: usize, col: usize, rows: usize, cols: usize) -> Self {
+        let mut b = Self::zeros(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                b[(i, j)] = self[(row + i, col + j)];
+            }
+        }
+        b
+    }
+
+    /// Overwrite a block of this matrix.
+    pub fn set_block(&mut self, row: usize, col: usize, b: &Self) {
+        for i in 0..b.rows {
+            for j in 0..b.cols {
+                self[(row + i, col + j)] = b[(i, j)];
+            }
+        }
+    }
+
+    /// Return the Frobenius norm of this matrix.
+    pub fn norm(&self) -> f64 {
+        self.data.iter().map(|v| v * v).sum::<f64>().sqrt()
+    }
+
+    /// Return the inverse of this (square) matrix, using Gauss-Jordan
+    /// elimination with partial pivoting.
+    ///
+    /// Returns `None` if the matrix is singular to working precision.
+    pub fn inverse(&self) -> Option<Self> {
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut inv = Self::identity(n);
+        let scale = self.norm().max(f64::MIN_POSITIVE);
+
+        for c in 0..n {
+            // Select pivot
+            let p = (c..n).max_by(|&i, &j| a[(i, c)].abs().total_cmp(&a[(j, c)].abs()))?;
+            if a[(p, c)].abs() <= 1e-13 * scale {
+                return None;
+            }
+            a.swap_rows(c, p);
+            inv.swap_rows(c, p);
+
+            // Normalize pivot row
+            let d = a[(c, c)];
+            for j in 0..n {
+                a[(c, j)] /= d;
+                inv[(c, j)] /= d;
+            }
+
+            // Eliminate column
+            for i in 0..n {
+                if i != c {
+                    let f = a[(i, c)];
+                    if f != 0.0 {
+                        for j in 0..n {
+                            a[(i, j)] -= f * a[(c, j)];
+                            inv[(i, j)] -= f * inv[(c, j)];
+                        }
+                    }
+                }
+            }
+        }
+
+        Some (inv)
+    }
+
+    /// Return the Moore-Penrose pseudo-inverse of this matrix.
+    ///
+    /// The pseudo-inverse is computed from the eigen-decomposition of the
+    /// Gram matrix, discarding singular values below a relative threshold.
+    pub fn pseudo_inverse(&self) -> Self {
+        let wide = self.cols > self.rows;
+        let a = if wide { self.transpose() } else { self.clone() };
+
+        // A+ = V diag(1/s^2) V^T A^T for a tall matrix A
+        let (s2, v) = (&a.transpose() * &a).symmetric_eigen();
+        let smax = s2.iter().copied().fold(0.0, f64::max);
+        let inv: Vec<f64> = s2.iter().map(|s| if *s > 1e-12 * smax { 1.0 / s } else { 0.0 }).collect();
+        let pinv = &(&(&v * &Self::diagonal(&inv)) * &v.transpose()) * &a.transpose();
+
+        if wide { pinv.transpose() } else { pinv }
+    }
+
+    /// Compute the lower-triangular Cholesky factor `L` of this (symmetric positive
+    /// definite) matrix, such that `L @ L.T` is equal to this matrix.
+    ///
+    /// Returns `None` if the matrix is not positive definite.
+    pub fn cholesky(&self) -> Option<Self> {
+        let n = self.rows;
+        let mut l = Self::zeros(n, n);
+
+        for j in 0..n {
+            let d = self[(j, j)] - (0..j).map(|k| l[(j, k)].powi(2)).sum::<f64>();
+            if d.is_nan() || d <= 0.0 {
+                return None;
+            }
+            l[(j, j)] = d.sqrt();
+
+            for i in (j + 1)..n {
+                let s = self[(i, j)] - (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum::<f64>();
+                l[(i, j)] = s / l[(j, j)];
+            }
+        }
+
+        Some (l)
+    }
+
+    /// Compute the eigen-decomposition of this (symmetric) matrix using the
+    /// cyclic Jacobi method.
+    ///
+    /// Returns the eigenvalues in ascending order, together with a matrix whose
+    /// columns are the corresponding unit eigenvectors.
+    pub fn symmetric_eigen(&self) -> (Vec<f64>, Self) {
+        let n = self.rows;
+        let mut a = self.symmetrize();
+        let mut v = Self::identity(n);
+
+        for _ in 0..MAX_SWEEPS {
+            let off: f64 = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[(i, j)].powi(2))
+                .sum();
+            if off <= TOLERANCE * TOLERANCE * a.norm().powi(2).max(f64::MIN_POSITIVE) {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[(p, q)] == 0.0 {
+                        continue;
+                    }
+
+                    // Jacobi rotation annihilating a[p, q]
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = (t * t + 1.0).sqrt().recip();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        // Sort ascending
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+        let values = order.iter().map(|&i| a[(i, i)]).collect();
+        let mut vectors = Self::zeros(n, n);
+        for (c, &i) in order.iter().enumerate() {
+            for k in 0..n {
+                vectors[(k, c)] = v[(k, i)];
+            }
+        }
+
+        (values, vectors)
+    }
+
+    /// Compute the exponential of this (square) matrix, by scaling and squaring a
+    /// truncated Taylor series.
+    pub fn exp(&self) -> Self {
+        // Scale so that the norm is at most one half
+        let squarings = self.norm().max(0.5).log2().ceil() as i32 + 1;
+        let a = self.scale(0.5_f64.powi(squarings));
+
+        let mut term = Self::identity(self.rows);
+        let mut sum = term.clone();
+        for k in 1..=16 {
+            term = (&term * &a).scale(1.0 / k as f64);
+            sum = &sum + &term;
+        }
+
+        for _ in 0..squarings {
+            sum = &sum * &sum;
+        }
+
+        sum
+    }
+
+    /// Swap two rows of this matrix.
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        if i != j {
+            for k in 0..self.cols {
+                self.data.swap(i * self.cols + k, j * self.cols + k);
+            }
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl Add<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn add(self, other: &Matrix) -> Self::Output {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().zip(&other.data).map(|(a, b)| a + b).collect(),
+        }
+    }
+}
+
+impl Sub<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn sub(self, other: &Matrix) -> Self::Output {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().zip(&other.data).map(|(a, b)| a - b).collect(),
+        }
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: &Matrix) -> Self::Output {
+        let mut m = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a != 0.0 {
+                    for j in 0..other.cols {
+                        m[(i, j)] += a * other[(k, j)];
+                    }
+                }
+            }
+        }
+        m
+    }
+}
@@ -0,0 +1,120 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Mass properties of composite bodies.
+
+use std::ops::Add;
+
+use pyo3::prelude::*;
+
+use crate::{
+    Inertia,
+    Quaternion,
+    Vector,
+};
+use crate::inertia::positive;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Mass properties of a rigid body.
+///
+/// Mass properties consist of the total mass, the position of the centre of mass,
+/// and the inertia tensor taken about the centre of mass.  All quantities are
+/// given in the _body frame_.
+///
+/// Mass properties of individual parts can be added together to assemble the
+/// mass properties of a composite spacecraft.
+pub struct MassProperties {
+    #[pyo3(get)]
+    /// Total mass.
+    pub mass: f64,
+
+    #[pyo3(get)]
+    /// Position of the centre of mass.
+    pub center_of_mass: Vector,
+
+    #[pyo3(get)]
+    /// Inertia tensor about the centre of mass.
+    pub inertia: Inertia,
+}
+
+#[pymethods]
+impl MassProperties {
+    #[new]
+    #[pyo3(signature = (mass, inertia, center_of_mass = None, orientation = None))]
+    /// Construct the mass properties of a part, given its mass and its inertia tensor
+    /// about its own centre of mass.
+    ///
+    /// The part is placed with its centre of mass at the given position (by default,
+    /// the origin).  If an orientation is given, the inertia tensor is taken to be
+    /// expressed in the part frame, and the orientation is the quaternion rotating
+    /// from the part frame to the body frame.
+    ///
+    /// Raises `ValueError` if the mass is not positive.
+    pub fn new(
+        mass: f64,
+        inertia: Inertia,
+        center_of_mass: Option<Vector>,
+        orientation: Option<Quaternion>,
+    ) -> PyResult<Self> {
+        positive("mass", mass)?;
+
+        let inertia = match orientation {
+            Some (q) => inertia.rotate(q),
+            None => inertia,
+        };
+
+        Ok (Self {
+            mass,
+            center_of_mass: center_of_mass.unwrap_or(Vector::new(0.0, 0.0, 0.0)),
+            inertia,
+        })
+    }
+
+    #[staticmethod]
+    /// Combine the mass properties of several parts into those of the assembly.
+    ///
+    /// Returns `None` if no parts are given.
+    pub fn combine(parts: Vec<Self>) -> Option<Self> {
+        parts.into_iter().reduce(|a, b| a + b)
+    }
+
+    /// Return the inertia tensor of this body about an arbitrary point.
+    pub fn inertia_about(&self, point: Vector) -> Inertia {
+        self.inertia.shift(self.mass, self.center_of_mass - point)
+    }
+
+    /// Combine the mass properties of two parts.
+    fn __add__(&self, other: Self) -> Self {
+        *self + other
+    }
+
+    /// Return a Pythonic representation of these mass properties.
+    fn __repr__(&self) -> String {
+        format!(
+            "MassProperties({}, {}, {})",
+            self.mass,
+            self.inertia.__repr__(),
+            self.center_of_mass.__repr__(),
+        )
+    }
+}
+
+impl Add<MassProperties> for MassProperties {
+    type Output = MassProperties;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mass = self.mass + other.mass;
+
+        // Mass-weighted centre of mass
+        let center_of_mass = (
+            self.center_of_mass.scale(self.mass) + other.center_of_mass.scale(other.mass)
+        ).scale(mass.powi(-1));
+
+        Self {
+            mass,
+            center_of_mass,
+            inertia: self.inertia_about(center_of_mass) + other.inertia_about(center_of_mass),
+        }
+    }
+}
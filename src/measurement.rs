@@ -0,0 +1,142 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Measurement types.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+use crate::{
+    Quaternion,
+    Vector,
+};
+use crate::inertia::positive;
+use crate::linalg::Matrix;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Vector measurement.
+///
+/// A vector measurement pairs a unit vector measured in the _body frame_, such as
+/// the direction of the sun or of the magnetic field, with the same direction known
+/// in the _reference (inertial) frame_.  The measurement noise is described by the
+/// standard deviation of the angular error of the body vector, in radians.
+pub struct VectorMeasurement {
+    #[pyo3(get)]
+    /// Measured unit vector (body frame).
+    pub body: Vector,
+
+    #[pyo3(get)]
+    /// Known unit vector (reference frame).
+    pub reference: Vector,
+
+    #[pyo3(get)]
+    /// Angular standard deviation of the measurement (radians).
+    pub sigma: f64,
+}
+
+#[pymethods]
+impl VectorMeasurement {
+    #[new]
+    /// Construct a new vector measurement.
+    ///
+    /// Both vectors are normalized automatically.  Raises `ValueError` if either vector
+    /// is zero or if the standard deviation is not positive.
+    pub fn new(body: Vector, reference: Vector, sigma: f64) -> PyResult<Self> {
+        positive("measurement standard deviation", sigma)?;
+
+        Ok (Self {
+            body: body.normalize()?,
+            reference: reference.normalize()?,
+            sigma,
+        })
+    }
+
+    /// Return the weight (inverse variance) of this measurement.
+    pub fn weight(&self) -> f64 {
+        self.sigma.powi(-2)
+    }
+
+    /// Return a Pythonic representation of this measurement.
+    fn __repr__(&self) -> String {
+        format!(
+            "VectorMeasurement({}, {}, {})",
+            self.body.__repr__(),
+            self.reference.__repr__(),
+            self.sigma,
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Quaternion measurement.
+///
+/// A quaternion measurement is a direct measurement of attitude, such as the output of
+/// a star tracker.  The measured quaternion is the true attitude perturbed by a small
+/// rotation, whose angles (in the _body frame_, in radians) have the given covariance.
+pub struct QuaternionMeasurement {
+    #[pyo3(get)]
+    /// Measured attitude (rotation from body frame to reference frame).
+    pub quaternion: Quaternion,
+
+    #[pyo3(get)]
+    /// Covariance of the measurement error angles (body frame), in square radians.
+    pub covariance: [[f64; 3]; 3],
+}
+
+#[pymethods]
+impl QuaternionMeasurement {
+    #[new]
+    /// Construct a new quaternion measurement, given its error covariance.
+    ///
+    /// The quaternion is normalized automatically.  Raises `ValueError` if the quaternion
+    /// is zero or if the covariance is not symmetric positive definite.
+    pub fn new(quaternion: Quaternion, covariance: [[f64; 3]; 3]) -> PyResult<Self> {
+        let measurement = Self {
+            quaternion: quaternion.normalize()?,
+            covariance,
+        };
+
+        let p = measurement.covariance_matrix();
+        let (values, _) = p.symmetric_eigen();
+        if (&p - &p.transpose()).norm() > 1e-12 * p.norm() || values[0].is_nan() || values[0] <= 0.0 {
+            return Err (PyValueError::new_err(
+                "measurement covariance must be symmetric positive definite"
+            ));
+        }
+
+        Ok (measurement)
+    }
+
+    #[classmethod]
+    /// Construct a new quaternion measurement with equal error on every axis, given the
+    /// angular standard deviation of the error (radians).
+    pub fn isotropic(_cls: &Bound<'_, PyType>, quaternion: Quaternion, sigma: f64) -> PyResult<Self> {
+        positive("measurement standard deviation", sigma)?;
+
+        let s2 = sigma.powi(2);
+        Self::new(quaternion, [
+            [s2, 0.0, 0.0],
+            [0.0, s2, 0.0],
+            [0.0, 0.0, s2],
+        ])
+    }
+
+    /// Return a Pythonic representation of this measurement.
+    pub(crate) fn __repr__(&self) -> String {
+        format!(
+            "QuaternionMeasurement({}, {:?})",
+            self.quaternion.__repr__(),
+            self.covariance,
+        )
+    }
+}
+
+impl QuaternionMeasurement {
+    /// Return the measurement covariance as a matrix.
+    pub(crate) fn covariance_matrix(&self) -> Matrix {
+        Matrix::from_rows(&self.covariance.map(|r| r.to_vec())).unwrap()
+    }
+}
@@ -0,0 +1,470 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Articulated multibody spacecraft.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    Quaternion,
+    Torque,
+    Vector,
+};
+use crate::inertia::positive;
+use crate::linalg::Matrix;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Body of an articulated spacecraft, such as a gimbaled antenna or a link of a robotic
+/// arm, attached to its parent by a revolute joint.
+///
+/// The parent is the bus or another body.  The body frame coincides with the frame of
+/// the parent when the joint angle is zero, and rotates about the joint axis, which is
+/// fixed in both frames, as the joint angle increases.
+pub struct Body {
+    #[pyo3(get)]
+    /// Mass of the body.
+    pub mass: f64,
+
+    #[pyo3(get)]
+    /// Inertia tensor of the body about its centre of mass (body frame).
+    pub inertia: Inertia,
+
+    #[pyo3(get)]
+    /// Position of the joint (parent frame), relative to the centre of mass of the
+    /// parent.
+    pub hinge: Vector,
+
+    #[pyo3(get)]
+    /// Unit axis of the joint (parent frame).
+    pub axis: Vector,
+
+    #[pyo3(get)]
+    /// Position of the centre of mass of the body (body frame), relative to the joint.
+    pub offset: Vector,
+
+    #[pyo3(get)]
+    /// Index of the parent body in the tree, or `None` if the parent is the bus.
+    pub parent: Option<usize>,
+}
+
+#[pymethods]
+impl Body {
+    #[new]
+    #[pyo3(signature = (mass, inertia, hinge, axis, offset = None, parent = None))]
+    /// Construct a new body, given its mass and inertia tensor about its centre of mass,
+    /// the position of its joint relative to the centre of mass of its parent, the axis
+    /// of its joint, the position of its centre of mass relative to the joint (by
+    /// default, at the joint), and the index of its parent (by default, the bus).
+    ///
+    /// The axis is normalized automatically.  Raises `ValueError` if the mass is not
+    /// positive, if the inertia tensor is not physically realizable, or if the axis is
+    /// zero.
+    pub fn new(
+        mass: f64,
+        inertia: Inertia,
+        hinge: Vector,
+        axis: Vector,
+        offset: Option<Vector>,
+        parent: Option<usize>,
+    ) -> PyResult<Self> {
+        positive("body mass", mass)?;
+        inertia.validate()?;
+
+        Ok (Self {
+            mass,
+            inertia,
+            hinge,
+            axis: axis.normalize()?,
+            offset: offset.unwrap_or(Vector::new(0.0, 0.0, 0.0)),
+            parent,
+        })
+    }
+
+    /// Return a Pythonic representation of this body.
+    fn __repr__(&self) -> String {
+        format!(
+            "Body({}, {}, {}, {}, {}, {})",
+            self.mass,
+            self.inertia.__repr__(),
+            self.hinge.__repr__(),
+            self.axis.__repr__(),
+            self.offset.__repr__(),
+            self.parent.map_or("None".to_string(), |p| p.to_string()),
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Tree of bodies articulated by revolute joints, rooted at the bus.
+///
+/// Each body has one joint, which connects it to its parent, so joint `i` is the joint
+/// of body `i`.  Parents precede their children in the tree.  The joints are driven by
+/// motor torques, which act between each body and its parent.
+///
+/// The inertia tensor of the state is that of the bus about its own centre of mass,
+/// excluding the bodies, and the attitude and angular velocity of the state are those
+/// of the bus.  The spacecraft is free of external forces, so its centre of mass is
+/// at rest while the bodies move; the dynamics follow by Kane's method.
+pub struct Multibody {
+    #[pyo3(get)]
+    /// Mass of the bus, excluding the bodies.
+    pub bus_mass: f64,
+
+    #[pyo3(get)]
+    /// Bodies of the tree.
+    pub bodies: Vec<Body>,
+
+    #[pyo3(get)]
+    /// Joint angles (rad).
+    pub angles: Vec<f64>,
+
+    #[pyo3(get)]
+    /// Joint rates (rad/s).
+    pub rates: Vec<f64>,
+
+    #[pyo3(get)]
+    /// Joint motor torques, applied to each body about its joint axis.
+    pub torques: Vec<f64>,
+}
+
+#[pymethods]
+impl Multibody {
+    #[new]
+    /// Construct a new articulated spacecraft, given the mass of the bus and the bodies
+    /// of the tree.  The joints are initially at zero angle, at rest, and unpowered.
+    ///
+    /// Raises `ValueError` if the mass of the bus is not positive, or if the parent of
+    /// any body does not precede it in the tree.
+    pub fn new(bus_mass: f64, bodies: Vec<Body>) -> PyResult<Self> {
+        let n = bodies.len();
+        let multibody = Self {
+            bus_mass,
+            bodies,
+            angles: vec![0.0; n],
+            rates: vec![0.0; n],
+            torques: vec![0.0; n],
+        };
+        multibody.validate()?;
+
+        Ok (multibody)
+    }
+
+    #[setter]
+    /// Set the joint angles, checking that there is one per joint.
+    fn set_angles(&mut self, angles: Vec<f64>) -> PyResult<()> {
+        self.check("joint angles", &angles)?;
+        self.angles = angles;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the joint rates, checking that there is one per joint.
+    fn set_rates(&mut self, rates: Vec<f64>) -> PyResult<()> {
+        self.check("joint rates", &rates)?;
+        self.rates = rates;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the joint motor torques, checking that there is one per joint.
+    pub fn set_torques(&mut self, torques: Vec<f64>) -> PyResult<()> {
+        self.check("joint torques", &torques)?;
+        self.torques = torques;
+
+        Ok (())
+    }
+
+    /// Check that this tree is physically meaningful.
+    ///
+    /// Raises `ValueError` if the mass of the bus is not positive, if the parent of any
+    /// body does not precede it in the tree, or if the numbers of joint angles, rates
+    /// and torques differ from the number of bodies.
+    pub fn validate(&self) -> PyResult<()> {
+        positive("bus mass", self.bus_mass)?;
+        for (i, body) in self.bodies.iter().enumerate() {
+            positive("body mass", body.mass)?;
+            if body.parent.is_some_and(|p| p >= i) {
+                return Err (PyValueError::new_err(format!(
+                    "parent of body {} must precede it in the tree",
+                    i,
+                )));
+            }
+        }
+
+        self.check("joint angles", &self.angles)?;
+        self.check("joint rates", &self.rates)?;
+        self.check("joint torques", &self.torques)?;
+
+        Ok (())
+    }
+
+    /// Compute the attitude of each body (rotation from body frame to bus frame).
+    pub fn orientations(&self) -> Vec<Quaternion> {
+        self.links().iter().skip(1).map(|l| l.orientation).collect()
+    }
+
+    /// Compute the position (bus frame) of the centre of mass of each body, relative to
+    /// the centre of mass of the bus.
+    pub fn positions(&self) -> Vec<Vector> {
+        self.links().iter().skip(1).map(|l| l.position).collect()
+    }
+
+    /// Compute the position (bus frame) of the centre of mass of the spacecraft,
+    /// relative to the centre of mass of the bus.
+    pub fn center_of_mass(&self) -> Vector {
+        let mass = self.bus_mass + self.bodies.iter().map(|b| b.mass).sum::<f64>();
+
+        self.bodies.iter()
+            .zip(self.positions())
+            .fold(Vector::new(0.0, 0.0, 0.0), |c, (b, p)| c + p.scale(b.mass / mass))
+    }
+
+    /// Compute the angular momentum (bus frame) of the bodies about the centre of mass
+    /// of the spacecraft, given the angular velocity of the bus.
+    pub fn momentum(&self, angular_velocity: AngularVelocity) -> AngularMomentum {
+        let w = Vector::new(angular_velocity.x, angular_velocity.y, angular_velocity.z);
+        let links = self.links();
+        let v0 = self.bus_velocity(&links, w);
+
+        let h = self.bodies.iter().zip(links.iter().skip(1)).fold(Vector::new(0.0, 0.0, 0.0), |h, (b, l)| {
+            let inertia = b.inertia.rotate(l.orientation);
+            let v = v0 + w.cross(l.position) + l.velocity;
+            h + product(inertia, w + l.rate) + l.position.cross(v).scale(b.mass)
+        });
+
+        AngularMomentum::new(h.x, h.y, h.z)
+    }
+
+    /// Return the number of joints.
+    fn __len__(&self) -> usize {
+        self.len()
+    }
+
+    /// Return a Pythonic representation of this tree.
+    fn __repr__(&self) -> String {
+        format!(
+            "Multibody({}, [{}])",
+            self.bus_mass,
+            self.bodies.iter().map(Body::__repr__).collect::<Vec<_>>().join(", "),
+        )
+    }
+}
+
+/// Kinematics of a body relative to the bus (bus frame), given the joint angles and
+/// rates, and the acceleration of the body at zero joint acceleration.
+#[derive(Clone, Debug)]
+struct Link {
+    /// Rotation from body frame to bus frame.
+    orientation: Quaternion,
+
+    /// Position of the centre of mass.
+    position: Vector,
+
+    /// Unit axis of the joint.
+    axis: Vector,
+
+    /// Position of the joint.
+    hinge: Vector,
+
+    /// Angular velocity relative to the bus.
+    rate: Vector,
+
+    /// Velocity of the centre of mass relative to the bus.
+    velocity: Vector,
+
+    /// Angular acceleration relative to the bus, at zero joint acceleration.
+    angular_acceleration: Vector,
+
+    /// Acceleration of the centre of mass relative to the bus, at zero joint
+    /// acceleration.
+    acceleration: Vector,
+
+    /// Joints from the bus to the body, inclusive.
+    chain: Vec<usize>,
+}
+
+impl Multibody {
+    /// Return the number of joints.
+    pub fn len(&self) -> usize {
+        self.bodies.len()
+    }
+
+    /// Determine whether there are no joints.
+    pub fn is_empty(&self) -> bool {
+        self.bodies.is_empty()
+    }
+
+    /// Compute the angular acceleration of the bus and the joint accelerations, given
+    /// the inertia tensor and angular velocity of the bus and the torque (body frame)
+    /// applied to the bus.
+    ///
+    /// The generalized speeds are the velocity of the bus, its angular velocity and the
+    /// joint rates.  The velocity of the bus is that which holds the centre of mass of
+    /// the spacecraft at rest.
+    pub(crate) fn accelerations(&self, inertia: Inertia, angular_velocity: AngularVelocity, torque: Torque) -> (AngularVelocity, Vec<f64>) {
+        let n = 6 + self.len();
+        let w = Vector::new(angular_velocity.x, angular_velocity.y, angular_velocity.z);
+        let links = self.links();
+        let v0 = self.bus_velocity(&links, w);
+        let zero = Vector::new(0.0, 0.0, 0.0);
+
+        // Generalized active forces
+        let mut mass = Matrix::zeros(n, n);
+        let mut rhs = Matrix::zeros(n, 1);
+        for (k, t) in [torque.x, torque.y, torque.z].into_iter().enumerate() {
+            rhs[(3 + k, 0)] = t;
+        }
+        for (j, t) in self.torques.iter().enumerate() {
+            rhs[(6 + j, 0)] = *t;
+        }
+
+        let bus = (self.bus_mass, inertia);
+        let bodies = self.bodies.iter().map(|b| (b.mass, b.inertia));
+        for ((m, inertia), link) in std::iter::once(bus).chain(bodies).zip(&links) {
+            let inertia = inertia.rotate(link.orientation);
+            let p = link.position;
+
+            // Partial velocities and partial angular velocities
+            let mut jv = vec![zero; n];
+            let mut jw = vec![zero; n];
+            for k in 0..3 {
+                let mut e = [0.0; 3];
+                e[k] = 1.0;
+                let e = Vector::new(e[0], e[1], e[2]);
+                jv[k] = e;
+                jv[3 + k] = e.cross(p);
+                jw[3 + k] = e;
+            }
+            for &j in &link.chain {
+                let joint = &links[j + 1];
+                jv[6 + j] = joint.axis.cross(p - joint.hinge);
+                jw[6 + j] = joint.axis;
+            }
+
+            // Inertial accelerations at zero generalized acceleration
+            let a = w.cross(v0) + w.cross(w.cross(p)) + w.cross(link.velocity).scale(2.0) + link.acceleration;
+            let wi = w + link.rate;
+            let alpha = link.angular_acceleration + w.cross(link.rate);
+            let force = a.scale(m);
+            let moment = product(inertia, alpha) + wi.cross(product(inertia, wi));
+
+            // Generalized inertia forces
+            for k in 0..n {
+                let jwk = product(inertia, jw[k]);
+                for l in 0..n {
+                    mass[(k, l)] += m * jv[k].dot(jv[l]) + jwk.dot(jw[l]);
+                }
+                rhs[(k, 0)] -= jv[k].dot(force) + jw[k].dot(moment);
+            }
+        }
+
+        let Some (inverse) = mass.inverse() else {
+            return (AngularVelocity::new(f64::NAN, f64::NAN, f64::NAN), vec![f64::NAN; self.len()]);
+        };
+        let udot = &inverse * &rhs;
+
+        (
+            AngularVelocity::new(udot[(3, 0)], udot[(4, 0)], udot[(5, 0)]),
+            (6..n).map(|k| udot[(k, 0)]).collect(),
+        )
+    }
+
+    /// Compute the kinematics of the bus and of every body, in order.
+    fn links(&self) -> Vec<Link> {
+        let zero = Vector::new(0.0, 0.0, 0.0);
+        let mut links = vec![Link {
+            orientation: Quaternion::new(1.0, 0.0, 0.0, 0.0),
+            position: zero,
+            axis: zero,
+            hinge: zero,
+            rate: zero,
+            velocity: zero,
+            angular_acceleration: zero,
+            acceleration: zero,
+            chain: Vec::new(),
+        }];
+
+        for (i, body) in self.bodies.iter().enumerate() {
+            let parent = links[body.parent.map_or(0, |p| p + 1)].clone();
+            let (theta, rate) = (self.angles[i], self.rates[i]);
+
+            // Joint, and vector from the joint to the centre of mass
+            let axis = body.axis.rotate(parent.orientation);
+            let lever = body.hinge.rotate(parent.orientation);
+            let hinge = parent.position + lever;
+            let a = body.axis.scale(theta);
+            let orientation = parent.orientation * Quaternion::from_rotation_vector(a.x, a.y, a.z);
+            let arm = body.offset.rotate(orientation);
+
+            // Relative velocities
+            let omega = parent.rate + axis.scale(rate);
+            let velocity = parent.velocity + parent.rate.cross(lever) + omega.cross(arm);
+
+            // Relative accelerations at zero joint acceleration
+            let alpha = parent.angular_acceleration + parent.rate.cross(axis).scale(rate);
+            let acceleration = parent.acceleration
+                + parent.angular_acceleration.cross(lever)
+                + parent.rate.cross(parent.rate.cross(lever))
+                + alpha.cross(arm)
+                + omega.cross(omega.cross(arm));
+
+            let mut chain = parent.chain;
+            chain.push(i);
+
+            links.push(Link {
+                orientation,
+                position: hinge + arm,
+                axis,
+                hinge,
+                rate: omega,
+                velocity,
+                angular_acceleration: alpha,
+                acceleration,
+                chain,
+            });
+        }
+
+        links
+    }
+
+    /// Compute the velocity (bus frame) of the bus that holds the centre of mass of the
+    /// spacecraft at rest, given the angular velocity of the bus.
+    fn bus_velocity(&self, links: &[Link], angular_velocity: Vector) -> Vector {
+        let mass = self.bus_mass + self.bodies.iter().map(|b| b.mass).sum::<f64>();
+
+        self.bodies.iter().zip(links.iter().skip(1)).fold(Vector::new(0.0, 0.0, 0.0), |v, (b, l)| {
+            v - (angular_velocity.cross(l.position) + l.velocity).scale(b.mass / mass)
+        })
+    }
+
+    /// Raise `ValueError` unless a named list has one entry per joint.
+    fn check(&self, name: &str, values: &[f64]) -> PyResult<()> {
+        if values.len() == self.len() {
+            Ok (())
+        } else {
+            Err (PyValueError::new_err(format!(
+                "expected {} {}, got {}",
+                self.len(),
+                name,
+                values.len(),
+            )))
+        }
+    }
+}
+
+/// Compute the product of an inertia tensor and a vector.
+fn product(inertia: Inertia, v: Vector) -> Vector {
+    Vector::new(
+        inertia.j1*v.x + inertia.j6*v.y + inertia.j5*v.z,
+        inertia.j6*v.x + inertia.j2*v.y + inertia.j4*v.z,
+        inertia.j5*v.x + inertia.j4*v.y + inertia.j3*v.z,
+    )
+}
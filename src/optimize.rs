@@ -0,0 +1,128 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Linear programming.
+
+use crate::linalg::Matrix;
+
+/// Feasibility and optimality tolerance of the simplex method.
+const TOLERANCE: f64 = 1e-9;
+
+/// Maximum number of simplex pivots before giving up.
+const MAX_PIVOTS: usize = 10_000;
+
+/// Solve the linear program
+/// ```
+/// minimize c.T @ x  subject to  A @ x == b,  x >= 0
+/// ```
+/// using the two-phase simplex method with Bland's rule.
+///
+/// Returns `None` if the program is infeasible or unbounded.
+pub(crate) fn linear_program(c: &[f64], a: &Matrix, b: &[f64]) -> Option<Vec<f64>> {
+    let (m, n) = (a.rows, a.cols);
+    let width = n + m;
+
+    // Tableau [A | I | b], with rows negated as needed so that b >= 0
+    let mut tableau = vec![vec![0.0; width + 1]; m];
+    for (i, row) in tableau.iter_mut().enumerate() {
+        let sign = if b[i] < 0.0 { -1.0 } else { 1.0 };
+        for j in 0..n {
+            row[j] = sign * a[(i, j)];
+        }
+        row[n + i] = 1.0;
+        row[width] = sign * b[i];
+    }
+    let mut basis: Vec<usize> = (n..width).collect();
+
+    // Phase I: minimize the sum of the artificial variables
+    let artificial: Vec<f64> = (0..width).map(|j| if j < n { 0.0 } else { 1.0 }).collect();
+    simplex(&mut tableau, &mut basis, &artificial, width)?;
+
+    let scale = b.iter().fold(1.0, |s: f64, v| s.max(v.abs()));
+    let infeasibility: f64 = basis.iter()
+        .zip(&tableau)
+        .filter(|(&j, _)| j >= n)
+        .map(|(_, row)| row[width])
+        .sum();
+    if infeasibility > TOLERANCE * scale {
+        return None;
+    }
+
+    // Drive any remaining artificial variables out of the basis
+    for i in 0..m {
+        if basis[i] >= n {
+            if let Some (j) = (0..n).find(|&j| tableau[i][j].abs() > TOLERANCE) {
+                pivot(&mut tableau, &mut basis, i, j);
+            }
+        }
+    }
+
+    // Phase II: minimize the objective over the original variables
+    let mut cost = c.to_vec();
+    cost.resize(width, 0.0);
+    simplex(&mut tableau, &mut basis, &cost, n)?;
+
+    let mut x = vec![0.0; n];
+    for (row, &j) in tableau.iter().zip(&basis) {
+        if j < n {
+            x[j] = row[width];
+        }
+    }
+
+    Some (x)
+}
+
+/// Run the simplex method on a tableau in canonical form, allowing only the first
+/// `allowed` columns to enter the basis.
+///
+/// Returns `None` if the objective is unbounded below.
+fn simplex(tableau: &mut [Vec<f64>], basis: &mut [usize], cost: &[f64], allowed: usize) -> Option<()> {
+    let last = tableau.first().map_or(0, |row| row.len() - 1);
+
+    for _ in 0..MAX_PIVOTS {
+        // Bland's rule: the lowest-index column with negative reduced cost enters
+        let entering = (0..allowed).find(|&j| {
+            let reduced = cost[j] - tableau.iter()
+                .zip(basis.iter())
+                .map(|(row, &k)| cost[k] * row[j])
+                .sum::<f64>();
+            !basis.contains(&j) && reduced < -TOLERANCE
+        });
+        let Some (j) = entering else {
+            return Some (());
+        };
+
+        // Minimum ratio test, breaking ties by the lowest basis index
+        let leaving = (0..tableau.len())
+            .filter(|&i| tableau[i][j] > TOLERANCE)
+            .min_by(|&p, &q| {
+                let rp = tableau[p][last] / tableau[p][j];
+                let rq = tableau[q][last] / tableau[q][j];
+                rp.total_cmp(&rq).then(basis[p].cmp(&basis[q]))
+            })?;
+
+        pivot(tableau, basis, leaving, j);
+    }
+
+    None
+}
+
+/// Pivot a tableau on a given row and column.
+fn pivot(tableau: &mut [Vec<f64>], basis: &mut [usize], row: usize, col: usize) {
+    let p = tableau[row][col];
+    for v in tableau[row].iter_mut() {
+        *v /= p;
+    }
+
+    let pivot_row = tableau[row].clone();
+    for (i, r) in tableau.iter_mut().enumerate() {
+        let f = r[col];
+        if i != row && f != 0.0 {
+            for (v, pv) in r.iter_mut().zip(&pivot_row) {
+                *v -= f * pv;
+            }
+        }
+    }
+
+    basis[row] = col;
+}
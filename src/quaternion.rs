@@ -10,10 +10,12 @@ use std::ops::{
     Sub,
 };
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
 use crate::AngularVelocity;
+use crate::linalg::Matrix;
 
 #[pyclass]
 #[derive(Clone, Copy, Debug)]
@@ -78,17 +80,18 @@ impl Quaternion {
     ///
     /// Note that the angle should be in radians and the axis should be a unit normal vector.
     /// If the axis does not have unit norm, then the axis will be rescaled automatically.
-    pub fn from_rotation(cls: &Bound<'_, PyType>, angle: f64, x: f64, y: f64, z: f64) -> Self {
+    /// A zero axis is rejected.
+    pub fn from_rotation(cls: &Bound<'_, PyType>, angle: f64, x: f64, y: f64, z: f64) -> PyResult<Self> {
         let c = (angle/2.0).cos();
         let s = (angle/2.0).sin();
-        let a = Self::from_vector(cls, x, y, z).normalize();
+        let a = Self::from_vector(cls, x, y, z).normalize()?;
         
-        Self {
+        Ok (Self {
             w: c,
             x: s * a.x,
             y: s * a.y,
             z: s * a.z,
-        }
+        })
     }
 
     /// Compute the norm of this quaternion.
@@ -112,8 +115,15 @@ impl Quaternion {
     }
 
     /// Return the unit quaternion in the direction of this quaternion.
-    pub fn normalize(&self) -> Self {
-        self.scale(self.norm().powi(-1))
+    ///
+    /// Raises `ValueError` if this quaternion has zero norm.
+    pub fn normalize(&self) -> PyResult<Self> {
+        let n = self.norm();
+        if n == 0.0 {
+            return Err (PyValueError::new_err("cannot normalize a quaternion with zero norm"));
+        }
+
+        Ok (self.scale(n.powi(-1)))
     }
 
     /// Add two quaternions.
@@ -172,7 +182,7 @@ impl Quaternion {
     }
 
     /// Return a Pythonic representation of this quaternion.
-    fn __repr__(&self) -> String {
+    pub(crate) fn __repr__(&self) -> String {
         format!(
             "Quaternion({}, {}, {}, {})",
             self.w,
@@ -195,6 +205,99 @@ impl Quaternion {
     }
 }
 
+impl Quaternion {
+    /// Construct the unit quaternion of a rotation vector (the rotation axis scaled by
+    /// the rotation angle in radians), by the exponential map.
+    pub(crate) fn from_rotation_vector(x: f64, y: f64, z: f64) -> Self {
+        let angle = (x*x + y*y + z*z).sqrt();
+
+        // sin(angle/2) / angle, which tends to 1/2 for small angles
+        let s = if angle < 1e-8 {
+            0.5 - angle * angle / 48.0
+        } else {
+            (angle / 2.0).sin() / angle
+        };
+
+        Self::new((angle / 2.0).cos(), s * x, s * y, s * z)
+    }
+
+    /// Return the rotation vector of this unit quaternion (the rotation axis scaled by
+    /// the rotation angle in radians), by the logarithmic map.
+    ///
+    /// The shorter of the two equivalent rotations is returned.
+    pub(crate) fn rotation_vector(&self) -> (f64, f64, f64) {
+        let q = if self.w < 0.0 { -*self } else { *self };
+        let s = (q.x*q.x + q.y*q.y + q.z*q.z).sqrt();
+        let angle = 2.0 * s.atan2(q.w);
+
+        // angle / sin(angle/2), which tends to 2 for small angles
+        let f = if s < 1e-8 { 2.0 } else { angle / s };
+
+        (f * q.x, f * q.y, f * q.z)
+    }
+
+    /// Return the rotation matrix (direction cosine matrix) of this unit quaternion.
+    ///
+    /// The returned matrix `R` satisfies `R @ v == q * v * inv(q)` for any vector `v`,
+    /// so a quaternion that does not have unit norm is treated as its normalization.
+    pub(crate) fn dcm(&self) -> Matrix {
+        let Self { w, x, y, z } = *self;
+        let s = 2.0 * self.norm().powi(-2);
+
+        Matrix::from_rows(&[
+            vec![1.0 - s*(y*y + z*z), s*(x*y - w*z), s*(x*z + w*y)],
+            vec![s*(x*y + w*z), 1.0 - s*(x*x + z*z), s*(y*z - w*x)],
+            vec![s*(x*z - w*y), s*(y*z + w*x), 1.0 - s*(x*x + y*y)],
+        ]).unwrap()
+    }
+
+    /// Construct the unit quaternion corresponding to a proper rotation matrix,
+    /// using Shepperd's method.
+    ///
+    /// This is the inverse of [`Quaternion::dcm`].  The returned quaternion has
+    /// non-negative scalar part.
+    pub(crate) fn from_dcm(r: &Matrix) -> Self {
+        let t = r.trace();
+        let (r00, r11, r22) = (r[(0, 0)], r[(1, 1)], r[(2, 2)]);
+
+        let q = if t >= r00 && t >= r11 && t >= r22 {
+            let w = 0.5 * (1.0 + t).sqrt();
+            Self::new(
+                w,
+                (r[(2, 1)] - r[(1, 2)]) / (4.0 * w),
+                (r[(0, 2)] - r[(2, 0)]) / (4.0 * w),
+                (r[(1, 0)] - r[(0, 1)]) / (4.0 * w),
+            )
+        } else if r00 >= r11 && r00 >= r22 {
+            let x = 0.5 * (1.0 + 2.0*r00 - t).sqrt();
+            Self::new(
+                (r[(2, 1)] - r[(1, 2)]) / (4.0 * x),
+                x,
+                (r[(0, 1)] + r[(1, 0)]) / (4.0 * x),
+                (r[(0, 2)] + r[(2, 0)]) / (4.0 * x),
+            )
+        } else if r11 >= r22 {
+            let y = 0.5 * (1.0 + 2.0*r11 - t).sqrt();
+            Self::new(
+                (r[(0, 2)] - r[(2, 0)]) / (4.0 * y),
+                (r[(0, 1)] + r[(1, 0)]) / (4.0 * y),
+                y,
+                (r[(1, 2)] + r[(2, 1)]) / (4.0 * y),
+            )
+        } else {
+            let z = 0.5 * (1.0 + 2.0*r22 - t).sqrt();
+            Self::new(
+                (r[(1, 0)] - r[(0, 1)]) / (4.0 * z),
+                (r[(0, 2)] + r[(2, 0)]) / (4.0 * z),
+                (r[(1, 2)] + r[(2, 1)]) / (4.0 * z),
+                z,
+            )
+        };
+
+        if q.w < 0.0 { -q } else { q }.scale(q.norm().powi(-1))
+    }
+}
+
 impl Add<Quaternion> for Quaternion {
     type Output = Quaternion;
 
@@ -0,0 +1,73 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Seedable pseudo-random number generation.
+//!
+//! Sensor noise is drawn from this generator so that Monte Carlo runs are exactly
+//! reproducible from a seed, independent of platform.
+
+/// Pseudo-random number generator (xoshiro256**).
+#[derive(Clone, Debug)]
+pub(crate) struct Rng {
+    /// Generator state.
+    state: [u64; 4],
+
+    /// Second normal deviate from the most recent Box-Muller transform.
+    spare: Option<f64>,
+}
+
+impl Rng {
+    /// Construct a new generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        // Expand the seed with SplitMix64, which never yields an all-zero state
+        let mut x = seed;
+        let state = std::array::from_fn(|_| {
+            x = x.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        });
+
+        Self {
+            state,
+            spare: None,
+        }
+    }
+
+    /// Draw the next 64 random bits.
+    fn next(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+
+    /// Draw a uniform deviate on the interval `[0, 1)`.
+    pub fn uniform(&mut self) -> f64 {
+        (self.next() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Draw a standard normal deviate, using the Box-Muller transform.
+    pub fn normal(&mut self) -> f64 {
+        if let Some (z) = self.spare.take() {
+            return z;
+        }
+
+        let u = 1.0 - self.uniform();
+        let v = self.uniform();
+        let r = (-2.0 * u.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * v;
+        self.spare = Some (r * theta.sin());
+
+        r * theta.cos()
+    }
+}
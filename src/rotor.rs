@@ -0,0 +1,213 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Dual-spin rotor.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{
+    AngularMomentum,
+    AngularVelocity,
+    Inertia,
+    Vector,
+};
+use crate::inertia::{
+    non_negative,
+    positive,
+};
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Rotor of a dual-spin spacecraft.
+///
+/// The rotor is an axisymmetric wheel spinning about a fixed body axis, relative to the
+/// platform, on a bearing.  The inertia tensor of the state is that of the whole
+/// spacecraft with the rotor locked, so that the angular momentum of the spacecraft is
+/// ```
+/// H = J @ omega + rotor_inertia * spin * axis
+/// ```
+/// The bearing applies the motor torque and a viscous friction torque
+/// `-damping * spin` to the rotor, and the opposite torque to the platform.
+pub struct Rotor {
+    #[pyo3(get)]
+    /// Unit spin axis (body frame).
+    pub axis: Vector,
+
+    #[pyo3(get)]
+    /// Moment of inertia about the spin axis.
+    pub inertia: f64,
+
+    #[pyo3(get, set)]
+    /// Spin rate relative to the platform (rad/s).
+    pub spin: f64,
+
+    #[pyo3(get, set)]
+    /// Motor torque of the bearing, applied to the rotor about its axis.
+    pub torque: f64,
+
+    #[pyo3(get)]
+    /// Viscous damping coefficient of the bearing.
+    pub damping: f64,
+}
+
+#[pymethods]
+impl Rotor {
+    #[new]
+    #[pyo3(signature = (axis, inertia, spin = 0.0, damping = 0.0))]
+    /// Construct a new rotor, given its spin axis (body frame), its moment of inertia
+    /// about that axis, its spin rate relative to the platform and the damping
+    /// coefficient of its bearing.  The motor torque is initially zero.
+    ///
+    /// The axis is normalized automatically.  Raises `ValueError` if the axis is zero,
+    /// if the inertia is not positive, or if the damping coefficient is negative.
+    pub fn new(axis: Vector, inertia: f64, spin: f64, damping: f64) -> PyResult<Self> {
+        let rotor = Self {
+            axis: axis.normalize()?,
+            inertia,
+            spin,
+            torque: 0.0,
+            damping,
+        };
+        rotor.validate()?;
+
+        Ok (rotor)
+    }
+
+    #[setter]
+    /// Set the moment of inertia about the spin axis.
+    ///
+    /// Raises `ValueError` if the inertia is not positive.
+    fn set_inertia(&mut self, inertia: f64) -> PyResult<()> {
+        positive("rotor inertia", inertia)?;
+        self.inertia = inertia;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the viscous damping coefficient of the bearing.
+    ///
+    /// Raises `ValueError` if the damping coefficient is negative.
+    fn set_damping(&mut self, damping: f64) -> PyResult<()> {
+        non_negative("bearing damping coefficient", damping)?;
+        self.damping = damping;
+
+        Ok (())
+    }
+
+    /// Check that this rotor is physically realizable.
+    ///
+    /// Raises `ValueError` if the inertia is not positive or the damping coefficient is
+    /// negative or not finite.
+    pub fn validate(&self) -> PyResult<()> {
+        if !(self.inertia.is_finite() && self.inertia > 0.0) {
+            return Err (PyValueError::new_err(format!(
+                "rotor inertia must be finite and positive, got {}",
+                self.inertia,
+            )));
+        }
+
+        if !(self.damping.is_finite() && self.damping >= 0.0) {
+            return Err (PyValueError::new_err(format!(
+                "bearing damping coefficient must be finite and non-negative, got {}",
+                self.damping,
+            )));
+        }
+
+        Ok (())
+    }
+
+    /// Compute the angular momentum (body frame) of the rotor relative to the platform.
+    pub fn momentum(&self) -> AngularMomentum {
+        let h = self.axis.scale(self.inertia * self.spin);
+
+        AngularMomentum::new(h.x, h.y, h.z)
+    }
+
+    /// Compute the nutation frequencies (rad/s) of the platform and the rotor, given
+    /// the inertia tensor of the spacecraft and the angular velocity of the platform.
+    ///
+    /// The spacecraft is taken to be axisymmetric about the spin axis, with transverse
+    /// moment of inertia the mean of the two transverse moments.  With total axial
+    /// momentum `h` and transverse inertia `I_t`, the nutation frequency of each body is
+    /// `h / I_t` less its own inertial spin rate.
+    pub fn nutation_frequencies(&self, inertia: Inertia, angular_velocity: AngularVelocity) -> (f64, f64) {
+        let a = self.axis;
+        let ja = Vector::new(
+            inertia.j1*a.x + inertia.j6*a.y + inertia.j5*a.z,
+            inertia.j6*a.x + inertia.j2*a.y + inertia.j4*a.z,
+            inertia.j5*a.x + inertia.j4*a.y + inertia.j3*a.z,
+        );
+        let axial = a.dot(ja);
+        let transverse = 0.5 * (inertia.j1 + inertia.j2 + inertia.j3 - axial);
+
+        let platform = a.x*angular_velocity.x + a.y*angular_velocity.y + a.z*angular_velocity.z;
+        let rotor = platform + self.spin;
+        let h = axial * platform + self.inertia * self.spin;
+
+        (h / transverse - platform, h / transverse - rotor)
+    }
+
+    /// Evaluate the energy-sink stability criterion of Iorillo and Likins, given the
+    /// inertia tensor of the spacecraft, the angular velocity of the platform, and the
+    /// rates of energy dissipation (non-negative) in the platform and in the rotor.
+    ///
+    /// Nutation about the spin axis decays if
+    /// ```
+    /// platform_dissipation / lambda_p + rotor_dissipation / lambda_r > 0
+    /// ```
+    /// where `lambda_p` and `lambda_r` are the nutation frequencies of the platform and
+    /// the rotor.  With the rotor at rest relative to the platform, this reduces to the
+    /// major-axis rule for a single-spin spacecraft.
+    ///
+    /// Raises `ValueError` if either dissipation rate is negative or not finite.
+    pub fn stable(
+        &self,
+        inertia: Inertia,
+        angular_velocity: AngularVelocity,
+        platform_dissipation: f64,
+        rotor_dissipation: f64,
+    ) -> PyResult<bool> {
+        non_negative("platform energy dissipation rate", platform_dissipation)?;
+        non_negative("rotor energy dissipation rate", rotor_dissipation)?;
+
+        let (platform, rotor) = self.nutation_frequencies(inertia, angular_velocity);
+        let sink = |d: f64, lambda: f64| if d == 0.0 { 0.0 } else { d / lambda };
+
+        Ok (sink(platform_dissipation, platform) + sink(rotor_dissipation, rotor) > 0.0)
+    }
+
+    /// Return a Pythonic representation of this rotor.
+    fn __repr__(&self) -> String {
+        format!(
+            "Rotor({}, {}, {}, {})",
+            self.axis.__repr__(),
+            self.inertia,
+            self.spin,
+            self.damping,
+        )
+    }
+}
+
+impl Rotor {
+    /// Compute the inertia tensor of the spacecraft, less the axial inertia of the rotor.
+    pub(crate) fn platform_inertia(&self, inertia: Inertia) -> Inertia {
+        let Vector { x, y, z } = self.axis;
+        let i = self.inertia;
+
+        Inertia::new(
+            inertia.j1 - i*x*x,
+            inertia.j2 - i*y*y,
+            inertia.j3 - i*z*z,
+            inertia.j4 - i*y*z,
+            inertia.j5 - i*x*z,
+            inertia.j6 - i*x*y,
+        )
+    }
+
+    /// Compute the net torque of the bearing on the rotor about its axis.
+    pub(crate) fn bearing_torque(&self) -> f64 {
+        self.torque - self.damping * self.spin
+    }
+}
@@ -0,0 +1,263 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Rate gyro model.
+
+use pyo3::prelude::*;
+
+use crate::{
+    AngularVelocity,
+    State,
+};
+use crate::inertia::{
+    non_negative,
+    positive,
+    positive_or_infinite,
+};
+use crate::random::Rng;
+
+use super::due;
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Three-axis rate gyro.
+///
+/// The gyro measures the true angular velocity of the body (body frame), distorted by
+/// the following error sources, each of which is disabled by default.
+/// ```
+/// measured = quantize(saturate((I + diag(scale_factor)) @ misalignment @ omega + bias + noise))
+/// ```
+/// The bias is the sum of a constant bias, a first-order Gauss-Markov process with
+/// the given bias instability and correlation time, and a random walk driven by the
+/// rate random walk (RRW).  The noise is white, with spectral density given by the
+/// angle random walk (ARW).
+pub struct Gyro {
+    #[pyo3(get)]
+    /// Sample rate (Hz).
+    pub sample_rate: f64,
+
+    #[pyo3(get, set)]
+    /// Axis misalignment and non-orthogonality matrix.
+    pub misalignment: [[f64; 3]; 3],
+
+    #[pyo3(get, set)]
+    /// Scale factor error of each axis (fractional).
+    pub scale_factor: (f64, f64, f64),
+
+    #[pyo3(get, set)]
+    /// Constant bias (body frame).
+    pub bias: AngularVelocity,
+
+    #[pyo3(get)]
+    /// Bias instability, the steady-state standard deviation of the Gauss-Markov bias (rad/s).
+    ///
+    /// The Gauss-Markov bias starts at zero, so the bias instability has no effect
+    /// while the correlation time is infinite (the default).
+    pub bias_instability: f64,
+
+    #[pyo3(get)]
+    /// Correlation time of the Gauss-Markov bias (s).
+    pub correlation_time: f64,
+
+    #[pyo3(get)]
+    /// Angle random walk (rad/s/sqrt(Hz)).
+    pub arw: f64,
+
+    #[pyo3(get)]
+    /// Rate random walk (rad/s^2/sqrt(Hz)).
+    pub rrw: f64,
+
+    #[pyo3(get)]
+    /// Output quantization step (rad/s), or zero for no quantization.
+    pub quantization: f64,
+
+    #[pyo3(get)]
+    /// Output saturation limit of each axis (rad/s).
+    pub saturation: f64,
+
+    // Gauss-Markov bias.
+    markov: AngularVelocity,
+
+    // Random-walk bias.
+    walk: AngularVelocity,
+
+    // Time of the most recent sample.
+    last: Option<f64>,
+
+    // Noise generator.
+    rng: Rng,
+}
+
+#[pymethods]
+impl Gyro {
+    #[new]
+    #[pyo3(signature = (sample_rate, seed = 0))]
+    /// Construct a new ideal gyro, given its sample rate and the seed of its noise generator.
+    ///
+    /// Raises `ValueError` if the sample rate is not positive.
+    pub fn new(sample_rate: f64, seed: u64) -> PyResult<Self> {
+        positive("sample rate", sample_rate)?;
+
+        Ok (Self {
+            sample_rate,
+            misalignment: [
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+            scale_factor: (0.0, 0.0, 0.0),
+            bias: AngularVelocity::new(0.0, 0.0, 0.0),
+            bias_instability: 0.0,
+            correlation_time: f64::INFINITY,
+            arw: 0.0,
+            rrw: 0.0,
+            quantization: 0.0,
+            saturation: f64::INFINITY,
+            markov: AngularVelocity::new(0.0, 0.0, 0.0),
+            walk: AngularVelocity::new(0.0, 0.0, 0.0),
+            last: None,
+            rng: Rng::new(seed),
+        })
+    }
+
+    #[setter]
+    /// Set the bias instability.
+    ///
+    /// Raises `ValueError` if the bias instability is negative.
+    fn set_bias_instability(&mut self, bias_instability: f64) -> PyResult<()> {
+        non_negative("bias instability", bias_instability)?;
+        self.bias_instability = bias_instability;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the correlation time of the Gauss-Markov bias, which may be infinite.
+    ///
+    /// Raises `ValueError` if the correlation time is not positive.
+    fn set_correlation_time(&mut self, correlation_time: f64) -> PyResult<()> {
+        positive_or_infinite("correlation time", correlation_time)?;
+        self.correlation_time = correlation_time;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the angle random walk.
+    ///
+    /// Raises `ValueError` if the angle random walk is negative.
+    fn set_arw(&mut self, arw: f64) -> PyResult<()> {
+        non_negative("angle random walk", arw)?;
+        self.arw = arw;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the rate random walk.
+    ///
+    /// Raises `ValueError` if the rate random walk is negative.
+    fn set_rrw(&mut self, rrw: f64) -> PyResult<()> {
+        non_negative("rate random walk", rrw)?;
+        self.rrw = rrw;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the quantization step, or zero for no quantization.
+    ///
+    /// Raises `ValueError` if the quantization step is negative.
+    fn set_quantization(&mut self, quantization: f64) -> PyResult<()> {
+        non_negative("quantization step", quantization)?;
+        self.quantization = quantization;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the saturation limit, which may be infinite.
+    ///
+    /// Raises `ValueError` if the saturation limit is not positive.
+    fn set_saturation(&mut self, saturation: f64) -> PyResult<()> {
+        positive_or_infinite("saturation limit", saturation)?;
+        self.saturation = saturation;
+
+        Ok (())
+    }
+
+    #[getter]
+    /// Total instantaneous bias (body frame), including its random components.
+    pub fn true_bias(&self) -> AngularVelocity {
+        self.bias + self.markov + self.walk
+    }
+
+    /// Measure the angular velocity of a state, if a new sample is due.
+    ///
+    /// Returns `None` if less than one sample period has elapsed since the previous sample.
+    pub fn measure(&mut self, state: State) -> Option<AngularVelocity> {
+        if !due(self.last, self.sample_rate.recip(), state.time) {
+            return None;
+        }
+
+        // The random biases evolve over the time actually elapsed, which exceeds one
+        // sample period if the gyro is polled more slowly than its sample rate
+        let dt = match self.last {
+            Some (t) => {
+                let dt = state.time - t;
+                self.advance(dt);
+                dt
+            },
+            None => self.sample_rate.recip(),
+        };
+        self.last = Some (state.time);
+
+        Some (self.sample(state.angular_velocity, dt))
+    }
+}
+
+impl Gyro {
+    /// Produce one sample of a true angular velocity, averaging the angle random walk
+    /// over the given time since the previous sample.
+    fn sample(&mut self, w: AngularVelocity, dt: f64) -> AngularVelocity {
+        let m = self.misalignment;
+        let sf = [self.scale_factor.0, self.scale_factor.1, self.scale_factor.2];
+        let b = self.true_bias();
+        let b = [b.x, b.y, b.z];
+        let w = [w.x, w.y, w.z];
+
+        let sigma = self.arw / dt.sqrt();
+        let output: [f64; 3] = std::array::from_fn(|i| {
+            let sensed = (1.0 + sf[i]) * (m[i][0] * w[0] + m[i][1] * w[1] + m[i][2] * w[2]);
+            let raw = (sensed + b[i] + sigma * self.rng.normal()).clamp(-self.saturation, self.saturation);
+
+            if self.quantization > 0.0 {
+                (raw / self.quantization).round() * self.quantization
+            } else {
+                raw
+            }
+        });
+
+        AngularVelocity::new(output[0], output[1], output[2])
+    }
+
+    /// Advance the random biases over a given time.
+    fn advance(&mut self, dt: f64) {
+        // Advance Gauss-Markov bias
+        let phi = (-dt / self.correlation_time).exp();
+        let q = self.bias_instability * (1.0 - phi * phi).sqrt();
+        self.markov = self.markov.scale(phi) + self.noise(q);
+
+        // Advance random-walk bias
+        self.walk = self.walk + self.noise(self.rrw * dt.sqrt());
+    }
+
+    /// Draw a vector of independent normal deviates with a given standard deviation.
+    fn noise(&mut self, sigma: f64) -> AngularVelocity {
+        AngularVelocity::new(
+            sigma * self.rng.normal(),
+            sigma * self.rng.normal(),
+            sigma * self.rng.normal(),
+        )
+    }
+}
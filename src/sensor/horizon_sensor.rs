@@ -0,0 +1,105 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Earth horizon sensor model.
+
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    State,
+    Vector,
+    VectorMeasurement,
+};
+use crate::inertia::positive;
+use crate::random::Rng;
+
+use super::{
+    due,
+    perturb,
+};
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Earth horizon sensor.
+///
+/// The horizon sensor locates the limb of the Earth and reports the direction of nadir
+/// (the centre of the Earth) in the body frame, provided that nadir lies within its
+/// field of view about its boresight, which is the Z axis of the sensor frame.
+pub struct HorizonSensor {
+    #[pyo3(get)]
+    /// Sample rate (Hz).
+    pub sample_rate: f64,
+
+    #[pyo3(get, set)]
+    /// Mounting (rotation from sensor frame to body frame).
+    pub mounting: Quaternion,
+
+    #[pyo3(get, set)]
+    /// Standard deviation of the angular error about each axis (rad).
+    pub sigma: f64,
+
+    #[pyo3(get, set)]
+    /// Half-angle of the field of view (rad).
+    pub field_of_view: f64,
+
+    // Time of the most recent sample.
+    last: Option<f64>,
+
+    // Noise generator.
+    rng: Rng,
+}
+
+#[pymethods]
+impl HorizonSensor {
+    #[new]
+    #[pyo3(signature = (sample_rate, sigma, field_of_view, seed = 0))]
+    /// Construct a new horizon sensor aligned with the body frame, given its sample rate,
+    /// angular standard deviation, field of view half-angle, and the seed of its noise
+    /// generator.
+    ///
+    /// Raises `ValueError` if the sample rate, standard deviation or field of view is
+    /// not positive.
+    pub fn new(sample_rate: f64, sigma: f64, field_of_view: f64, seed: u64) -> PyResult<Self> {
+        positive("sample rate", sample_rate)?;
+        positive("horizon sensor standard deviation", sigma)?;
+        positive("field of view", field_of_view)?;
+
+        Ok (Self {
+            sample_rate,
+            mounting: Quaternion::new(1.0, 0.0, 0.0, 0.0),
+            sigma,
+            field_of_view,
+            last: None,
+            rng: Rng::new(seed),
+        })
+    }
+
+    #[getter]
+    /// Boresight of the horizon sensor (body frame).
+    pub fn boresight(&self) -> Vector {
+        Vector::new(0.0, 0.0, 1.0).rotate(self.mounting)
+    }
+
+    /// Measure the direction of nadir of a state, given the position of the body
+    /// relative to the centre of the Earth (inertial frame).
+    ///
+    /// Returns `None` if less than one sample period has elapsed since the previous sample,
+    /// or if nadir is not in the field of view.
+    pub fn measure(&mut self, state: State, position: Vector) -> PyResult<Option<VectorMeasurement>> {
+        if !due(self.last, self.sample_rate.recip(), state.time) {
+            return Ok (None);
+        }
+        self.last = Some (state.time);
+
+        let reference = (-position).normalize()?;
+        let body = reference.rotate(state.quaternion.inv());
+        if body.dot(self.boresight()) < self.field_of_view.cos() {
+            return Ok (None);
+        }
+
+        let measured = perturb(body, self.sigma, &mut self.rng)?;
+
+        Ok (Some (VectorMeasurement::new(measured, reference, self.sigma)?))
+    }
+}
@@ -0,0 +1,103 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Magnetometer model.
+
+use pyo3::prelude::*;
+
+use crate::{
+    State,
+    Vector,
+};
+use crate::inertia::positive;
+use crate::random::Rng;
+
+use super::due;
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Three-axis magnetometer.
+///
+/// The magnetometer measures the magnetic field in the body frame, distorted by a
+/// misalignment matrix (which also captures scale factor errors and soft-iron effects),
+/// a constant bias (hard-iron effects) and white noise.
+/// ```
+/// measured = misalignment @ field + bias + noise
+/// ```
+/// Measurements are in the units of the reference field.
+pub struct Magnetometer {
+    #[pyo3(get)]
+    /// Sample rate (Hz).
+    pub sample_rate: f64,
+
+    #[pyo3(get, set)]
+    /// Misalignment, scale factor and soft-iron matrix.
+    pub misalignment: [[f64; 3]; 3],
+
+    #[pyo3(get, set)]
+    /// Constant (hard-iron) bias (body frame).
+    pub bias: Vector,
+
+    #[pyo3(get, set)]
+    /// Standard deviation of the noise on each axis.
+    pub noise: f64,
+
+    // Time of the most recent sample.
+    last: Option<f64>,
+
+    // Noise generator.
+    rng: Rng,
+}
+
+#[pymethods]
+impl Magnetometer {
+    #[new]
+    #[pyo3(signature = (sample_rate, seed = 0))]
+    /// Construct a new ideal magnetometer, given its sample rate and the seed of its
+    /// noise generator.
+    ///
+    /// Raises `ValueError` if the sample rate is not positive.
+    pub fn new(sample_rate: f64, seed: u64) -> PyResult<Self> {
+        positive("sample rate", sample_rate)?;
+
+        Ok (Self {
+            sample_rate,
+            misalignment: [
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+            bias: Vector::new(0.0, 0.0, 0.0),
+            noise: 0.0,
+            last: None,
+            rng: Rng::new(seed),
+        })
+    }
+
+    /// Measure the magnetic field of a state, given the magnetic field (inertial frame).
+    ///
+    /// Returns `None` if less than one sample period has elapsed since the previous sample.
+    pub fn measure(&mut self, state: State, field: Vector) -> Option<Vector> {
+        if !due(self.last, self.sample_rate.recip(), state.time) {
+            return None;
+        }
+        self.last = Some (state.time);
+
+        Some (self.sample(field.rotate(state.quaternion.inv())))
+    }
+}
+
+impl Magnetometer {
+    /// Produce one sample of a true magnetic field (body frame).
+    pub(crate) fn sample(&mut self, field: Vector) -> Vector {
+        let m = self.misalignment;
+        let b = [field.x, field.y, field.z];
+        let bias = [self.bias.x, self.bias.y, self.bias.z];
+
+        let out: [f64; 3] = std::array::from_fn(|i| {
+            m[i][0] * b[0] + m[i][1] * b[1] + m[i][2] * b[2] + bias[i] + self.noise * self.rng.normal()
+        });
+
+        Vector::new(out[0], out[1], out[2])
+    }
+}
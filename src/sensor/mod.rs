@@ -0,0 +1,49 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Sensor models.
+//!
+//! Each sensor model produces realistic measurements from the true rigid-body
+//! [`crate::State`], at its own sample rate.  Noise is drawn from a seedable generator,
+//! so that Monte Carlo runs are reproducible.
+
+mod gyro;
+mod horizon_sensor;
+mod magnetometer;
+mod star_tracker;
+mod sun_sensor;
+
+use pyo3::prelude::*;
+
+use crate::Vector;
+use crate::random::Rng;
+
+pub use gyro::Gyro;
+pub use horizon_sensor::HorizonSensor;
+pub use magnetometer::Magnetometer;
+pub use star_tracker::{
+    StarTracker,
+    StarTrackerReading,
+};
+pub use sun_sensor::{
+    CoarseSunSensor,
+    FineSunSensor,
+};
+
+/// Determine whether a sensor sampled at a given period is due for a new sample.
+pub(crate) fn due(last: Option<f64>, period: f64, time: f64) -> bool {
+    match last {
+        // Allow for rounding in the accumulated simulation time
+        Some (t) => time - t >= period * (1.0 - 1e-9),
+        None => true,
+    }
+}
+
+/// Perturb a direction by a small random rotation, with the given standard deviation
+/// (rad) about each axis perpendicular to the direction.
+pub(crate) fn perturb(direction: Vector, sigma: f64, rng: &mut Rng) -> PyResult<Vector> {
+    let u = direction.normalize()?;
+    let noise = Vector::new(rng.normal(), rng.normal(), rng.normal()).scale(sigma);
+
+    (u + noise - u.scale(u.dot(noise))).normalize()
+}
@@ -0,0 +1,261 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Star tracker model.
+
+use std::collections::VecDeque;
+
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    QuaternionMeasurement,
+    State,
+    Vector,
+};
+use crate::inertia::{
+    non_negative,
+    positive,
+    positive_or_infinite,
+};
+use crate::random::Rng;
+
+use super::due;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Star tracker reading.
+pub struct StarTrackerReading {
+    #[pyo3(get)]
+    /// Measured attitude of the body, with its error covariance (body frame).
+    pub measurement: QuaternionMeasurement,
+
+    #[pyo3(get)]
+    /// Simulation time at which the measurement was taken.
+    pub time: f64,
+
+    #[pyo3(get)]
+    /// Whether the star tracker reported a valid solution.
+    pub valid: bool,
+}
+
+#[pymethods]
+impl StarTrackerReading {
+    /// Return a Pythonic representation of this reading.
+    fn __repr__(&self) -> String {
+        format!(
+            "StarTrackerReading({}, {}, {})",
+            self.measurement.__repr__(),
+            self.time,
+            if self.valid { "True" } else { "False" },
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Star tracker.
+///
+/// The star tracker measures the attitude of its own frame, whose Z axis is the
+/// boresight, and reports the attitude of the body through its mounting.  Its error
+/// is a small rotation in the sensor frame, which is larger about the boresight (roll)
+/// than across it.
+///
+/// A reading is flagged invalid if the sun lies within the exclusion angle of the
+/// boresight, or if the body rate exceeds the rate limit.  Readings are delivered one
+/// latency period after they are taken.
+pub struct StarTracker {
+    #[pyo3(get)]
+    /// Sample rate (Hz).
+    pub sample_rate: f64,
+
+    #[pyo3(get, set)]
+    /// Mounting (rotation from sensor frame to body frame).
+    pub mounting: Quaternion,
+
+    #[pyo3(get)]
+    /// Standard deviation of the error about each cross-boresight axis (rad).
+    pub cross_boresight: f64,
+
+    #[pyo3(get)]
+    /// Standard deviation of the error about the boresight (rad).
+    pub roll: f64,
+
+    #[pyo3(get)]
+    /// Half-angle of the sun exclusion cone about the boresight (rad).
+    pub sun_exclusion: f64,
+
+    #[pyo3(get)]
+    /// Largest body rate at which the star tracker reports a valid solution (rad/s).
+    pub max_rate: f64,
+
+    #[pyo3(get)]
+    /// Delay between taking and delivering a reading (s).
+    pub latency: f64,
+
+    // Readings taken but not yet delivered.
+    pending: VecDeque<StarTrackerReading>,
+
+    // Time of the most recent sample.
+    last: Option<f64>,
+
+    // Noise generator.
+    rng: Rng,
+}
+
+#[pymethods]
+impl StarTracker {
+    #[new]
+    #[pyo3(signature = (sample_rate, cross_boresight, roll, seed = 0))]
+    /// Construct a new star tracker aligned with the body frame, given its sample rate,
+    /// its cross-boresight and roll standard deviations, and the seed of its noise
+    /// generator.
+    ///
+    /// Raises `ValueError` if the sample rate or either standard deviation is not positive.
+    pub fn new(sample_rate: f64, cross_boresight: f64, roll: f64, seed: u64) -> PyResult<Self> {
+        positive("sample rate", sample_rate)?;
+        positive("cross-boresight standard deviation", cross_boresight)?;
+        positive("roll standard deviation", roll)?;
+
+        Ok (Self {
+            sample_rate,
+            mounting: Quaternion::new(1.0, 0.0, 0.0, 0.0),
+            cross_boresight,
+            roll,
+            sun_exclusion: 0.0,
+            max_rate: f64::INFINITY,
+            latency: 0.0,
+            pending: VecDeque::new(),
+            last: None,
+            rng: Rng::new(seed),
+        })
+    }
+
+    #[setter]
+    /// Set the cross-boresight standard deviation.
+    ///
+    /// Raises `ValueError` if the standard deviation is not positive.
+    fn set_cross_boresight(&mut self, cross_boresight: f64) -> PyResult<()> {
+        positive("cross-boresight standard deviation", cross_boresight)?;
+        self.cross_boresight = cross_boresight;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the roll standard deviation.
+    ///
+    /// Raises `ValueError` if the standard deviation is not positive.
+    fn set_roll(&mut self, roll: f64) -> PyResult<()> {
+        positive("roll standard deviation", roll)?;
+        self.roll = roll;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the half-angle of the sun exclusion cone, or zero for no exclusion.
+    ///
+    /// Raises `ValueError` if the half-angle is negative.
+    fn set_sun_exclusion(&mut self, sun_exclusion: f64) -> PyResult<()> {
+        non_negative("sun exclusion angle", sun_exclusion)?;
+        self.sun_exclusion = sun_exclusion;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the rate limit, which may be infinite.
+    ///
+    /// Raises `ValueError` if the rate limit is not positive.
+    fn set_max_rate(&mut self, max_rate: f64) -> PyResult<()> {
+        positive_or_infinite("rate limit", max_rate)?;
+        self.max_rate = max_rate;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the latency, or zero for immediate delivery.
+    ///
+    /// Raises `ValueError` if the latency is negative.
+    fn set_latency(&mut self, latency: f64) -> PyResult<()> {
+        non_negative("latency", latency)?;
+        self.latency = latency;
+
+        Ok (())
+    }
+
+    #[getter]
+    /// Boresight of the star tracker (body frame).
+    pub fn boresight(&self) -> Vector {
+        Vector::new(0.0, 0.0, 1.0).rotate(self.mounting)
+    }
+
+    #[pyo3(signature = (state, sun = None))]
+    /// Measure the attitude of a state, given the direction of the sun (inertial frame).
+    ///
+    /// Returns the most recent reading whose latency has elapsed, or `None` if no new
+    /// reading has been delivered.  If no sun direction is given, the exclusion check
+    /// is skipped.
+    pub fn measure(&mut self, state: State, sun: Option<Vector>) -> PyResult<Option<StarTrackerReading>> {
+        if due(self.last, self.sample_rate.recip(), state.time) {
+            self.last = Some (state.time);
+            let reading = self.sample(state.clone(), sun)?;
+            self.pending.push_back(reading);
+        }
+
+        // Deliver the latest reading whose latency has elapsed
+        let mut delivered = None;
+        while let Some (r) = self.pending.front() {
+            if r.time + self.latency > state.time + 1e-9 * self.sample_rate.recip() {
+                break;
+            }
+            delivered = self.pending.pop_front();
+        }
+
+        Ok (delivered)
+    }
+}
+
+impl StarTracker {
+    /// Take a reading of the true attitude of a state.
+    fn sample(&mut self, state: State, sun: Option<Vector>) -> PyResult<StarTrackerReading> {
+        let (c, r) = (self.cross_boresight, self.roll);
+
+        // Error rotation in the sensor frame, expressed in the body frame
+        let error = Vector::new(
+            c * self.rng.normal(),
+            c * self.rng.normal(),
+            r * self.rng.normal(),
+        ).rotate(self.mounting);
+        let q = state.quaternion * Quaternion::from_rotation_vector(error.x, error.y, error.z);
+
+        // Covariance of the error, expressed in the body frame
+        let m = self.mounting.dcm();
+        let mut covariance = [[0.0; 3]; 3];
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, p) in row.iter_mut().enumerate() {
+                *p = c * c * (m[(i, 0)] * m[(j, 0)] + m[(i, 1)] * m[(j, 1)]) + r * r * m[(i, 2)] * m[(j, 2)];
+            }
+        }
+
+        // Validity checks
+        let w = state.angular_velocity;
+        let rate = (w.x * w.x + w.y * w.y + w.z * w.z).sqrt();
+        let blinded = match sun {
+            Some (s) => {
+                let boresight = self.boresight().rotate(state.quaternion);
+                let cos = boresight.dot(s.normalize()?);
+                cos.clamp(-1.0, 1.0).acos() < self.sun_exclusion
+            },
+            None => false,
+        };
+
+        Ok (StarTrackerReading {
+            measurement: QuaternionMeasurement::new(q, covariance)?,
+            time: state.time,
+            valid: rate <= self.max_rate && !blinded,
+        })
+    }
+}
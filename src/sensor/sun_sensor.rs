@@ -0,0 +1,306 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Sun sensor models.
+
+use pyo3::prelude::*;
+
+use crate::{
+    Quaternion,
+    State,
+    Vector,
+    VectorMeasurement,
+};
+use crate::inertia::{
+    non_negative,
+    positive,
+};
+use crate::linalg::Matrix;
+use crate::random::Rng;
+
+use super::{
+    due,
+    perturb,
+};
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Coarse sun sensor.
+///
+/// A coarse sun sensor is a set of photodiodes, each of which produces a current
+/// proportional to the cosine of the angle between its normal and the sun, within its
+/// field of view.  The direction of the sun can be recovered from the currents of
+/// three or more illuminated photodiodes.
+pub struct CoarseSunSensor {
+    #[pyo3(get)]
+    /// Sample rate (Hz).
+    pub sample_rate: f64,
+
+    #[pyo3(get)]
+    /// Unit normal of each photodiode (body frame).
+    pub normals: Vec<Vector>,
+
+    #[pyo3(get)]
+    /// Current produced by a photodiode facing the sun.
+    pub scale: f64,
+
+    #[pyo3(get)]
+    /// Standard deviation of the current noise.
+    pub noise: f64,
+
+    #[pyo3(get)]
+    /// Half-angle of the field of view of each photodiode (rad).
+    pub field_of_view: f64,
+
+    // Time of the most recent sample.
+    last: Option<f64>,
+
+    // Noise generator.
+    rng: Rng,
+}
+
+#[pymethods]
+impl CoarseSunSensor {
+    #[new]
+    #[pyo3(signature = (sample_rate, normals, seed = 0))]
+    /// Construct a new coarse sun sensor, given its sample rate, the normals of its
+    /// photodiodes (body frame), and the seed of its noise generator.
+    ///
+    /// The normals are normalized automatically.  Raises `ValueError` if the sample rate
+    /// is not positive or any normal is zero.
+    pub fn new(sample_rate: f64, normals: Vec<Vector>, seed: u64) -> PyResult<Self> {
+        positive("sample rate", sample_rate)?;
+
+        Ok (Self {
+            sample_rate,
+            normals: normals.iter().map(|n| n.normalize()).collect::<PyResult<_>>()?,
+            scale: 1.0,
+            noise: 0.0,
+            field_of_view: std::f64::consts::FRAC_PI_2,
+            last: None,
+            rng: Rng::new(seed),
+        })
+    }
+
+    #[setter]
+    /// Set the normals of the photodiodes (body frame), which are normalized automatically.
+    ///
+    /// Raises `ValueError` if any normal is zero.
+    fn set_normals(&mut self, normals: Vec<Vector>) -> PyResult<()> {
+        self.normals = normals.iter().map(|n| n.normalize()).collect::<PyResult<_>>()?;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the current produced by a photodiode facing the sun.
+    ///
+    /// Raises `ValueError` if the scale is not positive.
+    fn set_scale(&mut self, scale: f64) -> PyResult<()> {
+        positive("photodiode scale", scale)?;
+        self.scale = scale;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the standard deviation of the current noise.
+    ///
+    /// Raises `ValueError` if the standard deviation is negative.
+    fn set_noise(&mut self, noise: f64) -> PyResult<()> {
+        non_negative("photodiode noise", noise)?;
+        self.noise = noise;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the half-angle of the field of view of each photodiode.
+    ///
+    /// Raises `ValueError` if the half-angle is not positive.
+    fn set_field_of_view(&mut self, field_of_view: f64) -> PyResult<()> {
+        positive("field of view", field_of_view)?;
+        self.field_of_view = field_of_view;
+
+        Ok (())
+    }
+
+    #[pyo3(signature = (state, sun = None))]
+    /// Measure the photodiode currents of a state, given the direction of the sun
+    /// (inertial frame), or `None` if the body is in eclipse.
+    ///
+    /// Returns `None` if less than one sample period has elapsed since the previous sample.
+    pub fn measure(&mut self, state: State, sun: Option<Vector>) -> PyResult<Option<Vec<f64>>> {
+        if !due(self.last, self.sample_rate.recip(), state.time) {
+            return Ok (None);
+        }
+        self.last = Some (state.time);
+
+        let sun = match sun {
+            Some (s) => Some (s.normalize()?.rotate(state.quaternion.inv())),
+            None => None,
+        };
+        let cutoff = self.field_of_view.cos();
+
+        let currents = self.normals.clone().iter().map(|n| {
+            let cos = sun.map(|s| n.dot(s)).unwrap_or(0.0);
+            let ideal = if cos > 0.0 && cos >= cutoff { self.scale * cos } else { 0.0 };
+
+            (ideal + self.noise * self.rng.normal()).max(0.0)
+        }).collect();
+
+        Ok (Some (currents))
+    }
+
+    /// Estimate the direction of the sun (body frame) from photodiode currents, by least
+    /// squares over the illuminated photodiodes.
+    ///
+    /// A photodiode counts as illuminated only if its current exceeds three standard
+    /// deviations of the noise, since a dark photodiode still reads positive noise, and
+    /// only if it faces the estimated direction.  Returns `None` if the illuminated
+    /// photodiodes do not determine a direction.
+    pub fn estimate(&self, currents: Vec<f64>) -> Option<Vector> {
+        let threshold = 3.0 * self.noise;
+        let mut lit: Vec<bool> = currents.iter().map(|i| *i > threshold).collect();
+
+        loop {
+            let s = self.solve(&currents, &lit)?;
+
+            // A photodiode facing away from the sun is dark, whatever its noise
+            let facing: Vec<bool> = lit.iter()
+                .zip(&self.normals)
+                .map(|(l, n)| *l && n.dot(s) > 0.0)
+                .collect();
+            if facing == lit {
+                return Some (s);
+            }
+            lit = facing;
+        }
+    }
+}
+
+impl CoarseSunSensor {
+    /// Solve for the direction of the sun (body frame) by least squares over the
+    /// photodiodes flagged as illuminated.
+    fn solve(&self, currents: &[f64], lit: &[bool]) -> Option<Vector> {
+        let mut normal = Matrix::zeros(3, 3);
+        let mut rhs = Matrix::zeros(3, 1);
+        for ((n, i), _) in self.normals.iter().zip(currents).zip(lit).filter(|(_, l)| **l) {
+            normal = &normal + &(&n.column() * &n.column().transpose());
+            rhs = &rhs + &n.column().scale(i / self.scale);
+        }
+
+        let s = Vector::from_column(&(&normal.inverse()? * &rhs));
+        s.normalize().ok()
+    }
+}
+
+#[pyclass]
+#[derive(Clone, Debug)]
+/// Fine sun sensor.
+///
+/// A fine sun sensor measures the direction of the sun within a conical field of view
+/// about its boresight, which is the Z axis of the sensor frame.
+pub struct FineSunSensor {
+    #[pyo3(get)]
+    /// Sample rate (Hz).
+    pub sample_rate: f64,
+
+    #[pyo3(get, set)]
+    /// Mounting (rotation from sensor frame to body frame).
+    pub mounting: Quaternion,
+
+    #[pyo3(get)]
+    /// Standard deviation of the angular error about each axis (rad).
+    pub sigma: f64,
+
+    #[pyo3(get)]
+    /// Half-angle of the field of view (rad).
+    pub field_of_view: f64,
+
+    // Time of the most recent sample.
+    last: Option<f64>,
+
+    // Noise generator.
+    rng: Rng,
+}
+
+#[pymethods]
+impl FineSunSensor {
+    #[new]
+    #[pyo3(signature = (sample_rate, sigma, field_of_view, seed = 0))]
+    /// Construct a new fine sun sensor aligned with the body frame, given its sample rate,
+    /// angular standard deviation, field of view half-angle, and the seed of its noise
+    /// generator.
+    ///
+    /// Raises `ValueError` if the sample rate, standard deviation or field of view is
+    /// not positive.
+    pub fn new(sample_rate: f64, sigma: f64, field_of_view: f64, seed: u64) -> PyResult<Self> {
+        positive("sample rate", sample_rate)?;
+        positive("sun sensor standard deviation", sigma)?;
+        positive("field of view", field_of_view)?;
+
+        Ok (Self {
+            sample_rate,
+            mounting: Quaternion::new(1.0, 0.0, 0.0, 0.0),
+            sigma,
+            field_of_view,
+            last: None,
+            rng: Rng::new(seed),
+        })
+    }
+
+    #[setter]
+    /// Set the standard deviation of the angular error.
+    ///
+    /// Raises `ValueError` if the standard deviation is not positive.
+    fn set_sigma(&mut self, sigma: f64) -> PyResult<()> {
+        positive("sun sensor standard deviation", sigma)?;
+        self.sigma = sigma;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the half-angle of the field of view.
+    ///
+    /// Raises `ValueError` if the half-angle is not positive.
+    fn set_field_of_view(&mut self, field_of_view: f64) -> PyResult<()> {
+        positive("field of view", field_of_view)?;
+        self.field_of_view = field_of_view;
+
+        Ok (())
+    }
+
+    #[getter]
+    /// Boresight of the sun sensor (body frame).
+    pub fn boresight(&self) -> Vector {
+        Vector::new(0.0, 0.0, 1.0).rotate(self.mounting)
+    }
+
+    #[pyo3(signature = (state, sun = None))]
+    /// Measure the direction of the sun of a state, given the direction of the sun
+    /// (inertial frame), or `None` if the body is in eclipse.
+    ///
+    /// Returns `None` if less than one sample period has elapsed since the previous sample,
+    /// or if the sun is not in the field of view.
+    pub fn measure(&mut self, state: State, sun: Option<Vector>) -> PyResult<Option<VectorMeasurement>> {
+        if !due(self.last, self.sample_rate.recip(), state.time) {
+            return Ok (None);
+        }
+        self.last = Some (state.time);
+
+        let Some (sun) = sun else {
+            return Ok (None);
+        };
+        let reference = sun.normalize()?;
+        let body = reference.rotate(state.quaternion.inv());
+        if body.dot(self.boresight()) < self.field_of_view.cos() {
+            return Ok (None);
+        }
+
+        let measured = perturb(body, self.sigma, &mut self.rng)?;
+
+        Ok (Some (VectorMeasurement::new(measured, reference, self.sigma)?))
+    }
+}
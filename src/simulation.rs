@@ -0,0 +1,283 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Closed-loop simulation.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{
+    PyDict,
+    PyList,
+};
+
+use crate::{
+    State,
+    Torque,
+};
+use crate::actuator::Firing;
+use crate::control::Controller;
+use crate::inertia::positive;
+use crate::sensor::due;
+
+/// Actuator registered with a simulation.
+struct Actuator {
+    /// Name of the actuator, which keys its command.
+    name: String,
+
+    /// Actuator model, called as `model(state, command) -> Torque | Firing | list`.
+    model: Py<PyAny>,
+
+    /// Sample rate (Hz), or `None` to update at every integration step.
+    sample_rate: Option<f64>,
+}
+
+#[pyclass]
+/// Closed-loop simulation.
+///
+/// A simulation couples sensors, a controller and actuators to the rigid-body
+/// dynamics, and propagates them together with an integrator.  At each integration
+/// step, in order:
+///
+/// 1. every sensor is called as `sensor(state)`, and each reading that is not `None`
+///    replaces the latest measurement of that sensor;
+/// 2. if a control period has elapsed, the controller is called as
+///    `controller.update(time, measurements)` and returns a dict of commands keyed by
+///    actuator name;
+/// 3. every actuator that is due and has a command is called as
+///    `actuator(state, command)`, and the returned torque, thruster firing or list of
+///    joint torques is held until its next update;
+/// 4. the sum of the actuator torques is applied to the body for the step, the sum of
+///    the joint torques is applied to the joints of the articulated bodies, and any
+///    thruster firings are passed to the integrator as `step(state, firing)`, which
+///    delivers their impulse exactly.
+///
+/// The torque of the initial state is a constant disturbance, to which the actuator
+/// torques are added at every step.  Sensor models sample at their own rates, returning
+/// `None` between samples.
+/// Everything is recorded in the returned [`SimulationLog`].
+pub struct Simulation {
+    #[pyo3(get)]
+    /// Integrator, which provides `step(state, torque = None) -> State`.
+    pub integrator: Py<PyAny>,
+
+    #[pyo3(get)]
+    /// Controller, which provides `update(time, measurements) -> commands`.
+    pub controller: Py<PyAny>,
+
+    #[pyo3(get)]
+    /// Control rate (Hz).
+    pub control_rate: f64,
+
+    // Sensors, by name.
+    sensors: Vec<(String, Py<PyAny>)>,
+
+    // Actuators.
+    actuators: Vec<Actuator>,
+}
+
+#[pymethods]
+impl Simulation {
+    #[new]
+    /// Construct a new simulation, given an integrator, a controller and its rate.
+    ///
+    /// Raises `ValueError` if the control rate is not positive.
+    pub fn new(integrator: Py<PyAny>, controller: Py<PyAny>, control_rate: f64) -> PyResult<Self> {
+        positive("control rate", control_rate)?;
+
+        Ok (Self {
+            integrator,
+            controller,
+            control_rate,
+            sensors: Vec::new(),
+            actuators: Vec::new(),
+        })
+    }
+
+    /// Add a sensor, called as `sensor(state)` and returning a reading or `None`.
+    ///
+    /// A sensor added under an existing name replaces it.
+    pub fn add_sensor(&mut self, name: String, sensor: Py<PyAny>) {
+        self.sensors.retain(|(n, _)| *n != name);
+        self.sensors.push((name, sensor));
+    }
+
+    #[pyo3(signature = (name, actuator, sample_rate = None))]
+    /// Add an actuator, called as `actuator(state, command)` and returning a `Torque`
+    /// (body frame), a thruster `Firing`, or a list of joint torques for the articulated
+    /// bodies, at the given sample rate or, by default, at every integration step.
+    ///
+    /// An actuator added under an existing name replaces it.  Raises `ValueError` if
+    /// the sample rate is not positive.
+    pub fn add_actuator(&mut self, name: String, actuator: Py<PyAny>, sample_rate: Option<f64>) -> PyResult<()> {
+        if let Some (rate) = sample_rate {
+            positive("sample rate", rate)?;
+        }
+
+        self.actuators.retain(|a| a.name != name);
+        self.actuators.push(Actuator {
+            name,
+            model: actuator,
+            sample_rate,
+        });
+
+        Ok (())
+    }
+
+    /// Run the simulation from an initial state for a given duration.
+    ///
+    /// Raises `ValueError` if the duration is negative or not finite, or if joint
+    /// torques are commanded for a state without matching articulated bodies, and
+    /// `TypeError` if the controller does not return a dict.  Errors raised by the
+    /// integrator, sensors, controller or actuators are propagated.
+    pub fn run(&self, py: Python<'_>, state: State, duration: f64) -> PyResult<SimulationLog> {
+        if duration != 0.0 {
+            positive("duration", duration)?;
+        }
+
+        let integrator = self.integrator.bind(py);
+        let mut controller = self.controller.bind(py).clone();
+
+        let measurements = PyDict::new(py);
+        let mut commands = PyDict::new(py);
+        let mut last_control = None;
+        let mut last_actuation: Vec<Option<f64>> = vec![None; self.actuators.len()];
+        let mut held = vec![Torque::new(0.0, 0.0, 0.0); self.actuators.len()];
+        let mut firings: Vec<Option<Firing>> = vec![None; self.actuators.len()];
+        let mut joints: Vec<Option<Vec<f64>>> = vec![None; self.actuators.len()];
+
+        let readings = PyDict::new(py);
+        let mut logs = Vec::with_capacity(self.sensors.len());
+        for (name, _) in &self.sensors {
+            let log = PyList::empty(py);
+            readings.set_item(name, &log)?;
+            logs.push(log);
+        }
+        let history = PyList::empty(py);
+
+        let mut state = state;
+        let disturbance = state.torque;
+        let end = state.time + duration;
+        let mut states = vec![state.clone()];
+        let mut torques = Vec::new();
+        while end - state.time > 1e-9 * end.abs().max(1.0) {
+            // Sample sensors
+            for ((name, sensor), log) in self.sensors.iter().zip(&logs) {
+                let reading = sensor.bind(py).call1((state.clone(),))?;
+                if !reading.is_none() {
+                    log.append((state.time, &reading))?;
+                    measurements.set_item(name, reading)?;
+                }
+            }
+
+            // Update controller
+            if due(last_control, self.control_rate.recip(), state.time) {
+                last_control = Some (state.time);
+                commands = controller.update(state.time, &measurements.copy()?)?;
+                history.append((state.time, &commands))?;
+            }
+
+            // Update actuators
+            let mut torque = Torque::new(0.0, 0.0, 0.0);
+            let mut firing: Option<Firing> = None;
+            for (k, actuator) in self.actuators.iter().enumerate() {
+                let period = actuator.sample_rate.map_or(0.0, f64::recip);
+                if due(last_actuation[k], period, state.time) {
+                    if let Some (command) = commands.get_item(&actuator.name)? {
+                        last_actuation[k] = Some (state.time);
+                        let output = actuator.model.bind(py).call1((state.clone(), command))?;
+                        held[k] = Torque::new(0.0, 0.0, 0.0);
+                        firings[k] = None;
+                        joints[k] = None;
+                        if let Ok (f) = output.downcast::<Firing>() {
+                            firings[k] = Some (f.borrow().clone());
+                        } else if let Ok (t) = output.extract::<Torque>() {
+                            held[k] = t;
+                        } else {
+                            joints[k] = Some (output.extract()?);
+                        }
+                    }
+                }
+                torque = torque + held[k];
+                if let Some (f) = &firings[k] {
+                    firing = Some (firing.unwrap_or_default() + f.clone());
+                }
+            }
+
+            // Sum the joint torques of every actuator driving the joints
+            let mut joint_torque: Option<Vec<f64>> = None;
+            for j in joints.iter().flatten() {
+                joint_torque = Some (match joint_torque {
+                    Some (sum) if sum.len() == j.len() => sum.iter().zip(j).map(|(a, b)| a + b).collect(),
+                    Some (sum) => return Err (PyValueError::new_err(format!(
+                        "actuators command {} and {} joint torques",
+                        sum.len(),
+                        j.len(),
+                    ))),
+                    None => j.clone(),
+                });
+            }
+            if let Some (j) = joint_torque {
+                let Some (multibody) = &mut state.multibody else {
+                    return Err (PyValueError::new_err("joint torques commanded for a state without articulated bodies"));
+                };
+                multibody.set_torques(j)?;
+            }
+
+            // Integrate, recording the mean thruster torque over the step
+            let start = state.time;
+            state.torque = disturbance + torque;
+            state = if let Some (f) = &firing {
+                integrator.call_method1("step", (state, f.clone()))?.extract()?
+            } else {
+                integrator.call_method1("step", (state,))?.extract()?
+            };
+            if let Some (f) = &firing {
+                torque = torque + f.mean(start, state.time)?;
+            }
+            states.push(state.clone());
+            torques.push(torque);
+        }
+
+        Ok (SimulationLog {
+            states,
+            torques,
+            measurements: readings.unbind(),
+            commands: history.unbind(),
+        })
+    }
+}
+
+#[pyclass]
+/// Record of a closed-loop simulation.
+pub struct SimulationLog {
+    #[pyo3(get)]
+    /// State at the start of the simulation and after every integration step.
+    pub states: Vec<State>,
+
+    #[pyo3(get)]
+    /// Total actuator torque (body frame) applied during each integration step, with
+    /// thruster firings averaged over the step.
+    pub torques: Vec<Torque>,
+
+    #[pyo3(get)]
+    /// Readings of each sensor, keyed by sensor name, as a list of `(time, reading)`.
+    pub measurements: Py<PyDict>,
+
+    #[pyo3(get)]
+    /// Controller commands, as a list of `(time, commands)`.
+    pub commands: Py<PyList>,
+}
+
+#[pymethods]
+impl SimulationLog {
+    #[getter]
+    /// Time of each recorded state.
+    pub fn times(&self) -> Vec<f64> {
+        self.states.iter().map(|s| s.time).collect()
+    }
+
+    /// Return the number of recorded states.
+    fn __len__(&self) -> usize {
+        self.states.len()
+    }
+}
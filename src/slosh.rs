@@ -0,0 +1,194 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Propellant slosh pendulum.
+
+use pyo3::prelude::*;
+
+use crate::{
+    AngularMomentum,
+    AngularVelocity,
+    Vector,
+};
+use crate::inertia::{
+    non_negative,
+    positive,
+};
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// Spherical-pendulum model of propellant slosh.
+///
+/// The sloshing propellant is a point mass on a massless rod, hinged at a point of the
+/// tank.  The rod transmits the tension of the pendulum to the bus, and the hinge
+/// applies a viscous torque `-damping * (direction x rate)` to the pendulum, standing
+/// in for the damping of the liquid.  The pendulum is restored by the apparent gravity
+/// of the non-gravitational acceleration of the spacecraft, such as thrust, and by the
+/// centrifugal field of a spinning spacecraft.
+///
+/// The inertia tensor of the state is that of the bus about its own centre of mass,
+/// excluding the slosh mass.  The bus and the slosh mass move about their common centre
+/// of mass, so the slosh mass enters the dynamics through the reduced mass
+/// ```
+/// mu = mass * bus_mass / (mass + bus_mass)
+/// ```
+pub struct SloshPendulum {
+    #[pyo3(get)]
+    /// Slosh mass.
+    pub mass: f64,
+
+    #[pyo3(get)]
+    /// Length of the pendulum.
+    pub length: f64,
+
+    #[pyo3(get)]
+    /// Position of the hinge (body frame), relative to the centre of mass of the bus.
+    pub hinge: Vector,
+
+    #[pyo3(get)]
+    /// Mass of the bus, excluding the slosh mass.
+    pub bus_mass: f64,
+
+    #[pyo3(get)]
+    /// Viscous damping coefficient of the hinge.
+    pub damping: f64,
+
+    #[pyo3(get)]
+    /// Unit direction of the pendulum (body frame), from the hinge to the slosh mass.
+    pub direction: Vector,
+
+    #[pyo3(get)]
+    /// Time derivative of the direction of the pendulum, relative to the bus.
+    pub rate: Vector,
+
+    #[pyo3(get, set)]
+    /// Non-gravitational acceleration (body frame) of the spacecraft.
+    pub acceleration: Vector,
+}
+
+#[pymethods]
+impl SloshPendulum {
+    #[new]
+    #[pyo3(signature = (mass, length, hinge, bus_mass, damping = 0.0, direction = None))]
+    /// Construct a new slosh pendulum, given the slosh mass, the length of the
+    /// pendulum, the position of its hinge (body frame) relative to the centre of mass
+    /// of the bus, the mass of the bus, and the damping coefficient of the hinge.
+    ///
+    /// The pendulum is initially at rest relative to the bus, pointing in the given
+    /// direction, or by default away from the centre of mass of the bus.  The
+    /// spacecraft is initially unaccelerated.
+    ///
+    /// Raises `ValueError` if the slosh mass, length or bus mass is not positive, if
+    /// the damping coefficient is negative, or if the direction is zero.
+    pub fn new(
+        mass: f64,
+        length: f64,
+        hinge: Vector,
+        bus_mass: f64,
+        damping: f64,
+        direction: Option<Vector>,
+    ) -> PyResult<Self> {
+        let pendulum = Self {
+            mass,
+            length,
+            hinge,
+            bus_mass,
+            damping,
+            direction: direction.unwrap_or(hinge).normalize()?,
+            rate: Vector::new(0.0, 0.0, 0.0),
+            acceleration: Vector::new(0.0, 0.0, 0.0),
+        };
+        pendulum.validate()?;
+
+        Ok (pendulum)
+    }
+
+    #[setter]
+    /// Set the damping coefficient of the hinge.
+    ///
+    /// Raises `ValueError` if the damping coefficient is negative.
+    fn set_damping(&mut self, damping: f64) -> PyResult<()> {
+        non_negative("slosh damping coefficient", damping)?;
+        self.damping = damping;
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the direction of the pendulum, which is normalized automatically.
+    ///
+    /// The rate is projected onto the plane normal to the new direction.
+    fn set_direction(&mut self, direction: Vector) -> PyResult<()> {
+        self.direction = direction.normalize()?;
+        self.set_rate(self.rate);
+
+        Ok (())
+    }
+
+    #[setter]
+    /// Set the rate of the pendulum, projected onto the plane normal to its direction.
+    fn set_rate(&mut self, rate: Vector) {
+        self.rate = rate - self.direction.scale(rate.dot(self.direction));
+    }
+
+    /// Check that this pendulum is physically realizable.
+    ///
+    /// Raises `ValueError` if the slosh mass, length or bus mass is not positive, or if
+    /// the damping coefficient is negative.
+    pub fn validate(&self) -> PyResult<()> {
+        positive("slosh mass", self.mass)?;
+        positive("pendulum length", self.length)?;
+        positive("bus mass", self.bus_mass)?;
+        non_negative("slosh damping coefficient", self.damping)?;
+
+        Ok (())
+    }
+
+    /// Compute the reduced mass of the slosh mass and the bus.
+    pub fn reduced_mass(&self) -> f64 {
+        self.mass * self.bus_mass / (self.mass + self.bus_mass)
+    }
+
+    /// Compute the position (body frame) of the slosh mass, relative to the centre of
+    /// mass of the bus.
+    pub fn position(&self) -> Vector {
+        self.hinge + self.direction.scale(self.length)
+    }
+
+    /// Compute the angular momentum (body frame) of the slosh mass about the centre of
+    /// mass of the spacecraft, given the angular velocity of the bus.
+    pub fn momentum(&self, angular_velocity: AngularVelocity) -> AngularMomentum {
+        let h = self.momentum_vector(angular_velocity);
+
+        AngularMomentum::new(h.x, h.y, h.z)
+    }
+
+    /// Return a Pythonic representation of this pendulum.
+    fn __repr__(&self) -> String {
+        format!(
+            "SloshPendulum({}, {}, {}, {}, {}, {})",
+            self.mass,
+            self.length,
+            self.hinge.__repr__(),
+            self.bus_mass,
+            self.damping,
+            self.direction.__repr__(),
+        )
+    }
+}
+
+impl SloshPendulum {
+    /// Compute the angular momentum of the slosh mass as a vector.
+    pub(crate) fn momentum_vector(&self, angular_velocity: AngularVelocity) -> Vector {
+        let w = Vector::new(angular_velocity.x, angular_velocity.y, angular_velocity.z);
+        let r = self.position();
+
+        r.cross(w.cross(r) + self.rate.scale(self.length)).scale(self.reduced_mass())
+    }
+
+    /// Compute the lever `hinge x direction`, along which the rod transmits the inertia
+    /// of the slosh mass to the bus.
+    pub(crate) fn lever(&self) -> Vector {
+        self.hinge.cross(self.direction)
+    }
+}
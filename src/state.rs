@@ -3,18 +3,26 @@
 //!
 //! Rigid-body state abstraction.
 
+use pyo3::exceptions::{
+    PyFloatingPointError,
+    PyValueError,
+};
 use pyo3::prelude::*;
 
 use crate::{
     AngularVelocity,
+    FlexibleModes,
     KaneDamper,
     Inertia,
+    Multibody,
     Quaternion,
+    Rotor,
+    SloshPendulum,
     Torque,
 };
 
 #[pyclass]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 /// Rigid-body state.
 pub struct State {
     #[pyo3(get, set)]
@@ -29,7 +37,7 @@ pub struct State {
     /// Angular velocity (body frame).
     pub angular_velocity: AngularVelocity,
 
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     /// Rigid-body inertia (body frame).
     pub inertia: Inertia,
 
@@ -40,20 +48,232 @@ pub struct State {
     #[pyo3(get, set)]
     /// Kane damper.
     pub damper: Option<KaneDamper>,
+
+    #[pyo3(get)]
+    /// Rotor of a dual-spin spacecraft.
+    pub rotor: Option<Rotor>,
+
+    #[pyo3(get)]
+    /// Structural modes of flexible appendages.
+    pub flexible: Option<FlexibleModes>,
+
+    #[pyo3(get)]
+    /// Propellant slosh pendulum.
+    pub slosh: Option<SloshPendulum>,
+
+    #[pyo3(get)]
+    /// Bodies articulated from the bus by revolute joints.
+    pub multibody: Option<Multibody>,
 }
 
 #[pymethods]
 impl State {
     #[new]
     /// Initialize a new state, with body at default attitude and no velocities or torque.
-    fn new(inertia: Inertia) -> Self {
-        Self {
+    ///
+    /// Raises `ValueError` if the inertia tensor is not physically realizable.
+    pub fn new(inertia: Inertia) -> PyResult<Self> {
+        inertia.validate()?;
+
+        Ok (Self {
             quaternion: Quaternion::new(1.0, 0.0, 0.0, 0.0),
             angular_velocity: AngularVelocity::new(0.0, 0.0, 0.0),
             inertia,
             torque: Torque::new(0.0, 0.0, 0.0),
             damper: None,
+            rotor: None,
+            flexible: None,
+            slosh: None,
+            multibody: None,
             time: 0.0,
+        })
+    }
+
+    #[setter]
+    /// Set the rigid-body inertia, checking that it is physically realizable.
+    ///
+    /// Raises `ValueError` if the inertia is not physically realizable, or if the
+    /// inertia of the bus net of the rotor, modes and slosh mass is not positive.
+    fn set_inertia(&mut self, inertia: Inertia) -> PyResult<()> {
+        self.update(|s| s.inertia = inertia)
+    }
+
+    #[setter]
+    /// Set the rotor of a dual-spin spacecraft, or `None` to remove it.
+    ///
+    /// Raises `ValueError` if the rotor is not physically realizable, or if the inertia
+    /// of the bus net of the rotor, modes and slosh mass is not positive.
+    fn set_rotor(&mut self, rotor: Option<Rotor>) -> PyResult<()> {
+        self.update(|s| s.rotor = rotor)
+    }
+
+    #[setter]
+    /// Set the structural modes of flexible appendages, or `None` to remove them.
+    ///
+    /// Raises `ValueError` if the modes are not physically realizable, or if the inertia
+    /// of the bus net of the rotor, modes and slosh mass is not positive.
+    fn set_flexible(&mut self, flexible: Option<FlexibleModes>) -> PyResult<()> {
+        self.update(|s| s.flexible = flexible)
+    }
+
+    #[setter]
+    /// Set the propellant slosh pendulum, or `None` to remove it.
+    ///
+    /// Raises `ValueError` if the pendulum is not physically realizable, or if the
+    /// inertia of the bus net of the rotor, modes and slosh mass is not positive.
+    fn set_slosh(&mut self, slosh: Option<SloshPendulum>) -> PyResult<()> {
+        self.update(|s| s.slosh = slosh)
+    }
+
+    #[setter]
+    /// Set the bodies articulated from the bus, or `None` to remove them.
+    ///
+    /// Raises `ValueError` if the articulated bodies are not physically realizable.
+    fn set_multibody(&mut self, multibody: Option<Multibody>) -> PyResult<()> {
+        self.update(|s| s.multibody = multibody)
+    }
+
+    /// Check that this state is physically meaningful.
+    ///
+    /// Raises `ValueError` if the inertia, damper, rotor, flexible modes, slosh
+    /// pendulum or articulated bodies are not physically realizable, if the inertia of
+    /// the bus net of the rotor, modes and slosh mass is not positive, if the attitude
+    /// quaternion has zero norm, or if any quantity is not finite.
+    pub fn validate(&self) -> PyResult<()> {
+        self.validate_mass_properties()?;
+
+        if let Some (d) = self.damper {
+            d.validate()?;
+        }
+
+        if self.quaternion.norm() == 0.0 {
+            return Err (PyValueError::new_err("attitude quaternion has zero norm"));
+        }
+
+        if !self.is_finite() {
+            return Err (PyValueError::new_err(format!(
+                "state has non-finite components at time {}",
+                self.time,
+            )));
+        }
+
+        Ok (())
+    }
+}
+
+impl State {
+    /// Check that the inertia, rotor, flexible modes, slosh pendulum and articulated
+    /// bodies of this state are physically realizable, and that the inertia of the bus
+    /// net of the rotor, modes and slosh mass is positive.
+    fn validate_mass_properties(&self) -> PyResult<()> {
+        self.inertia.validate()?;
+
+        if let Some (r) = self.rotor {
+            r.validate()?;
+        }
+
+        if let Some (f) = &self.flexible {
+            f.validate()?;
+        }
+
+        if let Some (p) = self.slosh {
+            p.validate()?;
+        }
+
+        if let Some (m) = &self.multibody {
+            m.validate()?;
+        }
+
+        // The bus, net of the rotor, modes and slosh mass, must keep positive inertia
+        let ((smallest, _, _), _) = self.effective_inertia().principal();
+        if smallest <= 0.0 {
+            return Err (PyValueError::new_err(
+                "rotor and flexible modes couple more inertia than the spacecraft and slosh mass have",
+            ));
+        }
+
+        Ok (())
+    }
+
+    /// Apply a change to the mass properties of this state, keeping this state
+    /// unchanged if the result is not physically realizable.
+    fn update(&mut self, change: impl FnOnce(&mut Self)) -> PyResult<()> {
+        let mut state = self.clone();
+        change(&mut state);
+        state.validate_mass_properties()?;
+        *self = state;
+
+        Ok (())
+    }
+
+    /// Determine whether every dynamic quantity of this state is finite.
+    pub fn is_finite(&self) -> bool {
+        let Self { time, quaternion: q, angular_velocity: w, torque: t, .. } = *self;
+        let mut values = vec![time, q.w, q.x, q.y, q.z, w.x, w.y, w.z, t.x, t.y, t.z];
+        if let Some (d) = self.damper {
+            let wd = d.angular_velocity;
+            values.extend([wd.x, wd.y, wd.z]);
+        }
+        if let Some (r) = self.rotor {
+            values.extend([r.spin, r.torque]);
+        }
+        if let Some (f) = &self.flexible {
+            values.extend(&f.displacements);
+            values.extend(&f.velocities);
+        }
+        if let Some (p) = self.slosh {
+            let (e, u, a) = (p.direction, p.rate, p.acceleration);
+            values.extend([e.x, e.y, e.z, u.x, u.y, u.z, a.x, a.y, a.z]);
+        }
+        if let Some (m) = &self.multibody {
+            values.extend(&m.angles);
+            values.extend(&m.rates);
+            values.extend(&m.torques);
+        }
+
+        values.iter().all(|v| v.is_finite())
+    }
+
+    /// Compute the inertia tensor of the bus, less the axial inertia of the rotor and the
+    /// inertia coupled to the flexible modes, plus the inertia of the slosh mass
+    /// transmitted by the rod of the pendulum.
+    ///
+    /// This is the inertia that resists angular acceleration of the bus.
+    pub(crate) fn effective_inertia(&self) -> Inertia {
+        let mut inertia = match self.rotor {
+            Some (r) => r.platform_inertia(self.inertia),
+            None => self.inertia,
+        };
+        if let Some (f) = &self.flexible {
+            let c = f.coupled_inertia();
+            inertia = Inertia::new(
+                inertia.j1 - c.j1,
+                inertia.j2 - c.j2,
+                inertia.j3 - c.j3,
+                inertia.j4 - c.j4,
+                inertia.j5 - c.j5,
+                inertia.j6 - c.j6,
+            );
+        }
+        if let Some (p) = self.slosh {
+            let k = p.lever().scale(p.reduced_mass().sqrt());
+            inertia = inertia + Inertia::new(k.x*k.x, k.y*k.y, k.z*k.z, k.y*k.z, k.x*k.z, k.x*k.y);
+        }
+
+        inertia
+    }
+
+    /// Raise `FloatingPointError` if this state has non-finite components.
+    ///
+    /// This is used by integrators running in strict mode after each step.
+    pub(crate) fn check_finite(&self) -> PyResult<()> {
+        if self.is_finite() {
+            Ok (())
+        } else {
+            Err (PyFloatingPointError::new_err(format!(
+                "integration produced a non-finite state at time {}",
+                self.time,
+            )))
         }
     }
 }
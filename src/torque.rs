@@ -94,7 +94,7 @@ impl Torque {
     }
 
     /// Return a Pythonic representation of this vector.
-    fn __repr__(&self) -> String {
+    pub(crate) fn __repr__(&self) -> String {
         format!(
             "Torque({}, {}, {})",
             self.x,
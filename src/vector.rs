@@ -0,0 +1,201 @@
+//! ADCS
+//! Copyright (c) 2026 Joseph Hobbs
+//!
+//! Vector type.
+
+use std::ops::{
+    Add,
+    Sub,
+    Neg,
+};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+use crate::Quaternion;
+use crate::linalg::Matrix;
+
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+/// General-purpose vector, such as a position or a direction.
+///
+/// Note that vectors are, by default, given in the _body frame_.
+pub struct Vector {
+    #[pyo3(get, set)]
+    /// X coordinate.
+    pub x: f64,
+
+    #[pyo3(get, set)]
+    /// Y coordinate.
+    pub y: f64,
+
+    #[pyo3(get, set)]
+    /// Z coordinate.
+    pub z: f64,
+}
+
+#[pymethods]
+impl Vector {
+    #[new]
+    /// Construct a new vector.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self {
+            x,
+            y,
+            z,
+        }
+    }
+
+    #[classmethod]
+    /// Construct the zero vector.
+    pub fn zero(_cls: &Bound<'_, PyType>) -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    }
+
+    /// Rotate this vector by a given unit quaternion.
+    pub fn rotate(&self, q: Quaternion) -> Self {
+        let v = Quaternion::new(
+            0.0,
+            self.x,
+            self.y,
+            self.z,
+        );
+
+        // Rotate
+        let rotated = q * v * q.inv();
+
+        Self {
+            x: rotated.x,
+            y: rotated.y,
+            z: rotated.z,
+        }
+    }
+
+    /// Scale this vector by a given scalar.
+    pub fn scale(&self, s: f64) -> Self {
+        Self {
+            x: s * self.x,
+            y: s * self.y,
+            z: s * self.z,
+        }
+    }
+
+    /// Compute the norm of this vector.
+    pub fn norm(&self) -> f64 {
+        self.dot(*self).sqrt()
+    }
+
+    /// Return the unit vector in the direction of this vector.
+    ///
+    /// Raises `ValueError` if this vector has zero norm.
+    pub fn normalize(&self) -> PyResult<Self> {
+        let n = self.norm();
+        if n == 0.0 {
+            return Err (PyValueError::new_err("cannot normalize a vector with zero norm"));
+        }
+
+        Ok (self.scale(n.powi(-1)))
+    }
+
+    /// Compute the dot product of two vectors.
+    pub fn dot(&self, other: Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Compute the cross product of two vectors.
+    pub fn cross(&self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Return a human-readable string for this vector.
+    fn __str__(&self) -> String {
+        format!(
+            "i{:.6} + j{:.6} + k{:.6}",
+            self.x,
+            self.y,
+            self.z,
+        )
+    }
+
+    /// Return a Pythonic representation of this vector.
+    pub(crate) fn __repr__(&self) -> String {
+        format!(
+            "Vector({}, {}, {})",
+            self.x,
+            self.y,
+            self.z,
+        )
+    }
+
+    /// Add two vectors.
+    fn __add__(&self, other: Self) -> Self {
+        *self + other
+    }
+
+    /// Subtract two vectors.
+    fn __sub__(&self, other: Self) -> Self {
+        *self - other
+    }
+
+    /// Negate a vector.
+    fn __neg__(&self) -> Self {
+        -(*self)
+    }
+}
+
+impl Vector {
+    /// Return this vector as a 3x1 column matrix.
+    pub(crate) fn column(&self) -> Matrix {
+        Matrix::column(&[self.x, self.y, self.z])
+    }
+
+    /// Construct a vector from the first column of a 3xN matrix.
+    pub(crate) fn from_column(m: &Matrix) -> Self {
+        Self::new(m[(0, 0)], m[(1, 0)], m[(2, 0)])
+    }
+}
+
+impl Add<Vector> for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub<Vector> for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}